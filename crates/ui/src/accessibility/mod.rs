@@ -10,17 +10,32 @@ pub struct AccessibilityProps {
     pub hidden: bool,
     pub expanded: Option<bool>,
     pub selected: Option<bool>,
+    /// Pressed state of a toggle button (`aria-pressed`)
+    pub pressed: Option<bool>,
+    /// State of a checkbox (`aria-checked`)
+    pub checked: Option<AriaChecked>,
     pub disabled: bool,
     pub required: bool,
     pub invalid: bool,
 }
 
+/// Value of `aria-checked`, where `Mixed` is a partly checked checkbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AriaChecked {
+    False,
+    True,
+    Mixed,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AriaRole {
     Button,
     Textbox,
     Checkbox,
     Radio,
+    Switch,
+    RadioGroup,
+    Slider,
     Dialog,
     Alert,
     Navigation,
@@ -36,21 +51,38 @@ pub trait Accessible: Styled + Sized {
     }
 
     fn aria_label(self, label: impl Into<SharedString>) -> Self {
-        let mut props = AccessibilityProps::default();
-        props.label = Some(label.into());
-        self.accessibility(props)
+        self.accessibility(AccessibilityProps {
+            label: Some(label.into()),
+            ..Default::default()
+        })
     }
 
     fn aria_hidden(self, hidden: bool) -> Self {
-        let mut props = AccessibilityProps::default();
-        props.hidden = hidden;
-        self.accessibility(props)
+        self.accessibility(AccessibilityProps {
+            hidden,
+            ..Default::default()
+        })
     }
 
     fn aria_disabled(self, disabled: bool) -> Self {
-        let mut props = AccessibilityProps::default();
-        props.disabled = disabled;
-        self.accessibility(props)
+        self.accessibility(AccessibilityProps {
+            disabled,
+            ..Default::default()
+        })
+    }
+
+    fn aria_pressed(self, pressed: bool) -> Self {
+        self.accessibility(AccessibilityProps {
+            pressed: Some(pressed),
+            ..Default::default()
+        })
+    }
+
+    fn aria_checked(self, checked: AriaChecked) -> Self {
+        self.accessibility(AccessibilityProps {
+            checked: Some(checked),
+            ..Default::default()
+        })
     }
 }
 
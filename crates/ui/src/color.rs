@@ -18,6 +18,7 @@ impl Color {
     ///
     /// # Example
     /// ```
+    /// # use gpui_flutter::color::Color;
     /// let red = Color::from_rgba(255, 0, 0, 1.0);
     /// ```
     pub fn from_rgba(r: u8, g: u8, b: u8, a: f32) -> Self {
@@ -36,6 +37,7 @@ impl Color {
     ///
     /// # Example
     /// ```
+    /// # use gpui_flutter::color::Color;
     /// let red = Color::rgb(255, 0, 0);
     /// ```
     pub fn rgb(r: u8, g: u8, b: u8) -> Self {
@@ -46,6 +48,7 @@ impl Color {
     ///
     /// # Example
     /// ```
+    /// # use gpui_flutter::color::Color;
     /// let red = Color::from_argb(0xFFFF0000);
     /// ```
     pub fn from_argb(argb: u32) -> Self {
@@ -61,6 +64,7 @@ impl Color {
     ///
     /// # Example
     /// ```
+    /// # use gpui_flutter::color::Color;
     /// let red = Color::from_hex(0xFF0000);
     /// ```
     pub fn from_hex(hex: u32) -> Self {
@@ -71,6 +75,7 @@ impl Color {
     ///
     /// # Example
     /// ```
+    /// # use gpui_flutter::color::Color;
     /// let blue = Color::from_hsl(240.0, 100.0, 50.0);
     /// ```
     pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
@@ -456,6 +461,7 @@ impl Color {
     ///
     /// # Example
     /// ```
+    /// # use gpui_flutter::color::Color;
     /// let semi_transparent_red = Color::red().with_opacity(0.5);
     /// ```
     pub fn with_opacity(mut self, opacity: f32) -> Self {
@@ -595,9 +601,11 @@ impl std::error::Error for ColorParseError {}
 ///
 /// # Example
 /// ```
+/// # use gpui_flutter::color::Color;
 /// use std::str::FromStr;
 /// let color = Color::from_str("#ff0000")?;
 /// let color: Color = "blue".parse()?;  // even more idiomatic!
+/// # Ok::<(), gpui_flutter::color::ColorParseError>(())
 /// ```
 impl FromStr for Color {
     type Err = ColorParseError;
@@ -606,8 +614,8 @@ impl FromStr for Color {
         let s = s.trim();
 
         // Hex color: #RRGGBB or #RGB
-        if s.starts_with('#') {
-            return Self::parse_hex_string(&s[1..]);
+        if let Some(hex) = s.strip_prefix('#') {
+            return Self::parse_hex_string(hex);
         }
 
         // HSL format: hsl(h, s%, l%) or h s% l%
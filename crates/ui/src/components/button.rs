@@ -1,17 +1,386 @@
 // crates/ui/src/components/button.rs
 
-pub struct Button;
+use crate::accessibility::{AccessibilityProps, Accessible, AriaRole};
+use crate::icons::{Icon, IconSize, LucideIcon};
+use crate::theme::{ActiveTheme, Theme};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
 
+pub(super) type ClickHandler = Box<dyn Fn(&ClickEvent, &mut Window, &mut App)>;
+
+/// A shadcn-style button
+///
+/// Renders a label between optional leading and trailing icons, styled by
+/// its [`ButtonVariant`] and [`ButtonSize`] with the theme's colors. It
+/// shows hover and pressed states, and a `ring` colored focus ring when
+/// focused from the keyboard; pointer presses do not move focus. A focused
+/// button is activated with Enter or Space as well as by clicking.
+///
+/// A disabled or loading button ignores clicks and cannot be focused.
+/// Loading replaces the leading icon with a spinner.
+///
+/// ```ignore
+/// Button::new("save", "Save")
+///     .icon(Icon::new(LucideIcon::Save))
+///     .on_click(|_, _, cx| save(cx))
+/// ```
+#[derive(IntoElement)]
+pub struct Button {
+    id: ElementId,
+    label: SharedString,
+    variant: ButtonVariant,
+    size: ButtonSize,
+    icon: Option<Icon>,
+    trailing_icon: Option<Icon>,
+    on_click: Option<ClickHandler>,
+    disabled: bool,
+    loading: bool,
+    full_width: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ButtonVariant {
+    #[default]
     Primary,
     Secondary,
     Destructive,
+    Outline,
     Ghost,
     Link,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ButtonSize {
     Sm,
+    #[default]
     Md,
     Lg,
 }
+
+impl ButtonSize {
+    pub(super) fn height(self) -> Pixels {
+        match self {
+            ButtonSize::Sm => px(36.),
+            ButtonSize::Md => px(40.),
+            ButtonSize::Lg => px(44.),
+        }
+    }
+
+    pub(super) fn padding_x(self) -> Pixels {
+        match self {
+            ButtonSize::Sm => px(12.),
+            ButtonSize::Md => px(16.),
+            ButtonSize::Lg => px(32.),
+        }
+    }
+
+    pub(super) fn icon_size(self) -> IconSize {
+        match self {
+            ButtonSize::Lg => IconSize::Md,
+            ButtonSize::Sm | ButtonSize::Md => IconSize::Sm,
+        }
+    }
+}
+
+/// Colors of a variant at rest and while hovered
+pub(super) struct VariantColors {
+    pub background: Hsla,
+    pub foreground: Hsla,
+    pub border: Hsla,
+    /// Background while hovered
+    pub hover: Hsla,
+    /// Text color while hovered
+    pub hover_foreground: Hsla,
+}
+
+impl ButtonVariant {
+    pub(super) fn colors(self, theme: &Theme) -> VariantColors {
+        let colors = &theme.colors;
+        let transparent = Hsla::transparent_black();
+        let (background, foreground, border, hover, hover_foreground) = match self {
+            ButtonVariant::Primary => (
+                colors.primary.into(),
+                colors.primary_foreground.into(),
+                transparent,
+                Hsla::from(colors.primary).opacity(0.9),
+                colors.primary_foreground.into(),
+            ),
+            ButtonVariant::Secondary => (
+                colors.secondary.into(),
+                colors.secondary_foreground.into(),
+                transparent,
+                Hsla::from(colors.secondary).opacity(0.8),
+                colors.secondary_foreground.into(),
+            ),
+            ButtonVariant::Destructive => (
+                colors.destructive.into(),
+                colors.destructive_foreground.into(),
+                transparent,
+                Hsla::from(colors.destructive).opacity(0.9),
+                colors.destructive_foreground.into(),
+            ),
+            ButtonVariant::Outline => (
+                colors.background.into(),
+                colors.foreground.into(),
+                colors.input.into(),
+                colors.accent.into(),
+                colors.accent_foreground.into(),
+            ),
+            ButtonVariant::Ghost => (
+                transparent,
+                colors.foreground.into(),
+                transparent,
+                colors.accent.into(),
+                colors.accent_foreground.into(),
+            ),
+            ButtonVariant::Link => (
+                transparent,
+                colors.primary.into(),
+                transparent,
+                transparent,
+                colors.primary.into(),
+            ),
+        };
+
+        VariantColors {
+            background,
+            foreground,
+            border,
+            hover,
+            hover_foreground,
+        }
+    }
+}
+
+impl Button {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            variant: ButtonVariant::default(),
+            size: ButtonSize::default(),
+            icon: None,
+            trailing_icon: None,
+            on_click: None,
+            disabled: false,
+            loading: false,
+            full_width: false,
+        }
+    }
+
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Icon before the label
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Icon after the label
+    pub fn trailing_icon(mut self, icon: Icon) -> Self {
+        self.trailing_icon = Some(icon);
+        self
+    }
+
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Show a spinner in place of the leading icon and ignore clicks
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Stretch to the width of the parent
+    pub fn full_width(mut self, full_width: bool) -> Self {
+        self.full_width = full_width;
+        self
+    }
+
+    fn is_interactive(&self) -> bool {
+        !self.disabled && !self.loading
+    }
+}
+
+impl RenderOnce for Button {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let colors = self.variant.colors(&theme);
+        let interactive = self.is_interactive();
+        let is_link = self.variant == ButtonVariant::Link;
+        let icon_size = self.size.icon_size();
+        let foreground = colors.foreground;
+        let icon = |icon: Icon| icon.size(icon_size).color(foreground);
+        let leading = if self.loading {
+            Some(Icon::new(LucideIcon::LoaderCircle).label("Loading"))
+        } else {
+            self.icon
+        };
+
+        div()
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::Button),
+                label: Some(self.label.clone()),
+                disabled: !interactive,
+                ..Default::default()
+            })
+            .id(self.id)
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .gap(theme.spacing.sm)
+            .h(self.size.height())
+            .px(self.size.padding_x())
+            .rounded(theme.radius.md)
+            .border_1()
+            .border_color(colors.border)
+            .bg(colors.background)
+            .text_color(colors.foreground)
+            .text_size(theme.typography.size_sm)
+            .font_weight(FontWeight(theme.typography.weight_medium as f32))
+            .when(self.full_width, |button| button.w_full())
+            .children(leading.map(icon))
+            .child(self.label)
+            .children(self.trailing_icon.map(icon))
+            .map(|button| {
+                interactive_button(button, &colors, &theme, interactive, is_link, self.on_click)
+            })
+    }
+}
+
+/// Pointer, focus and click behavior shared by the button components
+///
+/// An enabled button gets hover and pressed styles, a focus ring and the
+/// click handler; a disabled one is dimmed and ignores the pointer.
+pub(super) fn interactive_button(
+    button: Stateful<Div>,
+    colors: &VariantColors,
+    theme: &Theme,
+    enabled: bool,
+    underline_on_hover: bool,
+    on_click: Option<ClickHandler>,
+) -> Stateful<Div> {
+    if !enabled {
+        return button.opacity(0.5).cursor_not_allowed();
+    }
+    let (hover, hover_foreground) = (colors.hover, colors.hover_foreground);
+    let ring: Hsla = theme.colors.ring.into();
+
+    button
+        .focusable()
+        .tab_stop(true)
+        .cursor_pointer()
+        .hover(|style| {
+            let style = style.bg(hover).text_color(hover_foreground);
+            if underline_on_hover {
+                style.underline()
+            } else {
+                style
+            }
+        })
+        .active(|style| style.opacity(0.8))
+        .focus(|style| {
+            style.shadow(vec![BoxShadow {
+                color: ring,
+                offset: point(px(0.), px(0.)),
+                blur_radius: px(0.),
+                spread_radius: px(2.),
+            }])
+        })
+        // Only keyboard navigation focuses a button, so the ring shows when
+        // it helps without flashing on every click
+        .on_mouse_down(MouseButton::Left, |_, window, _| window.prevent_default())
+        .when_some(on_click, |button, handler| {
+            button.on_click(move |event, window, cx| handler(event, window, cx))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Button;
+    use crate::test_util::draw_view;
+    use gpui::{
+        div, point, px, size, KeyUpEvent, Keystroke, Modifiers, ParentElement, Styled,
+        TestAppContext,
+    };
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn test_button_clicks_unless_disabled_or_loading(cx: &mut TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let counter = |clicks: &Rc<Cell<i32>>| {
+            let clicks = clicks.clone();
+            move |_: &_, _: &mut _, _: &mut _| clicks.set(clicks.get() + 1)
+        };
+        let (enabled, disabled, loading) = (counter(&clicks), counter(&clicks), counter(&clicks));
+
+        let cx = draw_view(cx, size(px(200.), px(200.)), move |_, _| {
+            let (enabled, disabled, loading) = (enabled.clone(), disabled.clone(), loading.clone());
+            div()
+                .flex()
+                .flex_col()
+                .child(Button::new("enabled", "Enabled").on_click(enabled))
+                .child(
+                    Button::new("disabled", "Disabled")
+                        .disabled(true)
+                        .on_click(disabled),
+                )
+                .child(
+                    Button::new("loading", "Loading")
+                        .loading(true)
+                        .on_click(loading),
+                )
+        });
+
+        // Buttons are 40px tall
+        cx.simulate_click(point(px(10.), px(20.)), Modifiers::default());
+        cx.simulate_click(point(px(10.), px(60.)), Modifiers::default());
+        cx.simulate_click(point(px(10.), px(100.)), Modifiers::default());
+        assert_eq!(clicks.get(), 1);
+        // Pointer presses leave focus where it was
+        assert!(cx.update(|window, cx| window.focused(cx)).is_none());
+    }
+
+    #[gpui::test]
+    fn test_button_activates_from_keyboard(cx: &mut TestAppContext) {
+        let clicks = Rc::new(Cell::new(0));
+        let counter = clicks.clone();
+
+        let cx = draw_view(cx, size(px(200.), px(200.)), move |_, _| {
+            let counter = counter.clone();
+            Button::new("save", "Save")
+                .full_width(true)
+                .on_click(move |_, _, _| counter.set(counter.get() + 1))
+        });
+
+        cx.update(|window, _| window.focus_next());
+        cx.run_until_parked();
+        for key in ["enter", "space", "a"] {
+            cx.simulate_event(KeyUpEvent {
+                keystroke: Keystroke::parse(key).unwrap(),
+            });
+        }
+
+        assert_eq!(clicks.get(), 2);
+    }
+}
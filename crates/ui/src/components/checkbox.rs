@@ -0,0 +1,489 @@
+// crates/ui/src/components/checkbox.rs
+
+use super::button::{interactive_button, VariantColors};
+use crate::accessibility::{AccessibilityProps, Accessible, AriaChecked, AriaRole};
+use crate::icons::{Icon, IconSize, LucideIcon};
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use std::rc::Rc;
+
+type CheckHandler = Rc<dyn Fn(&bool, &mut Window, &mut App)>;
+
+/// Side of the checkbox square
+const BOX_SIZE: Pixels = px(16.);
+
+/// Whether a [`Checkbox`] is checked
+///
+/// `Indeterminate` is for a checkbox standing for several others, some but
+/// not all of which are checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckState {
+    #[default]
+    Unchecked,
+    Checked,
+    Indeterminate,
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+}
+
+impl CheckState {
+    /// The state a click switches to: an indeterminate box becomes checked
+    pub fn toggled(self) -> Self {
+        match self {
+            CheckState::Checked => CheckState::Unchecked,
+            CheckState::Unchecked | CheckState::Indeterminate => CheckState::Checked,
+        }
+    }
+
+    fn aria(self) -> AriaChecked {
+        match self {
+            CheckState::Unchecked => AriaChecked::False,
+            CheckState::Checked => AriaChecked::True,
+            CheckState::Indeterminate => AriaChecked::Mixed,
+        }
+    }
+}
+
+/// A shadcn-style checkbox with an optional label
+///
+/// The state is owned by the caller, who passes it in with `checked` and
+/// updates it from `on_change`, which receives whether the box should now
+/// be checked. The box is focusable and toggles with Space, but not Enter,
+/// which is left to submit forms; the label beside it toggles it on click
+/// too.
+///
+/// ```ignore
+/// Checkbox::new("terms")
+///     .label("Accept the terms")
+///     .checked(self.accepted)
+///     .error(self.submitted && !self.accepted)
+///     .on_change(cx.listener(|this, checked, _, _| this.accepted = *checked))
+/// ```
+#[derive(IntoElement)]
+pub struct Checkbox {
+    id: ElementId,
+    state: CheckState,
+    label: Option<SharedString>,
+    on_change: Option<CheckHandler>,
+    disabled: bool,
+    error: bool,
+}
+
+impl Checkbox {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            state: CheckState::Unchecked,
+            label: None,
+            on_change: None,
+            disabled: false,
+            error: false,
+        }
+    }
+
+    /// Checked with `true`, or any [`CheckState`]
+    pub fn checked(mut self, state: impl Into<CheckState>) -> Self {
+        self.state = state.into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Called with whether the box should be checked
+    pub fn on_change(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Mark the checkbox invalid, as when a required box is unchecked
+    pub fn error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+}
+
+impl RenderOnce for Checkbox {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let colors = &theme.colors;
+        let marked = self.state != CheckState::Unchecked;
+        let accent: Hsla = if self.error {
+            colors.destructive.into()
+        } else {
+            colors.primary.into()
+        };
+        let background = if marked {
+            accent
+        } else {
+            Hsla::transparent_black()
+        };
+        let box_colors = VariantColors {
+            background,
+            foreground: colors.primary_foreground.into(),
+            border: accent,
+            hover: background,
+            hover_foreground: colors.primary_foreground.into(),
+        };
+        let enabled = !self.disabled;
+        let next = self.state.toggled() == CheckState::Checked;
+        let on_click = |handler: &Option<CheckHandler>| {
+            handler.clone().map(|handler| {
+                Box::new(
+                    move |event: &ClickEvent, window: &mut Window, cx: &mut App| {
+                        if let ClickEvent::Keyboard(KeyboardClickEvent {
+                            button: KeyboardButton::Enter,
+                            ..
+                        }) = event
+                        {
+                            return;
+                        }
+                        handler(&next, window, cx)
+                    },
+                )
+            })
+        };
+        let mark = match self.state {
+            CheckState::Unchecked => None,
+            CheckState::Checked => Some(LucideIcon::Check),
+            CheckState::Indeterminate => Some(LucideIcon::Minus),
+        };
+
+        let check = div()
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::Checkbox),
+                label: self.label.clone(),
+                disabled: self.disabled,
+                invalid: self.error,
+                ..Default::default()
+            })
+            .aria_checked(self.state.aria())
+            .id(self.id.clone())
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .size(BOX_SIZE)
+            .rounded(theme.radius.sm)
+            .border_1()
+            .border_color(box_colors.border)
+            .bg(box_colors.background)
+            .children(mark.map(|mark| {
+                Icon::new(mark)
+                    .size(IconSize::Xs)
+                    .color(box_colors.foreground)
+                    .decorative()
+            }));
+        let check = interactive_button(
+            check,
+            &box_colors,
+            &theme,
+            enabled,
+            false,
+            on_click(&self.on_change).map(|handler| handler as _),
+        );
+
+        let Some(label) = self.label else {
+            return div().flex().child(check);
+        };
+        div()
+            .flex()
+            .items_center()
+            .gap(theme.spacing.sm)
+            .child(check)
+            .child(
+                div()
+                    .id(ElementId::NamedChild(Box::new(self.id), "label".into()))
+                    .text_size(theme.typography.size_sm)
+                    .font_weight(FontWeight(theme.typography.weight_medium as f32))
+                    .text_color(if self.error {
+                        colors.destructive.into()
+                    } else {
+                        Hsla::from(colors.foreground)
+                    })
+                    .when(!enabled, |label| label.opacity(0.5).cursor_not_allowed())
+                    .when_some(
+                        on_click(&self.on_change).filter(|_| enabled),
+                        |label, handler| {
+                            label
+                                .cursor_pointer()
+                                .on_click(move |event, window, cx| handler(event, window, cx))
+                        },
+                    )
+                    .child(label),
+            )
+    }
+}
+
+type GroupHandler<T> = Rc<dyn Fn(&Vec<T>, &mut Window, &mut App)>;
+
+/// A column of checkboxes choosing any number of values
+///
+/// The chosen values are owned by the caller, passed in with `selected`
+/// and replaced from `on_change`, which receives them in option order.
+/// `select_all` adds a box above the options that checks or clears them
+/// all, and shows as indeterminate when only some are chosen.
+///
+/// ```ignore
+/// CheckboxGroup::new("toppings")
+///     .option(Topping::Cheese, "Cheese")
+///     .option(Topping::Olives, "Olives")
+///     .select_all("All toppings")
+///     .selected(self.toppings.clone())
+///     .on_change(cx.listener(|this, toppings: &Vec<Topping>, _, _| {
+///         this.toppings = toppings.clone()
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct CheckboxGroup<T: PartialEq + Clone + 'static> {
+    id: ElementId,
+    options: Vec<(T, SharedString)>,
+    selected: Vec<T>,
+    select_all: Option<SharedString>,
+    on_change: Option<GroupHandler<T>>,
+    disabled: bool,
+}
+
+impl<T: PartialEq + Clone + 'static> CheckboxGroup<T> {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            options: Vec::new(),
+            selected: Vec::new(),
+            select_all: None,
+            on_change: None,
+            disabled: false,
+        }
+    }
+
+    pub fn option(mut self, value: T, label: impl Into<SharedString>) -> Self {
+        self.options.push((value, label.into()));
+        self
+    }
+
+    pub fn options<L: Into<SharedString>>(
+        mut self,
+        options: impl IntoIterator<Item = (T, L)>,
+    ) -> Self {
+        self.options.extend(
+            options
+                .into_iter()
+                .map(|(value, label)| (value, label.into())),
+        );
+        self
+    }
+
+    pub fn selected(mut self, selected: Vec<T>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Add a box labelled `label` that checks or clears every option
+    pub fn select_all(mut self, label: impl Into<SharedString>) -> Self {
+        self.select_all = Some(label.into());
+        self
+    }
+
+    /// Called with the values that should now be chosen
+    pub fn on_change(mut self, handler: impl Fn(&Vec<T>, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn all_state(&self) -> CheckState {
+        let chosen = self
+            .options
+            .iter()
+            .filter(|(value, _)| self.selected.contains(value))
+            .count();
+        match chosen {
+            0 => CheckState::Unchecked,
+            n if n == self.options.len() => CheckState::Checked,
+            _ => CheckState::Indeterminate,
+        }
+    }
+}
+
+impl<T: PartialEq + Clone + 'static> RenderOnce for CheckboxGroup<T> {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let all_state = self.all_state();
+        let indent = self.select_all.is_some();
+        let options: Rc<[T]> = self
+            .options
+            .iter()
+            .map(|(value, _)| value.clone())
+            .collect();
+        let selected = Rc::new(self.selected);
+        let child_id = |name: SharedString| ElementId::NamedChild(Box::new(self.id.clone()), name);
+
+        let select_all = self.select_all.map(|label| {
+            let options = options.clone();
+            Checkbox::new(child_id("all".into()))
+                .label(label)
+                .checked(all_state)
+                .disabled(self.disabled)
+                .when_some(self.on_change.clone(), |checkbox, handler| {
+                    checkbox.on_change(move |checked, window, cx| {
+                        let chosen = if *checked {
+                            options.to_vec()
+                        } else {
+                            Vec::new()
+                        };
+                        handler(&chosen, window, cx)
+                    })
+                })
+        });
+
+        let checkboxes = self
+            .options
+            .into_iter()
+            .enumerate()
+            .map(|(ix, (value, label))| {
+                let (options, selected) = (options.clone(), selected.clone());
+                Checkbox::new(child_id(ix.to_string().into()))
+                    .label(label)
+                    .checked(selected.contains(&value))
+                    .disabled(self.disabled)
+                    .when_some(self.on_change.clone(), |checkbox, handler| {
+                        checkbox.on_change(move |checked, window, cx| {
+                            let chosen = options
+                                .iter()
+                                .filter(|option| {
+                                    if **option == value {
+                                        *checked
+                                    } else {
+                                        selected.contains(option)
+                                    }
+                                })
+                                .cloned()
+                                .collect();
+                            handler(&chosen, window, cx)
+                        })
+                    })
+            });
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(theme.spacing.sm)
+            .children(select_all)
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(theme.spacing.sm)
+                    .when(indent, |options| options.pl(px(24.)))
+                    .children(checkboxes),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CheckState, Checkbox, CheckboxGroup};
+    use crate::test_util::{draw_view, redraw};
+    use gpui::{
+        div, point, px, size, KeyUpEvent, Keystroke, Modifiers, ParentElement, Styled,
+        TestAppContext,
+    };
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn test_checkbox_toggles_from_box_label_and_space(cx: &mut TestAppContext) {
+        let state = Rc::new(Cell::new(CheckState::Indeterminate));
+        let current = state.clone();
+
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, _| {
+            let current = current.clone();
+            div().flex().flex_col().children([
+                Checkbox::new("check")
+                    .label("Check")
+                    .checked(current.get())
+                    .on_change(move |checked, _, _| current.set((*checked).into())),
+                Checkbox::new("disabled")
+                    .label("Disabled")
+                    .disabled(true)
+                    .on_change(|_, _, _| panic!("disabled checkbox changed")),
+            ])
+        });
+
+        // An indeterminate box becomes checked
+        cx.simulate_click(point(px(8.), px(8.)), Modifiers::default());
+        assert_eq!(state.get(), CheckState::Checked);
+
+        // The label toggles it too
+        redraw(cx);
+        cx.simulate_click(point(px(40.), px(8.)), Modifiers::default());
+        assert_eq!(state.get(), CheckState::Unchecked);
+
+        redraw(cx);
+        cx.update(|window, _| window.focus_next());
+        cx.run_until_parked();
+        cx.simulate_event(KeyUpEvent {
+            keystroke: Keystroke::parse("space").unwrap(),
+        });
+        assert_eq!(state.get(), CheckState::Checked);
+
+        // Enter clicks focused elements, but does not toggle a checkbox
+        redraw(cx);
+        cx.simulate_event(KeyUpEvent {
+            keystroke: Keystroke::parse("enter").unwrap(),
+        });
+        assert_eq!(state.get(), CheckState::Checked);
+
+        cx.simulate_click(point(px(8.), px(30.)), Modifiers::default());
+        cx.simulate_click(point(px(40.), px(30.)), Modifiers::default());
+    }
+
+    #[gpui::test]
+    fn test_checkbox_group_selects_values_and_all(cx: &mut TestAppContext) {
+        let chosen = Rc::new(RefCell::new(vec![2]));
+        let current = chosen.clone();
+
+        let cx = draw_view(cx, size(px(200.), px(200.)), move |_, _| {
+            let (current, selected) = (current.clone(), current.borrow().clone());
+            CheckboxGroup::new("numbers")
+                .options([(1, "One"), (2, "Two"), (3, "Three")])
+                .select_all("All")
+                .selected(selected)
+                .on_change(move |values, _, _| *current.borrow_mut() = values.clone())
+        });
+
+        // Rows are a line of small text apart; "One" is the second row
+        let row_height = 20. + 8.;
+        cx.simulate_click(point(px(32.), px(row_height + 8.)), Modifiers::default());
+        assert_eq!(*chosen.borrow(), vec![1, 2]);
+
+        // Only some are chosen, so select-all checks the rest
+        redraw(cx);
+        cx.simulate_click(point(px(8.), px(8.)), Modifiers::default());
+        assert_eq!(*chosen.borrow(), vec![1, 2, 3]);
+
+        redraw(cx);
+        cx.simulate_click(point(px(8.), px(8.)), Modifiers::default());
+        assert!(chosen.borrow().is_empty());
+    }
+}
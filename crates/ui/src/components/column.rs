@@ -1,3 +1,117 @@
 // crates/ui/src/components/column.rs
 
-pub struct Column;
+use super::flex::Flex;
+use crate::layout::{CrossAxisAlignment, MainAxisAlignment, MainAxisSize, TextDirection};
+use gpui::*;
+
+/// Flutter-style Column: children laid out vertically
+///
+/// `CrossAxisAlignment::Start` and `End` follow the text direction, so in
+/// RTL `Start` lines children up against the right edge. The direction
+/// comes from the enclosing [`Directionality`](crate::layout::Directionality)
+/// unless set with `text_direction`.
+///
+/// Like Flutter, a Column fills the available height (`MainAxisSize::Max`)
+/// and centers its children horizontally by default.
+#[derive(IntoElement)]
+pub struct Column {
+    flex: Flex,
+}
+
+impl Column {
+    pub fn new() -> Self {
+        Self {
+            flex: Flex::new(Axis::Vertical),
+        }
+    }
+
+    pub fn main_axis_alignment(mut self, alignment: MainAxisAlignment) -> Self {
+        self.flex.main_axis_alignment = alignment;
+        self
+    }
+
+    pub fn cross_axis_alignment(mut self, alignment: CrossAxisAlignment) -> Self {
+        self.flex.cross_axis_alignment = alignment;
+        self
+    }
+
+    pub fn main_axis_size(mut self, size: MainAxisSize) -> Self {
+        self.flex.main_axis_size = size;
+        self
+    }
+
+    /// Gap between consecutive children
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.flex.spacing = px(spacing);
+        self
+    }
+
+    pub fn text_direction(mut self, direction: TextDirection) -> Self {
+        self.flex.text_direction = Some(direction);
+        self
+    }
+
+    /// Insert an element built by `separator` between every two children,
+    /// such as `separated_by(Divider::new)`
+    pub fn separated_by<E: IntoElement>(mut self, separator: impl Fn() -> E + 'static) -> Self {
+        self.flex.separator = Some(Box::new(move || separator().into_any_element()));
+        self
+    }
+}
+
+impl Default for Column {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentElement for Column {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.flex.children.extend(elements);
+    }
+}
+
+impl RenderOnce for Column {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        self.flex.render(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Column;
+    use crate::layout::{CrossAxisAlignment, Directionality, MainAxisSize};
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{div, point, px, size, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_column_cross_axis_follows_text_direction(cx: &mut TestAppContext) {
+        let (ltr, ltr_bounds) = sized_probe(10., 10.);
+        let (rtl, rtl_bounds) = sized_probe(10., 10.);
+        let (centered, centered_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(100.), px(100.)), move |_, _| {
+            let start = |child| {
+                Column::new()
+                    .main_axis_size(MainAxisSize::Min)
+                    .cross_axis_alignment(CrossAxisAlignment::Start)
+                    .child(child)
+            };
+            div()
+                .flex()
+                .flex_col()
+                .w(px(100.))
+                .child(start(ltr))
+                .child(Directionality::rtl(start(rtl)))
+                .child(
+                    Column::new()
+                        .main_axis_size(MainAxisSize::Min)
+                        .child(centered),
+                )
+        });
+
+        assert_eq!(ltr_bounds.get().origin, point(px(0.), px(0.)));
+        assert_eq!(rtl_bounds.get().origin, point(px(90.), px(10.)));
+        assert_eq!(centered_bounds.get().origin, point(px(45.), px(20.)));
+    }
+}
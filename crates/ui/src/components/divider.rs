@@ -0,0 +1,286 @@
+// crates/ui/src/components/divider.rs
+
+use crate::layout::TextDirection;
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+/// Flutter-style Divider: a thin horizontal line with space around it
+///
+/// The divider is as wide as its parent and `space` tall (the theme's
+/// medium spacing by default), with a `thickness` line through its middle.
+/// `indent` and `end_indent` leave a gap before the leading and trailing
+/// ends of the line, mirrored in RTL. With a `label`, the line is split
+/// around it, as in an "or" divider between sign-in options.
+///
+/// The line takes the theme's border color unless `color` is set.
+#[derive(IntoElement)]
+pub struct Divider {
+    space: Option<Pixels>,
+    thickness: Pixels,
+    indent: Pixels,
+    end_indent: Pixels,
+    color: Option<Hsla>,
+    label: Option<AnyElement>,
+}
+
+impl Divider {
+    pub fn new() -> Self {
+        Self {
+            space: None,
+            thickness: px(1.),
+            indent: Pixels::ZERO,
+            end_indent: Pixels::ZERO,
+            color: None,
+            label: None,
+        }
+    }
+
+    /// Total height taken by the divider, including space around the line
+    pub fn space(mut self, space: f32) -> Self {
+        self.space = Some(px(space));
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = px(thickness);
+        self
+    }
+
+    /// Gap before the leading end of the line
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = px(indent);
+        self
+    }
+
+    /// Gap after the trailing end of the line
+    pub fn end_indent(mut self, end_indent: f32) -> Self {
+        self.end_indent = px(end_indent);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Content centered on the line, such as "or"
+    pub fn label(mut self, label: impl IntoElement) -> Self {
+        self.label = Some(label.into_any_element());
+        self
+    }
+}
+
+impl Default for Divider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for Divider {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let color = self.color.unwrap_or(theme.colors.border.into());
+        let line = || div().flex_1().h(self.thickness).bg(color);
+        let space = self.space.unwrap_or(theme.spacing.md);
+        let labelled = self.label.is_some();
+        let (left, right) = match TextDirection::of(cx) {
+            TextDirection::Ltr => (self.indent, self.end_indent),
+            TextDirection::Rtl => (self.end_indent, self.indent),
+        };
+
+        div()
+            .flex()
+            .items_center()
+            .w_full()
+            .flex_none()
+            // A label taller than the space grows the divider instead of overflowing
+            .map(|divider| {
+                if labelled {
+                    divider.min_h(space)
+                } else {
+                    divider.h(space)
+                }
+            })
+            .pl(left)
+            .pr(right)
+            .child(line())
+            .children(self.label.map(|label| {
+                div()
+                    .flex_none()
+                    .px(theme.spacing.sm)
+                    .text_size(theme.typography.size_sm)
+                    .text_color(theme.colors.muted_foreground)
+                    .child(label)
+            }))
+            .when(labelled, |divider| divider.child(line()))
+    }
+}
+
+/// Flutter-style VerticalDivider: a thin vertical line with space around it
+///
+/// The vertical counterpart of [`Divider`], `space` wide and as tall as its
+/// parent. In a [`Row`](super::Row) whose height comes from its content,
+/// wrap the row in [`IntrinsicHeight`](crate::layout::IntrinsicHeight) so
+/// the divider has a height to fill. `indent` and `end_indent` leave gaps
+/// at the top and bottom.
+#[derive(IntoElement)]
+pub struct VerticalDivider {
+    space: Option<Pixels>,
+    thickness: Pixels,
+    indent: Pixels,
+    end_indent: Pixels,
+    color: Option<Hsla>,
+    label: Option<AnyElement>,
+}
+
+impl VerticalDivider {
+    pub fn new() -> Self {
+        Self {
+            space: None,
+            thickness: px(1.),
+            indent: Pixels::ZERO,
+            end_indent: Pixels::ZERO,
+            color: None,
+            label: None,
+        }
+    }
+
+    /// Total width taken by the divider, including space around the line
+    pub fn space(mut self, space: f32) -> Self {
+        self.space = Some(px(space));
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = px(thickness);
+        self
+    }
+
+    /// Gap above the line
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = px(indent);
+        self
+    }
+
+    /// Gap below the line
+    pub fn end_indent(mut self, end_indent: f32) -> Self {
+        self.end_indent = px(end_indent);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Content centered on the line
+    pub fn label(mut self, label: impl IntoElement) -> Self {
+        self.label = Some(label.into_any_element());
+        self
+    }
+}
+
+impl Default for VerticalDivider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for VerticalDivider {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let color = self.color.unwrap_or(theme.colors.border.into());
+        let line = || div().flex_1().w(self.thickness).bg(color);
+        let space = self.space.unwrap_or(theme.spacing.md);
+        let labelled = self.label.is_some();
+
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .h_full()
+            .flex_none()
+            .map(|divider| {
+                if labelled {
+                    divider.min_w(space)
+                } else {
+                    divider.w(space)
+                }
+            })
+            .pt(self.indent)
+            .pb(self.end_indent)
+            .child(line())
+            .children(self.label.map(|label| {
+                div()
+                    .flex_none()
+                    .py(theme.spacing.sm)
+                    .text_size(theme.typography.size_sm)
+                    .text_color(theme.colors.muted_foreground)
+                    .child(label)
+            }))
+            .when(labelled, |divider| divider.child(line()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Divider, VerticalDivider};
+    use crate::components::{Column, Row};
+    use crate::layout::{CrossAxisAlignment, Directionality, IntrinsicHeight, MainAxisSize};
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{div, point, px, size, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_divider_label_centers_between_indents(cx: &mut TestAppContext) {
+        let (ltr, ltr_bounds) = sized_probe(20., 10.);
+        let (rtl, rtl_bounds) = sized_probe(20., 10.);
+
+        draw(cx, size(px(100.), px(100.)), move |_, _| {
+            div()
+                .w(px(100.))
+                .child(Divider::new().indent(20.).label(ltr))
+                .child(Directionality::rtl(Divider::new().indent(20.).label(rtl)))
+        });
+
+        // 8px of padding on each side of the label, lines share the rest
+        assert_eq!(ltr_bounds.get().origin, point(px(50.), px(3.)));
+        assert_eq!(rtl_bounds.get().origin, point(px(30.), px(19.)));
+    }
+
+    #[gpui::test]
+    fn test_separated_by_inserts_dividers(cx: &mut TestAppContext) {
+        let (a, _) = sized_probe(10., 20.);
+        let (b, b_bounds) = sized_probe(10., 20.);
+        let (c, c_bounds) = sized_probe(10., 20.);
+        let (d, _) = sized_probe(10., 40.);
+        let (label, label_bounds) = sized_probe(4., 4.);
+        let (e, e_bounds) = sized_probe(10., 20.);
+
+        draw(cx, size(px(100.), px(200.)), move |_, _| {
+            div()
+                .w(px(100.))
+                .child(
+                    Column::new()
+                        .main_axis_size(MainAxisSize::Min)
+                        .cross_axis_alignment(CrossAxisAlignment::Start)
+                        .separated_by(|| Divider::new().space(10.))
+                        .child(a)
+                        .child(b)
+                        .child(c),
+                )
+                .child(IntrinsicHeight::new(
+                    Row::new()
+                        .child(d)
+                        .child(VerticalDivider::new().label(label))
+                        .child(e),
+                ))
+        });
+
+        assert_eq!(b_bounds.get().origin, point(px(0.), px(30.)));
+        assert_eq!(c_bounds.get().origin, point(px(0.), px(60.)));
+        // The vertical divider fills the row's height, label in the middle
+        assert_eq!(label_bounds.get().origin, point(px(16.), px(98.)));
+        assert_eq!(e_bounds.get().origin, point(px(26.), px(90.)));
+    }
+}
@@ -0,0 +1,67 @@
+// crates/ui/src/components/flex.rs
+
+use crate::layout::{CrossAxisAlignment, MainAxisAlignment, MainAxisSize, TextDirection};
+use gpui::*;
+
+/// Layout shared by [`Row`](super::Row) and [`Column`](super::Column)
+pub(super) struct Flex {
+    pub axis: Axis,
+    pub children: Vec<AnyElement>,
+    pub main_axis_alignment: MainAxisAlignment,
+    pub cross_axis_alignment: CrossAxisAlignment,
+    pub main_axis_size: MainAxisSize,
+    pub spacing: Pixels,
+    pub text_direction: Option<TextDirection>,
+    pub separator: Option<Box<dyn Fn() -> AnyElement>>,
+}
+
+impl Flex {
+    pub fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            children: Vec::new(),
+            main_axis_alignment: MainAxisAlignment::default(),
+            cross_axis_alignment: CrossAxisAlignment::default(),
+            main_axis_size: MainAxisSize::default(),
+            spacing: Pixels::ZERO,
+            text_direction: None,
+            separator: None,
+        }
+    }
+
+    pub fn render(self, cx: &App) -> Div {
+        let rtl =
+            self.text_direction.unwrap_or_else(|| TextDirection::of(cx)) == TextDirection::Rtl;
+        let fill = self.main_axis_size == MainAxisSize::Max;
+
+        // A Row runs from the leading edge, so RTL reverses it; a Column only
+        // mirrors its cross axis
+        let element = match self.axis {
+            Axis::Horizontal if rtl => div().flex().flex_row_reverse(),
+            Axis::Horizontal => div().flex().flex_row(),
+            Axis::Vertical => div().flex().flex_col(),
+        };
+        let mirror_cross = rtl && self.axis == Axis::Vertical;
+
+        let mut element = element.gap(self.spacing);
+        let style = element.style();
+        style.justify_content = Some(self.main_axis_alignment.justify_content());
+        style.align_items = Some(self.cross_axis_alignment.align_items(mirror_cross));
+        if fill {
+            match self.axis {
+                Axis::Horizontal => style.size.width = Some(relative(1.).into()),
+                Axis::Vertical => style.size.height = Some(relative(1.).into()),
+            }
+        }
+        match self.separator {
+            Some(separator) => {
+                let mut children = self.children.into_iter();
+                let first = children.next();
+                element
+                    .children(first)
+                    .children(children.flat_map(|child| [separator(), child]))
+            }
+            None => element.children(self.children),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+// crates/ui/src/components/icon_button.rs
+
+use super::button::{interactive_button, ClickHandler};
+use super::ButtonVariant;
+use crate::accessibility::{AccessibilityProps, Accessible, AriaRole};
+use crate::icons::{Icon, IconSize};
+use crate::theme::ActiveTheme;
+use gpui::*;
+
+/// Space between the icon and each edge of an [`IconButton`]
+const ICON_PADDING: Pixels = px(12.);
+
+/// A square [`Button`](super::Button) showing only an icon
+///
+/// The button is the icon's size plus even padding on every side, so a
+/// [`IconSize::Sm`] icon (the default) gives a 40px button matching a
+/// medium text button. With no visible text, the label is required: it is
+/// what assistive technology announces for the button.
+///
+/// ```ignore
+/// IconButton::new("close", Icon::new(LucideIcon::X), "Close")
+///     .variant(ButtonVariant::Ghost)
+///     .on_click(|_, window, _| window.remove_window())
+/// ```
+#[derive(IntoElement)]
+pub struct IconButton {
+    id: ElementId,
+    icon: Icon,
+    label: SharedString,
+    variant: ButtonVariant,
+    size: IconSize,
+    on_click: Option<ClickHandler>,
+    disabled: bool,
+}
+
+impl IconButton {
+    pub fn new(id: impl Into<ElementId>, icon: Icon, label: impl Into<SharedString>) -> Self {
+        let label = label.into();
+        debug_assert!(
+            !label.trim().is_empty(),
+            "IconButton needs a non-empty accessible label"
+        );
+        Self {
+            id: id.into(),
+            icon,
+            label,
+            variant: ButtonVariant::default(),
+            size: IconSize::Sm,
+            on_click: None,
+            disabled: false,
+        }
+    }
+
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Size of the icon; the button grows with it
+    pub fn size(mut self, size: IconSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl RenderOnce for IconButton {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let colors = self.variant.colors(&theme);
+        let side = self.size.to_pixels() + ICON_PADDING * 2.;
+
+        let button = div()
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::Button),
+                label: Some(self.label),
+                disabled: self.disabled,
+                ..Default::default()
+            })
+            .id(self.id)
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .size(side)
+            .rounded(theme.radius.md)
+            .border_1()
+            .border_color(colors.border)
+            .bg(colors.background)
+            .child(
+                self.icon
+                    .size(self.size)
+                    .color(colors.foreground)
+                    .decorative(),
+            );
+
+        interactive_button(
+            button,
+            &colors,
+            &theme,
+            !self.disabled,
+            false,
+            self.on_click,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IconButton;
+    use crate::components::ButtonVariant;
+    use crate::icons::{Icon, IconSize, LucideIcon};
+    use crate::test_util::{draw_view, ProbeBounds};
+    use gpui::{canvas, div, point, px, size, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_icon_button_is_square_around_icon(cx: &mut TestAppContext) {
+        let (sm_bounds, xl_bounds) = (ProbeBounds::default(), ProbeBounds::default());
+        let (sm, xl) = (sm_bounds.clone(), xl_bounds.clone());
+
+        draw_view(cx, size(px(200.), px(200.)), move |_, _| {
+            let after = |bounds: &ProbeBounds| {
+                let bounds = bounds.clone();
+                canvas(move |b, _, _| bounds.set(b), |_, _, _, _| {}).size(px(10.))
+            };
+            div()
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .flex()
+                        .child(IconButton::new(
+                            "search",
+                            Icon::new(LucideIcon::Search),
+                            "Search",
+                        ))
+                        .child(after(&sm)),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .child(
+                            IconButton::new("add", Icon::new(LucideIcon::Plus), "Add")
+                                .size(IconSize::Xl)
+                                .variant(ButtonVariant::Outline),
+                        )
+                        .child(after(&xl)),
+                )
+        });
+
+        assert_eq!(sm_bounds.get().origin, point(px(40.), px(0.)));
+        assert_eq!(xl_bounds.get().origin, point(px(56.), px(40.)));
+    }
+}
@@ -0,0 +1,174 @@
+// crates/ui/src/components/input_decoration.rs
+
+use crate::icons::{Icon, IconSize};
+use crate::theme::Theme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+/// Flutter-style InputDecoration: the chrome around a text input
+///
+/// A label above the input, helper or error text below it, and icons
+/// inside the input's border before and after the text. Error text
+/// replaces the helper text and turns the border, label and message the
+/// theme's `destructive` color.
+///
+/// ```ignore
+/// InputDecoration::new()
+///     .label("Email")
+///     .helper_text("We'll never share it")
+///     .prefix_icon(Icon::new(LucideIcon::Mail))
+/// ```
+#[derive(Clone, Default)]
+pub struct InputDecoration {
+    label: Option<SharedString>,
+    helper_text: Option<SharedString>,
+    error_text: Option<SharedString>,
+    prefix_icon: Option<Icon>,
+    suffix_icon: Option<Icon>,
+}
+
+impl InputDecoration {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Hint shown below the input while there is no error
+    pub fn helper_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.helper_text = Some(text.into());
+        self
+    }
+
+    /// Message shown below the input, marking it invalid
+    pub fn error_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.error_text = Some(text.into());
+        self
+    }
+
+    pub fn prefix_icon(mut self, icon: Icon) -> Self {
+        self.prefix_icon = Some(icon);
+        self
+    }
+
+    pub fn suffix_icon(mut self, icon: Icon) -> Self {
+        self.suffix_icon = Some(icon);
+        self
+    }
+
+    pub(super) fn label_text(&self) -> Option<&SharedString> {
+        self.label.as_ref()
+    }
+
+    pub(super) fn error(&self) -> Option<&SharedString> {
+        self.error_text.as_ref()
+    }
+
+    /// Lay out the decoration around an input's editable area
+    pub(super) fn decorate(
+        &self,
+        editor: impl IntoElement,
+        state: InputState,
+        theme: &Theme,
+    ) -> Div {
+        let InputState {
+            focused,
+            error,
+            counter,
+            multiline,
+        } = state;
+        let colors = &theme.colors;
+        let destructive: Hsla = colors.destructive.into();
+        let muted: Hsla = colors.muted_foreground.into();
+        let border: Hsla = match (&error, focused) {
+            (Some(_), _) => destructive,
+            (None, true) => colors.ring.into(),
+            (None, false) => colors.input.into(),
+        };
+        let icon = |icon: &Icon| icon.clone().size(IconSize::Sm).color(muted).decorative();
+        let message = error.clone().or_else(|| self.helper_text.clone());
+
+        div()
+            .flex()
+            .flex_col()
+            .w_full()
+            .gap(theme.spacing.xs)
+            .text_size(theme.typography.size_sm)
+            .when_some(self.label.clone(), |column, label| {
+                column.child(
+                    div()
+                        .font_weight(FontWeight(theme.typography.weight_medium as f32))
+                        .text_color(if error.is_some() {
+                            destructive
+                        } else {
+                            colors.foreground.into()
+                        })
+                        .child(label),
+                )
+            })
+            .child(
+                div()
+                    .flex()
+                    .map(|input| {
+                        if multiline {
+                            input.items_start()
+                        } else {
+                            input.items_center()
+                        }
+                    })
+                    .gap(theme.spacing.sm)
+                    .min_h(px(40.))
+                    .px(px(12.))
+                    .py(px(8.))
+                    .rounded(theme.radius.md)
+                    .border_1()
+                    .border_color(border)
+                    .bg(Hsla::from(colors.background))
+                    .text_color(colors.foreground)
+                    .when(focused, |input| {
+                        input.shadow(vec![BoxShadow {
+                            color: border.opacity(0.4),
+                            offset: point(px(0.), px(0.)),
+                            blur_radius: px(0.),
+                            spread_radius: px(2.),
+                        }])
+                    })
+                    .children(self.prefix_icon.as_ref().map(icon))
+                    .child(div().flex_1().min_w_0().child(editor))
+                    .children(self.suffix_icon.as_ref().map(icon)),
+            )
+            .when(message.is_some() || counter.is_some(), |column| {
+                column.child(
+                    div()
+                        .flex()
+                        .justify_between()
+                        .gap(theme.spacing.sm)
+                        .text_size(theme.typography.size_xs)
+                        .text_color(muted)
+                        .child(
+                            div()
+                                .when(error.is_some(), |message| message.text_color(destructive))
+                                .children(message),
+                        )
+                        .children(counter.map(|counter| div().flex_none().child(counter))),
+                )
+            })
+    }
+}
+
+/// What a decorated input shows besides its text
+#[derive(Default)]
+pub(super) struct InputState {
+    pub focused: bool,
+    /// Error replacing the helper text; callers combine the decoration's
+    /// own error text with their validation
+    pub error: Option<SharedString>,
+    /// Character count shown at the end of the message row
+    pub counter: Option<SharedString>,
+    /// Whether the editor is several lines tall, which aligns the icons
+    /// with its first line
+    pub multiline: bool,
+}
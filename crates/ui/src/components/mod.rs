@@ -1,13 +1,37 @@
 // crates/ui/src/components/mod.rs
 
 mod button;
+mod checkbox;
 mod column;
 mod container;
+mod divider;
+mod flex;
+mod icon_button;
+mod input_decoration;
+mod radio;
+mod rich_text;
 mod row;
+mod slider;
+mod switch;
 mod text;
+mod text_area;
+mod text_field;
+mod text_input;
+mod toggle;
 
 pub use button::{Button, ButtonSize, ButtonVariant};
+pub use checkbox::{CheckState, Checkbox, CheckboxGroup};
 pub use column::Column;
 pub use container::Container;
+pub use divider::{Divider, VerticalDivider};
+pub use icon_button::IconButton;
+pub use input_decoration::InputDecoration;
+pub use radio::{Radio, RadioGroup};
+pub use rich_text::{InlineSpan, RichText, TextSpan, WidgetSpan};
 pub use row::Row;
-pub use text::Text;
+pub use slider::{RangeSlider, Slider};
+pub use switch::{Switch, SwitchSize};
+pub use text::{Text, TextOverflow, TextStyle};
+pub use text_area::{TextArea, TEXT_AREA_CONTEXT};
+pub use text_field::{TextField, TEXT_FIELD_CONTEXT};
+pub use toggle::{Toggle, ToggleButton};
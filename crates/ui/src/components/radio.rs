@@ -0,0 +1,393 @@
+// crates/ui/src/components/radio.rs
+
+use super::button::{interactive_button, VariantColors};
+use crate::accessibility::{AccessibilityProps, Accessible, AriaChecked, AriaRole};
+use crate::layout::TextDirection;
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use std::rc::Rc;
+
+type SelectHandler<T> = Rc<dyn Fn(&T, &mut Window, &mut App)>;
+
+/// Side of the radio circle
+const CIRCLE_SIZE: Pixels = px(16.);
+
+/// Side of the dot marking the selected radio
+const DOT_SIZE: Pixels = px(8.);
+
+/// One choice in a [`RadioGroup`]
+pub struct Radio<T> {
+    value: T,
+    label: SharedString,
+    disabled: bool,
+}
+
+impl<T> Radio<T> {
+    pub fn new(value: T, label: impl Into<SharedString>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A shadcn-style set of radio buttons choosing one value
+///
+/// The chosen value is owned by the caller, passed in with `selected` and
+/// replaced from `on_change`. The group is a single tab stop: Tab focuses
+/// the selected radio, or the first enabled one, and the arrow keys move
+/// both focus and selection to the next or previous enabled radio,
+/// wrapping at the ends. Radios stack vertically unless the orientation is
+/// [`Axis::Horizontal`]; a horizontal group runs from the leading edge of
+/// the enclosing [`Directionality`](crate::layout::Directionality), with
+/// Left and Right following it.
+///
+/// ```ignore
+/// RadioGroup::new("plan")
+///     .radio(Radio::new(Plan::Free, "Free"))
+///     .radio(Radio::new(Plan::Pro, "Pro"))
+///     .radio(Radio::new(Plan::Team, "Team").disabled(!self.has_org))
+///     .selected(Some(self.plan))
+///     .on_change(cx.listener(|this, plan: &Plan, _, _| this.plan = *plan))
+/// ```
+#[derive(IntoElement)]
+pub struct RadioGroup<T: PartialEq + Clone + 'static> {
+    id: ElementId,
+    radios: Vec<Radio<T>>,
+    selected: Option<T>,
+    orientation: Axis,
+    label: Option<SharedString>,
+    on_change: Option<SelectHandler<T>>,
+    disabled: bool,
+}
+
+impl<T: PartialEq + Clone + 'static> RadioGroup<T> {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            radios: Vec::new(),
+            selected: None,
+            orientation: Axis::Vertical,
+            label: None,
+            on_change: None,
+            disabled: false,
+        }
+    }
+
+    pub fn radio(mut self, radio: Radio<T>) -> Self {
+        self.radios.push(radio);
+        self
+    }
+
+    /// Add an enabled radio for each value and label
+    pub fn options<L: Into<SharedString>>(
+        mut self,
+        options: impl IntoIterator<Item = (T, L)>,
+    ) -> Self {
+        self.radios.extend(
+            options
+                .into_iter()
+                .map(|(value, label)| Radio::new(value, label)),
+        );
+        self
+    }
+
+    pub fn selected(mut self, selected: Option<T>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn orientation(mut self, orientation: Axis) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Accessible name of the group, such as the question it answers
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Called with the value that should now be chosen
+    pub fn on_change(mut self, handler: impl Fn(&T, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// The radio that Tab lands on: the selected one when it is enabled,
+    /// otherwise the first enabled radio
+    fn tab_stop(&self, enabled: &[bool]) -> Option<usize> {
+        self.radios
+            .iter()
+            .position(|radio| Some(&radio.value) == self.selected.as_ref())
+            .filter(|ix| enabled[*ix])
+            .or_else(|| enabled.iter().position(|enabled| *enabled))
+    }
+}
+
+/// Index of the next enabled radio after `from` in the direction of
+/// `step`, wrapping around the ends
+fn next_enabled(enabled: &[bool], from: usize, step: isize) -> Option<usize> {
+    let len = enabled.len() as isize;
+    (1..=len)
+        .map(|offset| (from as isize + step * offset).rem_euclid(len) as usize)
+        .find(|ix| enabled[*ix])
+}
+
+impl<T: PartialEq + Clone + 'static> RenderOnce for RadioGroup<T> {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let colors = &theme.colors;
+        let enabled: Rc<[bool]> = self
+            .radios
+            .iter()
+            .map(|radio| !self.disabled && !radio.disabled)
+            .collect();
+        let tab_stop = self.tab_stop(&enabled);
+        let values: Rc<[T]> = self
+            .radios
+            .iter()
+            .map(|radio| radio.value.clone())
+            .collect();
+        let child_id = |name: SharedString| ElementId::NamedChild(Box::new(self.id.clone()), name);
+        let rtl = TextDirection::of(cx) == TextDirection::Rtl;
+
+        // Focus handles live across frames so the arrow keys can move focus
+        // to a sibling radio
+        let count = self.radios.len();
+        let handles: Rc<[FocusHandle]> =
+            window.with_global_id(child_id("focus".into()), |id, window| {
+                window.with_element_state(id, |handles: Option<Vec<FocusHandle>>, _| {
+                    let mut handles = handles.unwrap_or_default();
+                    handles.resize_with(count.max(handles.len()), || cx.focus_handle());
+                    (handles[..count].into(), handles)
+                })
+            });
+
+        let accent: Hsla = colors.primary.into();
+        let circle_colors = VariantColors {
+            background: Hsla::transparent_black(),
+            foreground: accent,
+            border: accent,
+            hover: Hsla::transparent_black(),
+            hover_foreground: accent,
+        };
+        // Collected so the key handler below can take the shared state
+        let radios: Vec<_> = self
+            .radios
+            .into_iter()
+            .enumerate()
+            .map(|(ix, radio)| {
+                let chosen = self.selected.as_ref() == Some(&radio.value);
+                let on_select = self
+                    .on_change
+                    .clone()
+                    .filter(|_| enabled[ix] && !chosen)
+                    .map(|handler| {
+                        let value = radio.value.clone();
+                        Rc::new(move |_: &ClickEvent, window: &mut Window, cx: &mut App| {
+                            handler(&value, window, cx)
+                        })
+                    });
+
+                let circle = div()
+                    .accessibility(AccessibilityProps {
+                        role: Some(AriaRole::Radio),
+                        label: Some(radio.label.clone()),
+                        disabled: !enabled[ix],
+                        ..Default::default()
+                    })
+                    .aria_checked(if chosen {
+                        AriaChecked::True
+                    } else {
+                        AriaChecked::False
+                    })
+                    .id(child_id(ix.to_string().into()))
+                    .track_focus(&handles[ix].clone().tab_stop(tab_stop == Some(ix)))
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .justify_center()
+                    .size(CIRCLE_SIZE)
+                    .rounded(theme.radius.full)
+                    .border_1()
+                    .border_color(circle_colors.border)
+                    .when(chosen, |circle| {
+                        circle.child(
+                            div()
+                                .size(DOT_SIZE)
+                                .rounded(theme.radius.full)
+                                .bg(circle_colors.foreground),
+                        )
+                    });
+                let circle = interactive_button(
+                    circle,
+                    &circle_colors,
+                    &theme,
+                    enabled[ix],
+                    false,
+                    on_select.clone().map(|handler| {
+                        Box::new(
+                            move |event: &ClickEvent, window: &mut Window, cx: &mut App| {
+                                handler(event, window, cx)
+                            },
+                        ) as _
+                    }),
+                )
+                // The group is a single tab stop, set on the radio's handle above
+                .tab_stop(tab_stop == Some(ix));
+
+                div()
+                    .flex()
+                    .when(rtl, |radio| radio.flex_row_reverse())
+                    .items_center()
+                    .gap(theme.spacing.sm)
+                    .child(circle)
+                    .child(
+                        div()
+                            .id(child_id(format!("{ix}-label").into()))
+                            .text_size(theme.typography.size_sm)
+                            .font_weight(FontWeight(theme.typography.weight_medium as f32))
+                            .text_color(colors.foreground)
+                            .when(!enabled[ix], |label| {
+                                label.opacity(0.5).cursor_not_allowed()
+                            })
+                            .when_some(on_select, |label, handler| {
+                                label
+                                    .cursor_pointer()
+                                    .on_click(move |event, window, cx| handler(event, window, cx))
+                            })
+                            .child(radio.label),
+                    )
+            })
+            .collect();
+
+        let on_change = self.on_change;
+        div()
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::RadioGroup),
+                label: self.label,
+                disabled: self.disabled,
+                ..Default::default()
+            })
+            .flex()
+            .map(|group| match self.orientation {
+                Axis::Horizontal if rtl => {
+                    group.flex_row_reverse().flex_wrap().gap(theme.spacing.md)
+                }
+                Axis::Horizontal => group.flex_row().flex_wrap().gap(theme.spacing.md),
+                Axis::Vertical => group.flex_col().gap(theme.spacing.sm),
+            })
+            .on_key_down(move |event, window, cx| {
+                // Left and Right move towards the leading and trailing edges
+                let step = match (event.keystroke.key.as_str(), rtl) {
+                    ("down", _) | ("right", false) | ("left", true) => 1,
+                    ("up", _) | ("left", false) | ("right", true) => -1,
+                    _ => return,
+                };
+                let Some(focused) = handles.iter().position(|handle| handle.is_focused(window))
+                else {
+                    return;
+                };
+                cx.stop_propagation();
+                let Some(next) = next_enabled(&enabled, focused, step) else {
+                    return;
+                };
+                window.focus(&handles[next]);
+                if let Some(handler) = &on_change {
+                    handler(&values[next], window, cx);
+                }
+            })
+            .children(radios)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{next_enabled, Radio, RadioGroup};
+    use crate::layout::{Directionality, TextDirection};
+    use crate::test_util::{draw_view, redraw};
+    use gpui::{point, px, size, Axis, Modifiers, TestAppContext};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_next_enabled_skips_disabled_and_wraps() {
+        let enabled = [true, false, true, true];
+        assert_eq!(next_enabled(&enabled, 0, 1), Some(2));
+        assert_eq!(next_enabled(&enabled, 3, 1), Some(0));
+        assert_eq!(next_enabled(&enabled, 0, -1), Some(3));
+        assert_eq!(next_enabled(&enabled, 2, -1), Some(0));
+        assert_eq!(next_enabled(&[false, true], 1, 1), Some(1));
+        assert_eq!(next_enabled(&[false], 0, 1), None);
+    }
+
+    #[gpui::test]
+    fn test_radio_group_selects_with_click_and_arrow_keys(cx: &mut TestAppContext) {
+        let chosen = Rc::new(Cell::new(2));
+        let rtl = Rc::new(Cell::new(false));
+        let (current, direction) = (chosen.clone(), rtl.clone());
+
+        let cx = draw_view(cx, size(px(400.), px(100.)), move |_, _| {
+            let current = current.clone();
+            let group = RadioGroup::new("numbers")
+                .orientation(Axis::Horizontal)
+                .radio(Radio::new(1, "One"))
+                .radio(Radio::new(2, "Two"))
+                .radio(Radio::new(3, "Three").disabled(true))
+                .radio(Radio::new(4, "Four"))
+                .selected(Some(current.get()))
+                .on_change(move |value, _, _| current.set(*value));
+            let direction = if direction.get() {
+                TextDirection::Rtl
+            } else {
+                TextDirection::Ltr
+            };
+            Directionality::new(direction, group)
+        });
+
+        cx.simulate_click(point(px(8.), px(10.)), Modifiers::default());
+        assert_eq!(chosen.get(), 1);
+
+        // Tab lands on the selected radio; arrows skip the disabled one and wrap
+        redraw(cx);
+        cx.update(|window, _| window.focus_next());
+        cx.run_until_parked();
+        cx.simulate_keystrokes("right");
+        assert_eq!(chosen.get(), 2);
+        redraw(cx);
+        cx.simulate_keystrokes("right");
+        assert_eq!(chosen.get(), 4);
+        redraw(cx);
+        cx.simulate_keystrokes("down");
+        assert_eq!(chosen.get(), 1);
+        redraw(cx);
+        cx.simulate_keystrokes("up");
+        assert_eq!(chosen.get(), 4);
+
+        // In RTL the group runs from the right and the arrows swap
+        rtl.set(true);
+        redraw(cx);
+        cx.simulate_keystrokes("left");
+        assert_eq!(chosen.get(), 1);
+        redraw(cx);
+        cx.simulate_keystrokes("right");
+        assert_eq!(chosen.get(), 4);
+        // The group is as wide as its radios, with One's circle at its right end
+        redraw(cx);
+        cx.simulate_click(point(px(268.), px(10.)), Modifiers::default());
+        assert_eq!(chosen.get(), 1);
+    }
+}
@@ -0,0 +1,553 @@
+// crates/ui/src/components/rich_text.rs
+
+use super::TextStyle;
+use crate::media_query::MediaQuery;
+use crate::theme::{ActiveTheme, Theme};
+use gpui::*;
+use std::ops::Range;
+use std::rc::Rc;
+
+type TapHandler = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// Flutter-style TextSpan: a run of text within a [`RichText`]
+///
+/// Spans form a tree: a span's text comes first, then its children, and
+/// each style setter applies to the span and every child that does not
+/// set it again. A span with `on_tap` is tappable over all of its text,
+/// except children with their own handler.
+///
+/// All spans share the [`RichText`]'s font size.
+///
+/// ```ignore
+/// TextSpan::new("Run ")
+///     .child(TextSpan::new("cargo test").code())
+///     .child(" or read the ")
+///     .child(TextSpan::new("guide").color(theme.colors.primary).underline().on_tap(open_guide))
+/// ```
+#[derive(Default)]
+pub struct TextSpan {
+    text: Option<SharedString>,
+    style: SpanStyle,
+    children: Vec<InlineSpan>,
+    on_tap: Option<TapHandler>,
+}
+
+/// A child of a [`TextSpan`]: more text, or an inline widget
+pub enum InlineSpan {
+    Text(TextSpan),
+    Widget(WidgetSpan),
+}
+
+/// An element such as an [`Icon`](crate::icons::Icon) laid out inline with
+/// the text of a [`RichText`], centered on its line
+///
+/// The text leaves room for the widget's width, rounded up to a whole
+/// number of spaces.
+pub struct WidgetSpan {
+    child: AnyElement,
+}
+
+impl WidgetSpan {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+        }
+    }
+}
+
+impl From<TextSpan> for InlineSpan {
+    fn from(span: TextSpan) -> Self {
+        InlineSpan::Text(span)
+    }
+}
+
+impl From<WidgetSpan> for InlineSpan {
+    fn from(span: WidgetSpan) -> Self {
+        InlineSpan::Widget(span)
+    }
+}
+
+impl From<&'static str> for InlineSpan {
+    fn from(text: &'static str) -> Self {
+        InlineSpan::Text(TextSpan::new(text))
+    }
+}
+
+impl From<String> for InlineSpan {
+    fn from(text: String) -> Self {
+        InlineSpan::Text(TextSpan::new(text))
+    }
+}
+
+impl From<SharedString> for InlineSpan {
+    fn from(text: SharedString) -> Self {
+        InlineSpan::Text(TextSpan::new(text))
+    }
+}
+
+/// Style set on a span; unset fields are inherited from its parent
+#[derive(Debug, Clone, Default)]
+struct SpanStyle {
+    font_weight: Option<FontWeight>,
+    italic: Option<bool>,
+    color: Option<Hsla>,
+    background: Option<Hsla>,
+    underline: Option<bool>,
+    strikethrough: Option<bool>,
+    code: Option<bool>,
+}
+
+impl SpanStyle {
+    /// This style on top of the inherited `parent` style
+    fn inherit(&self, parent: &SpanStyle) -> SpanStyle {
+        SpanStyle {
+            font_weight: self.font_weight.or(parent.font_weight),
+            italic: self.italic.or(parent.italic),
+            color: self.color.or(parent.color),
+            background: self.background.or(parent.background),
+            underline: self.underline.or(parent.underline),
+            strikethrough: self.strikethrough.or(parent.strikethrough),
+            code: self.code.or(parent.code),
+        }
+    }
+
+    /// Apply to the paragraph's text style
+    fn resolve(&self, base: &gpui::TextStyle, theme: &Theme) -> gpui::TextStyle {
+        let mut style = base.clone();
+        if self.code == Some(true) {
+            style.font_family = theme.typography.font_mono.clone().into();
+            style.background_color = Some(theme.colors.muted.into());
+        }
+        if let Some(weight) = self.font_weight {
+            style.font_weight = weight;
+        }
+        if let Some(italic) = self.italic {
+            style.font_style = if italic {
+                FontStyle::Italic
+            } else {
+                FontStyle::Normal
+            };
+        }
+        if let Some(color) = self.color {
+            style.color = color;
+        }
+        if let Some(background) = self.background {
+            style.background_color = Some(background);
+        }
+        if self.underline == Some(true) {
+            style.underline = Some(UnderlineStyle {
+                thickness: px(1.),
+                ..Default::default()
+            });
+        }
+        if self.strikethrough == Some(true) {
+            style.strikethrough = Some(StrikethroughStyle {
+                thickness: px(1.),
+                ..Default::default()
+            });
+        }
+        style
+    }
+}
+
+impl TextSpan {
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn child(mut self, child: impl Into<InlineSpan>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = impl Into<InlineSpan>>) -> Self {
+        self.children.extend(children.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.style.font_weight = Some(weight);
+        self
+    }
+
+    pub fn bold(self) -> Self {
+        self.weight(FontWeight::BOLD)
+    }
+
+    pub fn italic(mut self) -> Self {
+        self.style.italic = Some(true);
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.style.color = Some(color.into());
+        self
+    }
+
+    pub fn background(mut self, color: impl Into<Hsla>) -> Self {
+        self.style.background = Some(color.into());
+        self
+    }
+
+    pub fn underline(mut self) -> Self {
+        self.style.underline = Some(true);
+        self
+    }
+
+    pub fn strikethrough(mut self) -> Self {
+        self.style.strikethrough = Some(true);
+        self
+    }
+
+    /// Inline code: the theme's monospace font on its muted background
+    pub fn code(mut self) -> Self {
+        self.style.code = Some(true);
+        self
+    }
+
+    /// Make the span tappable, as for a link
+    pub fn on_tap(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_tap = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// A span tree flattened into a single string
+#[derive(Default)]
+struct Flattened {
+    text: String,
+    /// Consecutive styled ranges covering all of `text`
+    styles: Vec<(Range<usize>, SpanStyle)>,
+    /// Disjoint tappable ranges, each with the innermost handler
+    taps: Vec<(Range<usize>, TapHandler)>,
+    /// Inline widgets and the placeholder ranges reserved for them
+    widgets: Vec<(Range<usize>, AnyElement)>,
+}
+
+impl Flattened {
+    fn push(&mut self, text: &str, style: &SpanStyle, on_tap: Option<&TapHandler>) -> Range<usize> {
+        let range = self.text.len()..self.text.len() + text.len();
+        self.text.push_str(text);
+        self.styles.push((range.clone(), style.clone()));
+        if let Some(on_tap) = on_tap {
+            match self.taps.last_mut() {
+                Some((last, handler)) if last.end == range.start && Rc::ptr_eq(handler, on_tap) => {
+                    last.end = range.end
+                }
+                _ => self.taps.push((range.clone(), on_tap.clone())),
+            }
+        }
+        range
+    }
+
+    /// Flatten `span` under the inherited style and tap handler
+    ///
+    /// `placeholder` measures a widget and returns how many spaces to
+    /// reserve for it.
+    fn add(
+        &mut self,
+        span: TextSpan,
+        inherited: &SpanStyle,
+        inherited_tap: Option<&TapHandler>,
+        placeholder: &mut dyn FnMut(&mut AnyElement) -> usize,
+    ) {
+        let style = span.style.inherit(inherited);
+        let on_tap = span.on_tap.as_ref().or(inherited_tap);
+        if let Some(text) = &span.text {
+            self.push(text, &style, on_tap);
+        }
+        for child in span.children {
+            match child {
+                InlineSpan::Text(child) => self.add(child, &style, on_tap, placeholder),
+                InlineSpan::Widget(mut widget) => {
+                    let spaces = "\u{a0}".repeat(placeholder(&mut widget.child));
+                    let range = self.push(&spaces, &style, on_tap);
+                    self.widgets.push((range, widget.child));
+                }
+            }
+        }
+    }
+}
+
+/// Flutter-style RichText: one paragraph of differently styled spans
+///
+/// The paragraph is laid out in a [`TextStyle`] (the theme's body style by
+/// default), which the [`TextSpan`] tree then varies span by span. Taps
+/// within a span's text call its `on_tap`, and tappable text shows a
+/// pointer cursor.
+///
+/// ```ignore
+/// RichText::new(
+///     TextSpan::new("Saved to ")
+///         .child(TextSpan::new("~/notes.md").code())
+///         .child(WidgetSpan::new(Icon::new(LucideIcon::Check).size(IconSize::Sm))),
+/// )
+/// ```
+pub struct RichText {
+    id: ElementId,
+    span: Option<TextSpan>,
+    style: Option<TextStyle>,
+    text_scale_factor: Option<f32>,
+    /// The laid-out paragraph and inline widgets, kept until paint
+    state: Option<RichTextState>,
+}
+
+impl RichText {
+    /// Without an explicit [`id`](Self::id), the text is keyed by the call
+    /// site.
+    #[track_caller]
+    pub fn new(span: TextSpan) -> Self {
+        Self {
+            id: ElementId::CodeLocation(*core::panic::Location::caller()),
+            span: Some(span),
+            style: None,
+            text_scale_factor: None,
+            state: None,
+        }
+    }
+
+    /// Key under which tap state is kept between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Style of the paragraph, which spans inherit
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn text_scale_factor(mut self, factor: f32) -> Self {
+        self.text_scale_factor = Some(factor.max(0.1));
+        self
+    }
+}
+
+struct RichTextState {
+    paragraph: AnyElement,
+    layout: TextLayout,
+    widgets: Vec<(Range<usize>, AnyElement, Size<Pixels>)>,
+}
+
+impl IntoElement for RichText {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for RichText {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let theme = cx.theme();
+        let style = self
+            .style
+            .clone()
+            .unwrap_or_else(|| TextStyle::body(&theme));
+        let scale = self
+            .text_scale_factor
+            .unwrap_or_else(|| MediaQuery::of(window, cx).text_scale_factor);
+        let font_size = style.font_size * scale;
+
+        let mut base = window.text_style();
+        base.font_family = style.font_family.clone();
+        base.font_weight = style.font_weight;
+        base.font_style = if style.italic {
+            FontStyle::Italic
+        } else {
+            FontStyle::Normal
+        };
+        base.color = style.color;
+
+        let text_system = window.text_system().clone();
+        let space_width = text_system
+            .advance(text_system.resolve_font(&base.font()), font_size, ' ')
+            .map_or(font_size / 4., |advance| advance.width);
+        let mut sizes = Vec::new();
+        let mut flattened = Flattened::default();
+        let span = self.span.take().unwrap_or_default();
+        flattened.add(span, &SpanStyle::default(), None, &mut |widget| {
+            let size = widget.layout_as_root(
+                size(AvailableSpace::MaxContent, AvailableSpace::MaxContent),
+                window,
+                cx,
+            );
+            sizes.push(size);
+            (size.width / space_width).ceil().max(1.) as usize
+        });
+
+        let runs = flattened
+            .styles
+            .iter()
+            .map(|(range, span_style)| span_style.resolve(&base, &theme).to_run(range.len()))
+            .collect();
+        let text = StyledText::new(flattened.text).with_runs(runs);
+        let layout = text.layout().clone();
+        let (tap_ranges, handlers): (Vec<_>, Vec<_>) = flattened.taps.into_iter().unzip();
+        let paragraph = InteractiveText::new(self.id.clone(), text)
+            .on_click(tap_ranges, move |ix, window, cx| handlers[ix](window, cx));
+
+        let mut paragraph = style
+            .apply(div(), scale)
+            .child(paragraph)
+            .into_any_element();
+        let widgets = flattened
+            .widgets
+            .into_iter()
+            .zip(sizes)
+            .map(|((range, widget), size)| (range, widget, size))
+            .collect();
+
+        let layout_id = paragraph.request_layout(window, cx);
+        self.state = Some(RichTextState {
+            paragraph,
+            layout,
+            widgets,
+        });
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let Some(state) = self.state.as_mut() else {
+            return;
+        };
+        state.paragraph.prepaint(window, cx);
+
+        let line_height = state.layout.line_height();
+        for (range, widget, size) in &mut state.widgets {
+            let Some(position) = state.layout.position_for_index(range.start) else {
+                continue;
+            };
+            let origin = position + point(px(0.), (line_height - size.height) / 2.);
+            widget.prepaint_at(origin, window, cx);
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let Some(state) = self.state.as_mut() else {
+            return;
+        };
+        state.paragraph.paint(window, cx);
+        for (_, widget, _) in &mut state.widgets {
+            widget.paint(window, cx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RichText, SpanStyle, TextSpan, WidgetSpan};
+    use crate::test_util::{draw_view, ProbeBounds};
+    use gpui::{canvas, point, px, size, FontWeight, Modifiers, Styled, TestAppContext};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_flatten_inherits_styles_and_innermost_taps() {
+        let span = TextSpan::new("see ")
+            .bold()
+            .on_tap(|_, _| {})
+            .child(TextSpan::new("this").italic().on_tap(|_, _| {}))
+            .child(" and ")
+            .child(TextSpan::new("that").weight(FontWeight::NORMAL));
+
+        let mut flattened = super::Flattened::default();
+        flattened.add(span, &SpanStyle::default(), None, &mut |_| 0);
+
+        assert_eq!(flattened.text, "see this and that");
+        let styles: Vec<_> = flattened
+            .styles
+            .iter()
+            .map(|(range, style)| (range.clone(), style.font_weight, style.italic))
+            .collect();
+        assert_eq!(
+            styles,
+            [
+                (0..4, Some(FontWeight::BOLD), None),
+                (4..8, Some(FontWeight::BOLD), Some(true)),
+                (8..13, Some(FontWeight::BOLD), None),
+                (13..17, Some(FontWeight::NORMAL), None),
+            ]
+        );
+        // The inner link splits its parent's tappable range
+        let taps: Vec<_> = flattened
+            .taps
+            .iter()
+            .map(|(range, _)| range.clone())
+            .collect();
+        assert_eq!(taps, [0..4, 4..8, 8..17]);
+    }
+
+    #[gpui::test]
+    fn test_rich_text_taps_and_places_widgets(cx: &mut TestAppContext) {
+        let taps = Rc::new(Cell::new(0));
+        let icon_bounds = ProbeBounds::default();
+        let (counter, icon) = (taps.clone(), icon_bounds.clone());
+
+        let cx = draw_view(cx, size(px(400.), px(100.)), move |_, _| {
+            let (counter, icon) = (counter.clone(), icon.clone());
+            RichText::new(
+                TextSpan::new("ab")
+                    .child(WidgetSpan::new(
+                        canvas(move |bounds, _, _| icon.set(bounds), |_, _, _, _| {}).size(px(8.)),
+                    ))
+                    .child(
+                        TextSpan::new("link").on_tap(move |_, _| counter.set(counter.get() + 1)),
+                    ),
+            )
+            .text_scale_factor(1.)
+        });
+
+        // The icon sits after "ab", centered on the 24px line
+        let icon = icon_bounds.get();
+        assert!(icon.origin.x > px(0.));
+        assert_eq!(icon.origin.y, px(8.));
+
+        // Text before the link is not tappable; the link is
+        cx.simulate_click(point(px(1.), px(12.)), Modifiers::default());
+        assert_eq!(taps.get(), 0);
+        cx.simulate_click(point(px(398.), px(12.)), Modifiers::default());
+        assert_eq!(taps.get(), 0, "past the end of the text");
+        let link_x = icon.right() + px(12.);
+        cx.simulate_click(point(link_x, px(12.)), Modifiers::default());
+        assert_eq!(taps.get(), 1);
+    }
+}
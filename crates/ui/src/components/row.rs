@@ -1,3 +1,126 @@
 // crates/ui/src/components/row.rs
 
-pub struct Row;
+use super::flex::Flex;
+use crate::layout::{CrossAxisAlignment, MainAxisAlignment, MainAxisSize, TextDirection};
+use gpui::*;
+
+/// Flutter-style Row: children laid out horizontally
+///
+/// Children run from the leading edge, so the first child is on the right
+/// in RTL and `MainAxisAlignment::Start` packs them against the right edge.
+/// The direction comes from the enclosing
+/// [`Directionality`](crate::layout::Directionality) unless set with
+/// `text_direction`.
+///
+/// Like Flutter, a Row fills the available width (`MainAxisSize::Max`) and
+/// centers its children vertically by default.
+#[derive(IntoElement)]
+pub struct Row {
+    flex: Flex,
+}
+
+impl Row {
+    pub fn new() -> Self {
+        Self {
+            flex: Flex::new(Axis::Horizontal),
+        }
+    }
+
+    pub fn main_axis_alignment(mut self, alignment: MainAxisAlignment) -> Self {
+        self.flex.main_axis_alignment = alignment;
+        self
+    }
+
+    pub fn cross_axis_alignment(mut self, alignment: CrossAxisAlignment) -> Self {
+        self.flex.cross_axis_alignment = alignment;
+        self
+    }
+
+    pub fn main_axis_size(mut self, size: MainAxisSize) -> Self {
+        self.flex.main_axis_size = size;
+        self
+    }
+
+    /// Gap between consecutive children
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.flex.spacing = px(spacing);
+        self
+    }
+
+    pub fn text_direction(mut self, direction: TextDirection) -> Self {
+        self.flex.text_direction = Some(direction);
+        self
+    }
+
+    /// Insert an element built by `separator` between every two children,
+    /// such as `separated_by(VerticalDivider::new)`
+    pub fn separated_by<E: IntoElement>(mut self, separator: impl Fn() -> E + 'static) -> Self {
+        self.flex.separator = Some(Box::new(move || separator().into_any_element()));
+        self
+    }
+}
+
+impl Default for Row {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentElement for Row {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.flex.children.extend(elements);
+    }
+}
+
+impl RenderOnce for Row {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        self.flex.render(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Row;
+    use crate::layout::{Directionality, MainAxisAlignment, MainAxisSize, TextDirection};
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{point, px, size, ParentElement, TestAppContext};
+
+    #[gpui::test]
+    fn test_row_runs_from_leading_edge(cx: &mut TestAppContext) {
+        let (first, first_bounds) = sized_probe(10., 10.);
+        let (second, second_bounds) = sized_probe(20., 20.);
+
+        draw(cx, size(px(100.), px(20.)), move |_, _| {
+            Row::new().spacing(5.).child(first).child(second)
+        });
+
+        assert_eq!(first_bounds.get().origin, point(px(0.), px(5.)));
+        assert_eq!(second_bounds.get().origin, point(px(15.), px(0.)));
+    }
+
+    #[gpui::test]
+    fn test_row_mirrors_in_rtl(cx: &mut TestAppContext) {
+        let (first, first_bounds) = sized_probe(10., 10.);
+        let (second, second_bounds) = sized_probe(20., 20.);
+        let (end, end_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(100.), px(40.)), move |_, _| {
+            gpui::div()
+                .child(Directionality::rtl(
+                    Row::new().spacing(5.).child(first).child(second),
+                ))
+                .child(
+                    Row::new()
+                        .text_direction(TextDirection::Rtl)
+                        .main_axis_alignment(MainAxisAlignment::End)
+                        .main_axis_size(MainAxisSize::Max)
+                        .child(end),
+                )
+        });
+
+        assert_eq!(first_bounds.get().origin, point(px(90.), px(5.)));
+        assert_eq!(second_bounds.get().origin, point(px(65.), px(0.)));
+        // The trailing edge is on the left
+        assert_eq!(end_bounds.get().origin, point(px(0.), px(20.)));
+    }
+}
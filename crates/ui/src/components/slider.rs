@@ -0,0 +1,891 @@
+// crates/ui/src/components/slider.rs
+
+use crate::accessibility::{AccessibilityProps, Accessible, AriaRole};
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type ValueHandler<V> = Rc<dyn Fn(&V, &mut Window, &mut App)>;
+type ValuesHandler = Rc<dyn Fn(&[f32], &mut Window, &mut App)>;
+type LabelFormat = Rc<dyn Fn(f32) -> SharedString>;
+
+/// Side of a slider thumb
+const THUMB_SIZE: Pixels = px(16.);
+/// Thickness of the track the thumbs slide along
+const TRACK_HEIGHT: Pixels = px(6.);
+
+/// The values a slider can take: `min` to `max`, in steps when set
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct SliderRange {
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+}
+
+impl SliderRange {
+    fn span(&self) -> f32 {
+        self.max - self.min
+    }
+
+    /// Clamp `value` into the range and round it to the nearest step
+    fn snap(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        match self.step.filter(|step| *step > 0.) {
+            Some(step) => (self.min + ((value - self.min) / step).round() * step).min(self.max),
+            None => value,
+        }
+    }
+
+    /// Position of `value` along the track, from 0 at `min` to 1 at `max`
+    fn fraction(&self, value: f32) -> f32 {
+        if self.span() <= 0. {
+            return 0.;
+        }
+        ((value - self.min) / self.span()).clamp(0., 1.)
+    }
+
+    fn value_at(&self, fraction: f32) -> f32 {
+        self.snap(self.min + self.span() * fraction.clamp(0., 1.))
+    }
+
+    /// Change made by an arrow key: one step, or a hundredth of the range
+    fn arrow_step(&self) -> f32 {
+        self.step
+            .filter(|step| *step > 0.)
+            .unwrap_or(self.span() / 100.)
+    }
+
+    /// Change made by Page Up and Page Down: a tenth of the range
+    fn page_step(&self) -> f32 {
+        (self.span() / 10.).max(self.arrow_step())
+    }
+
+    /// `values` with the thumb at `thumb` moved to `value`, kept from
+    /// crossing its neighbours
+    fn move_thumb(&self, values: &[f32], thumb: usize, value: f32) -> Vec<f32> {
+        let low = thumb
+            .checked_sub(1)
+            .map_or(self.min, |previous| values[previous]);
+        let high = values.get(thumb + 1).copied().unwrap_or(self.max);
+        let mut values = values.to_vec();
+        values[thumb] = self.snap(value).clamp(low, high);
+        values
+    }
+}
+
+/// Per-slider state kept between frames
+#[derive(Default)]
+struct SliderState {
+    /// Focus handle of each thumb
+    handles: Vec<FocusHandle>,
+    drag: Option<Drag>,
+}
+
+/// A thumb being dragged and the values as of the last pointer move
+struct Drag {
+    thumb: usize,
+    values: Vec<f32>,
+}
+
+/// What [`Slider`] and [`RangeSlider`] share: one or more thumbs on a
+/// track between `min` and `max`
+struct SliderBase {
+    id: ElementId,
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+    divisions: Option<usize>,
+    label: Option<SharedString>,
+    value_label: Option<LabelFormat>,
+    disabled: bool,
+}
+
+impl SliderBase {
+    fn new(id: ElementId) -> Self {
+        Self {
+            id,
+            min: 0.,
+            max: 1.,
+            step: None,
+            divisions: None,
+            label: None,
+            value_label: None,
+            disabled: false,
+        }
+    }
+
+    fn range(&self) -> SliderRange {
+        let step = match self.divisions.filter(|divisions| *divisions > 0) {
+            Some(divisions) => Some((self.max - self.min) / divisions as f32),
+            None => self.step,
+        };
+        SliderRange {
+            min: self.min,
+            max: self.max,
+            step,
+        }
+    }
+
+    /// Accessible name of one of `count` thumbs: the slider's label, with
+    /// "start" or "end" appended for the thumbs of a range
+    fn thumb_label(&self, thumb: usize, count: usize) -> Option<SharedString> {
+        let label = self.label.as_ref()?;
+        if count == 1 {
+            return Some(label.clone());
+        }
+        let end = if thumb == 0 { "start" } else { "end" };
+        Some(format!("{label} {end}").into())
+    }
+
+    fn render(
+        self,
+        values: Vec<f32>,
+        on_change: Option<ValuesHandler>,
+        on_change_end: Option<ValuesHandler>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Stateful<Div> {
+        let theme = cx.theme();
+        let colors = &theme.colors;
+        let range = self.range();
+        let values: Rc<[f32]> = values.into_iter().map(|value| range.snap(value)).collect();
+        let child_id = |name: SharedString| ElementId::NamedChild(Box::new(self.id.clone()), name);
+
+        let count = values.len();
+        let state = window.with_global_id(child_id("state".into()), |id, window| {
+            window.with_element_state(id, |state: Option<Rc<RefCell<SliderState>>>, _| {
+                let state = state.unwrap_or_default();
+                state
+                    .borrow_mut()
+                    .handles
+                    .resize_with(count, || cx.focus_handle());
+                (state.clone(), state)
+            })
+        });
+        let handles = state.borrow().handles.clone();
+        let dragging = state.borrow().drag.as_ref().map(|drag| drag.thumb);
+
+        // Keyboard changes end as soon as they are made
+        let set_values: Option<ValuesHandler> = (!self.disabled)
+            .then(|| {
+                let (on_change, on_change_end) = (on_change.clone(), on_change_end.clone());
+                Rc::new(move |values: &[f32], window: &mut Window, cx: &mut App| {
+                    if let Some(handler) = &on_change {
+                        handler(values, window, cx);
+                    }
+                    if let Some(handler) = &on_change_end {
+                        handler(values, window, cx);
+                    }
+                }) as ValuesHandler
+            })
+            .filter(|_| on_change.is_some() || on_change_end.is_some());
+
+        let first = range.fraction(values[0]);
+        let last = range.fraction(values[count - 1]);
+        let (active_start, active_end) = if count == 1 {
+            (0., first)
+        } else {
+            (first, last)
+        };
+        let ring: Hsla = colors.ring.into();
+
+        let thumbs = values.iter().enumerate().map(|(thumb, value)| {
+            let focused = handles[thumb].is_focused(window);
+            let label = self
+                .value_label
+                .as_ref()
+                .filter(|_| focused || dragging == Some(thumb))
+                .map(|format| format(*value));
+            let set_values = set_values.clone();
+            let values = values.clone();
+
+            div()
+                .accessibility(AccessibilityProps {
+                    role: Some(AriaRole::Slider),
+                    label: self.thumb_label(thumb, count),
+                    disabled: self.disabled,
+                    ..Default::default()
+                })
+                .id(child_id(format!("thumb-{thumb}").into()))
+                .when(!self.disabled, |thumb_div| {
+                    thumb_div.track_focus(&handles[thumb])
+                })
+                .absolute()
+                .left(relative(range.fraction(*value)))
+                .ml(-THUMB_SIZE / 2.)
+                .size(THUMB_SIZE)
+                .rounded(theme.radius.full)
+                .border_2()
+                .border_color(colors.primary)
+                .bg(Hsla::from(colors.background))
+                .shadow_sm()
+                .focus(|style| {
+                    style.shadow(vec![BoxShadow {
+                        color: ring.opacity(0.5),
+                        offset: point(px(0.), px(0.)),
+                        blur_radius: px(0.),
+                        spread_radius: px(3.),
+                    }])
+                })
+                .when_some(set_values, |thumb_div, set_values| {
+                    thumb_div.on_key_down(move |event, window, cx| {
+                        let value = values[thumb];
+                        let target = match event.keystroke.key.as_str() {
+                            "right" | "up" => value + range.arrow_step(),
+                            "left" | "down" => value - range.arrow_step(),
+                            "pageup" => value + range.page_step(),
+                            "pagedown" => value - range.page_step(),
+                            "home" => range.min,
+                            "end" => range.max,
+                            _ => return,
+                        };
+                        cx.stop_propagation();
+                        let moved = range.move_thumb(&values, thumb, target);
+                        if moved[thumb] != value {
+                            set_values(&moved, window, cx);
+                        }
+                    })
+                })
+                .when_some(label, |thumb_div, label| {
+                    thumb_div.child(
+                        div()
+                            .absolute()
+                            .bottom(relative(1.))
+                            .left(-THUMB_SIZE * 2.)
+                            .w(THUMB_SIZE * 5.)
+                            .mb(px(6.))
+                            .flex()
+                            .justify_center()
+                            .child(
+                                div()
+                                    .px(px(6.))
+                                    .py(px(2.))
+                                    .rounded(theme.radius.sm)
+                                    .bg(Hsla::from(colors.primary))
+                                    .text_color(colors.primary_foreground)
+                                    .text_size(theme.typography.size_xs)
+                                    .whitespace_nowrap()
+                                    .child(label),
+                            ),
+                    )
+                })
+        });
+        let thumbs: Vec<_> = thumbs.collect();
+
+        let rail = div()
+            .relative()
+            .flex()
+            .items_center()
+            .w_full()
+            .h(THUMB_SIZE)
+            .child(
+                div()
+                    .relative()
+                    .w_full()
+                    .h(TRACK_HEIGHT)
+                    .rounded(theme.radius.full)
+                    .bg(Hsla::from(colors.secondary))
+                    .child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .bottom_0()
+                            .left(relative(active_start))
+                            .w(relative(active_end - active_start))
+                            .rounded(theme.radius.full)
+                            .bg(Hsla::from(colors.primary)),
+                    ),
+            )
+            .children(thumbs)
+            .when(!self.disabled, |rail| {
+                rail.child(SliderDragArea {
+                    state,
+                    range,
+                    values,
+                    on_change,
+                    on_change_end,
+                })
+            });
+
+        // Room for the thumbs to overhang the ends of the track
+        div()
+            .id(self.id)
+            .w_full()
+            .px(THUMB_SIZE / 2.)
+            .when(self.disabled, |slider| {
+                slider.opacity(0.5).cursor_not_allowed()
+            })
+            .child(rail)
+    }
+}
+
+/// Overlay on a slider's track that turns presses and drags into values
+struct SliderDragArea {
+    state: Rc<RefCell<SliderState>>,
+    range: SliderRange,
+    values: Rc<[f32]>,
+    on_change: Option<ValuesHandler>,
+    on_change_end: Option<ValuesHandler>,
+}
+
+impl SliderDragArea {
+    /// Thumb a press at `fraction` grabs: the nearest one, or for thumbs on
+    /// top of each other, the one that can move toward the press
+    fn thumb_at(&self, fraction: f32) -> usize {
+        let value = self.range.value_at(fraction);
+        let mut nearest = 0;
+        for (thumb, candidate) in self.values.iter().enumerate().skip(1) {
+            let distance = (candidate - value).abs();
+            let best = (self.values[nearest] - value).abs();
+            if distance < best || (distance == best && value > *candidate) {
+                nearest = thumb;
+            }
+        }
+        nearest
+    }
+}
+
+impl IntoElement for SliderDragArea {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for SliderDragArea {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let zero: Length = px(0.).into();
+        let mut style = Style {
+            position: Position::Absolute,
+            ..Default::default()
+        };
+        style.inset.top = zero;
+        style.inset.bottom = zero;
+        style.inset.left = zero;
+        style.inset.right = zero;
+
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _state: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        _cx: &mut App,
+    ) -> Self::PrepaintState {
+        window.insert_hitbox(bounds, HitboxBehavior::Normal)
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _state: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        window: &mut Window,
+        _cx: &mut App,
+    ) {
+        window.set_cursor_style(CursorStyle::PointingHand, hitbox);
+
+        let view = window.current_view();
+        let fraction_at = move |position: Point<Pixels>| {
+            if bounds.size.width <= Pixels::ZERO {
+                return 0.;
+            }
+            ((position.x - bounds.left()) / bounds.size.width).clamp(0., 1.)
+        };
+        let area = Rc::new(SliderDragArea {
+            state: self.state.clone(),
+            range: self.range,
+            values: self.values.clone(),
+            on_change: self.on_change.clone(),
+            on_change_end: self.on_change_end.clone(),
+        });
+
+        window.on_mouse_event({
+            let area = area.clone();
+            let hitbox = hitbox.clone();
+            move |event: &MouseDownEvent, phase, window, cx| {
+                if phase != DispatchPhase::Bubble
+                    || event.button != MouseButton::Left
+                    || !hitbox.is_hovered(window)
+                {
+                    return;
+                }
+
+                let fraction = fraction_at(event.position);
+                let thumb = area.thumb_at(fraction);
+                let values =
+                    area.range
+                        .move_thumb(&area.values, thumb, area.range.value_at(fraction));
+                let handle = area.state.borrow().handles[thumb].clone();
+                window.focus(&handle);
+                if values[thumb] != area.values[thumb] {
+                    if let Some(handler) = &area.on_change {
+                        handler(&values, window, cx);
+                    }
+                }
+                area.state.borrow_mut().drag = Some(Drag { thumb, values });
+                window.prevent_default();
+                cx.stop_propagation();
+                cx.notify(view);
+            }
+        });
+
+        window.on_mouse_event({
+            let area = area.clone();
+            move |event: &MouseMoveEvent, phase, window, cx| {
+                if phase != DispatchPhase::Bubble {
+                    return;
+                }
+                let mut state = area.state.borrow_mut();
+                let Some(drag) = state.drag.as_mut() else {
+                    return;
+                };
+                if event.pressed_button != Some(MouseButton::Left) {
+                    state.drag = None;
+                    cx.notify(view);
+                    return;
+                }
+
+                let value = area.range.value_at(fraction_at(event.position));
+                let values = area.range.move_thumb(&drag.values, drag.thumb, value);
+                if values == drag.values {
+                    return;
+                }
+                drag.values = values.clone();
+                drop(state);
+                if let Some(handler) = &area.on_change {
+                    handler(&values, window, cx);
+                }
+                cx.notify(view);
+            }
+        });
+
+        window.on_mouse_event(move |event: &MouseUpEvent, phase, window, cx| {
+            if phase != DispatchPhase::Bubble || event.button != MouseButton::Left {
+                return;
+            }
+            let Some(drag) = area.state.borrow_mut().drag.take() else {
+                return;
+            };
+            if let Some(handler) = &area.on_change_end {
+                handler(&drag.values, window, cx);
+            }
+            cx.notify(view);
+        });
+    }
+}
+
+/// A shadcn-style slider choosing a number between `min` and `max`
+///
+/// The value is owned by the caller, passed in with `value` and replaced
+/// from `on_change` while the thumb is dragged or moved with the keyboard;
+/// `on_change_end` follows with the final value once the pointer is
+/// released or the key pressed. With `step` or `divisions` the value
+/// snaps to evenly spaced stops. The focused thumb moves with the arrow
+/// keys, Page Up and Page Down move it a tenth of the range, and Home and
+/// End jump to the ends. `value_label` shows the formatted value above the
+/// thumb while it is dragged or focused.
+///
+/// ```ignore
+/// Slider::new("volume")
+///     .range(0., 100.)
+///     .step(1.)
+///     .value(self.volume)
+///     .value_label(|volume| format!("{volume:.0}%").into())
+///     .on_change(cx.listener(|this, volume, _, cx| {
+///         this.volume = *volume;
+///         cx.notify();
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct Slider {
+    base: SliderBase,
+    value: f32,
+    on_change: Option<ValueHandler<f32>>,
+    on_change_end: Option<ValueHandler<f32>>,
+}
+
+/// A slider with two thumbs choosing a range between `min` and `max`
+///
+/// Works like [`Slider`], with the values passed as a `(start, end)` pair.
+/// A press on the track moves the nearest thumb, and neither thumb can be
+/// moved past the other.
+///
+/// ```ignore
+/// RangeSlider::new("price")
+///     .range(0., 500.)
+///     .divisions(50)
+///     .values(self.price)
+///     .on_change_end(cx.listener(|this, price, _, cx| this.filter_by_price(*price, cx)))
+/// ```
+#[derive(IntoElement)]
+pub struct RangeSlider {
+    base: SliderBase,
+    values: (f32, f32),
+    on_change: Option<ValueHandler<(f32, f32)>>,
+    on_change_end: Option<ValueHandler<(f32, f32)>>,
+}
+
+impl Slider {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: SliderBase::new(id.into()),
+            value: 0.,
+            on_change: None,
+            on_change_end: None,
+        }
+    }
+
+    /// Lowest and highest values; `0.` to `1.` by default
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.base.min = min;
+        self.base.max = max.max(min);
+        self
+    }
+
+    /// Snap values to multiples of `step` above `min`
+    pub fn step(mut self, step: f32) -> Self {
+        self.base.step = Some(step);
+        self
+    }
+
+    /// Snap values to `divisions` equal steps between `min` and `max`,
+    /// taking precedence over `step`
+    pub fn divisions(mut self, divisions: usize) -> Self {
+        self.base.divisions = Some(divisions);
+        self
+    }
+
+    /// Accessible name of the slider, such as the setting it adjusts
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.base.label = Some(label.into());
+        self
+    }
+
+    /// Show the value, formatted by `format`, above a dragged or
+    /// focused thumb
+    pub fn value_label(mut self, format: impl Fn(f32) -> SharedString + 'static) -> Self {
+        self.base.value_label = Some(Rc::new(format));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.base.disabled = disabled;
+        self
+    }
+
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Called with each new value while the slider moves
+    pub fn on_change(mut self, handler: impl Fn(&f32, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called with the final value when a drag or key press ends
+    pub fn on_change_end(
+        mut self,
+        handler: impl Fn(&f32, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change_end = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RangeSlider {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: SliderBase::new(id.into()),
+            values: (0., 1.),
+            on_change: None,
+            on_change_end: None,
+        }
+    }
+
+    /// Lowest and highest values; `0.` to `1.` by default
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.base.min = min;
+        self.base.max = max.max(min);
+        self
+    }
+
+    /// Snap values to multiples of `step` above `min`
+    pub fn step(mut self, step: f32) -> Self {
+        self.base.step = Some(step);
+        self
+    }
+
+    /// Snap values to `divisions` equal steps between `min` and `max`,
+    /// taking precedence over `step`
+    pub fn divisions(mut self, divisions: usize) -> Self {
+        self.base.divisions = Some(divisions);
+        self
+    }
+
+    /// Accessible name of the slider, such as the setting it adjusts
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.base.label = Some(label.into());
+        self
+    }
+
+    /// Show the value, formatted by `format`, above a dragged or
+    /// focused thumb
+    pub fn value_label(mut self, format: impl Fn(f32) -> SharedString + 'static) -> Self {
+        self.base.value_label = Some(Rc::new(format));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.base.disabled = disabled;
+        self
+    }
+
+    /// The `(start, end)` of the chosen range
+    pub fn values(mut self, values: (f32, f32)) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Called with each new range while a thumb moves
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&(f32, f32), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called with the final range when a drag or key press ends
+    pub fn on_change_end(
+        mut self,
+        handler: impl Fn(&(f32, f32), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change_end = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Slider {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let single = |handler: Option<ValueHandler<f32>>| {
+            handler.map(|handler| {
+                Rc::new(move |values: &[f32], window: &mut Window, cx: &mut App| {
+                    handler(&values[0], window, cx)
+                }) as ValuesHandler
+            })
+        };
+        self.base.render(
+            vec![self.value],
+            single(self.on_change),
+            single(self.on_change_end),
+            window,
+            cx,
+        )
+    }
+}
+
+impl RenderOnce for RangeSlider {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let pair = |handler: Option<ValueHandler<(f32, f32)>>| {
+            handler.map(|handler| {
+                Rc::new(move |values: &[f32], window: &mut Window, cx: &mut App| {
+                    handler(&(values[0], values[1]), window, cx)
+                }) as ValuesHandler
+            })
+        };
+        let (start, end) = self.values;
+        self.base.render(
+            vec![start.min(end), end.max(start)],
+            pair(self.on_change),
+            pair(self.on_change_end),
+            window,
+            cx,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RangeSlider, Slider, SliderRange};
+    use crate::test_util::{draw_view, redraw};
+    use gpui::{point, px, size, Modifiers, MouseButton, TestAppContext};
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_slider_range_snaps_and_keeps_thumbs_apart() {
+        let range = SliderRange {
+            min: 0.,
+            max: 10.,
+            step: Some(2.5),
+        };
+        assert_eq!(range.snap(3.), 2.5);
+        assert_eq!(range.snap(4.), 5.);
+        assert_eq!(range.snap(12.), 10.);
+        assert_eq!(range.value_at(0.74), 7.5);
+        assert_eq!(range.fraction(5.), 0.5);
+        assert_eq!(range.page_step(), 2.5);
+
+        assert_eq!(range.move_thumb(&[2.5, 5.], 0, 9.), vec![5., 5.]);
+        assert_eq!(range.move_thumb(&[2.5, 5.], 1, 0.), vec![2.5, 2.5]);
+        assert_eq!(range.move_thumb(&[2.5], 0, -1.), vec![0.]);
+
+        // A step that can't advance falls back to a hundredth of the range
+        for step in [0., -1.] {
+            let range = SliderRange {
+                step: Some(step),
+                ..range
+            };
+            assert_eq!(range.arrow_step(), 0.1);
+            assert_eq!(range.page_step(), 1.);
+        }
+    }
+
+    #[test]
+    fn test_thumb_labels_name_the_ends_of_a_range() {
+        let slider = Slider::new("volume").label("Volume");
+        assert_eq!(slider.base.thumb_label(0, 1), Some("Volume".into()));
+
+        let price = RangeSlider::new("price").label("Price");
+        assert_eq!(price.base.thumb_label(0, 2), Some("Price start".into()));
+        assert_eq!(price.base.thumb_label(1, 2), Some("Price end".into()));
+
+        assert_eq!(RangeSlider::new("unnamed").base.thumb_label(0, 2), None);
+    }
+
+    #[gpui::test]
+    fn test_slider_drag_and_keyboard(cx: &mut TestAppContext) {
+        let value = Rc::new(Cell::new(0.));
+        let ended = Rc::new(RefCell::new(Vec::new()));
+        let (current, ends) = (value.clone(), ended.clone());
+
+        // The track runs from x = 8 to 208, a pixel per half unit
+        let cx = draw_view(cx, size(px(216.), px(40.)), move |_, _| {
+            let (current, ends) = (current.clone(), ends.clone());
+            Slider::new("volume")
+                .range(0., 100.)
+                .step(1.)
+                .value(current.get())
+                .on_change(move |value, _, _| current.set(*value))
+                .on_change_end(move |value, _, _| ends.borrow_mut().push(*value))
+        });
+
+        cx.simulate_mouse_down(
+            point(px(58.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(value.get(), 25.);
+        cx.simulate_mouse_move(
+            point(px(108.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(value.get(), 50.);
+        // Dragging past the end of the track stops at the maximum
+        cx.simulate_mouse_move(
+            point(px(300.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(value.get(), 100.);
+        assert!(ended.borrow().is_empty());
+        cx.simulate_mouse_up(
+            point(px(300.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(*ended.borrow(), vec![100.]);
+
+        // The press focused the thumb, so the keyboard moves it
+        redraw(cx);
+        cx.simulate_keystrokes("left");
+        assert_eq!(value.get(), 99.);
+        redraw(cx);
+        cx.simulate_keystrokes("pagedown");
+        assert_eq!(value.get(), 89.);
+        redraw(cx);
+        cx.simulate_keystrokes("home");
+        assert_eq!(value.get(), 0.);
+        redraw(cx);
+        cx.simulate_keystrokes("end");
+        assert_eq!(value.get(), 100.);
+        assert_eq!(*ended.borrow(), vec![100., 99., 89., 0., 100.]);
+    }
+
+    #[gpui::test]
+    fn test_range_slider_moves_nearest_thumb(cx: &mut TestAppContext) {
+        let values = Rc::new(Cell::new((20., 60.)));
+        let current = values.clone();
+
+        let cx = draw_view(cx, size(px(216.), px(40.)), move |_, _| {
+            let current = current.clone();
+            RangeSlider::new("price")
+                .range(0., 100.)
+                .divisions(20)
+                .values(current.get())
+                .on_change(move |values, _, _| current.set(*values))
+        });
+
+        // A press at 72.5 moves the nearer end thumb, snapping to 75
+        cx.simulate_mouse_down(
+            point(px(153.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_up(
+            point(px(153.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(values.get(), (20., 75.));
+
+        // Dragging the start thumb stops at the end thumb
+        redraw(cx);
+        cx.simulate_mouse_down(
+            point(px(48.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_move(
+            point(px(200.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_up(
+            point(px(200.), px(8.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        assert_eq!(values.get(), (75., 75.));
+    }
+}
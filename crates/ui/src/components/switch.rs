@@ -0,0 +1,326 @@
+// crates/ui/src/components/switch.rs
+
+use super::button::{interactive_button, VariantColors};
+use crate::accessibility::{AccessibilityProps, Accessible, AriaChecked, AriaRole};
+use crate::icons::{Icon, IconSize};
+use crate::layout::Tween;
+use crate::media_query::MediaQuery;
+use crate::theme::ActiveTheme;
+use gpui::*;
+use std::rc::Rc;
+use std::time::Duration;
+
+type SwitchHandler = Rc<dyn Fn(&bool, &mut Window, &mut App)>;
+
+/// Width of the transparent border between the track's edge and the thumb
+const TRACK_INSET: Pixels = px(2.);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SwitchSize {
+    Sm,
+    #[default]
+    Md,
+}
+
+impl SwitchSize {
+    fn track(self) -> Size<Pixels> {
+        match self {
+            SwitchSize::Sm => size(px(36.), px(20.)),
+            SwitchSize::Md => size(px(44.), px(24.)),
+        }
+    }
+
+    fn thumb(self) -> Pixels {
+        self.track().height - TRACK_INSET * 2.
+    }
+
+    /// How far the thumb moves between off and on
+    fn travel(self) -> Pixels {
+        self.track().width - self.thumb() - TRACK_INSET * 2.
+    }
+}
+
+/// A shadcn-style on/off switch
+///
+/// The state is owned by the caller, who passes it in with `checked` and
+/// updates it from `on_change`, which receives the new state. The track
+/// takes the theme's `primary` color when on and `input` when off, and the
+/// thumb slides between the ends over the theme's fast motion duration,
+/// or jumps when the user prefers reduced motion. The switch is focusable
+/// and toggles with Space.
+///
+/// ```ignore
+/// Switch::new("wifi")
+///     .checked(self.wifi)
+///     .checked_icon(Icon::new(LucideIcon::Check))
+///     .on_change(cx.listener(|this, on, _, _| this.wifi = *on))
+/// ```
+#[derive(IntoElement)]
+pub struct Switch {
+    id: ElementId,
+    checked: bool,
+    size: SwitchSize,
+    label: Option<SharedString>,
+    checked_icon: Option<Icon>,
+    unchecked_icon: Option<Icon>,
+    on_change: Option<SwitchHandler>,
+    disabled: bool,
+}
+
+impl Switch {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            checked: false,
+            size: SwitchSize::default(),
+            label: None,
+            checked_icon: None,
+            unchecked_icon: None,
+            on_change: None,
+            disabled: false,
+        }
+    }
+
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    pub fn size(mut self, size: SwitchSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Accessible name of the switch, such as the setting it turns on
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Icon drawn on the thumb while the switch is on
+    pub fn checked_icon(mut self, icon: Icon) -> Self {
+        self.checked_icon = Some(icon);
+        self
+    }
+
+    /// Icon drawn on the thumb while the switch is off
+    pub fn unchecked_icon(mut self, icon: Icon) -> Self {
+        self.unchecked_icon = Some(icon);
+        self
+    }
+
+    /// Called with whether the switch should now be on
+    pub fn on_change(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl RenderOnce for Switch {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let colors = &theme.colors;
+        let duration = if MediaQuery::of(window, cx).reduce_motion {
+            Duration::ZERO
+        } else {
+            theme.motion.fast
+        };
+
+        // Thumb position from 0 (off) to 1 (on), eased toward the current state
+        let target = if self.checked { 1. } else { 0. };
+        let now = cx.background_executor().now();
+        let thumb_id = ElementId::NamedChild(Box::new(self.id.clone()), "thumb".into());
+        let (position, animating) = window.with_global_id(thumb_id, |id, window| {
+            window.with_element_state(id, |slide: Option<Tween>, _| {
+                let mut slide = slide.unwrap_or_else(|| Tween::settled(target, now));
+                slide.retarget(target, now, duration, &ease_in_out);
+                let position = slide.sample(now, duration, &ease_in_out);
+                ((position, slide.progress(now, duration) < 1.), slide)
+            })
+        });
+        if animating {
+            window.request_animation_frame();
+        }
+
+        let track: Hsla = if self.checked {
+            colors.primary.into()
+        } else {
+            colors.input.into()
+        };
+        let track_colors = VariantColors {
+            background: track,
+            foreground: colors.primary_foreground.into(),
+            border: Hsla::transparent_black(),
+            hover: track,
+            hover_foreground: colors.primary_foreground.into(),
+        };
+        let icon = if self.checked {
+            self.checked_icon
+        } else {
+            self.unchecked_icon
+        };
+        let icon_color: Hsla = if self.checked {
+            colors.primary.into()
+        } else {
+            colors.muted_foreground.into()
+        };
+        let next = !self.checked;
+        let on_click = self.on_change.map(|handler| {
+            Box::new(move |_: &ClickEvent, window: &mut Window, cx: &mut App| {
+                handler(&next, window, cx)
+            }) as _
+        });
+
+        let thumb = div()
+            .absolute()
+            .top_0()
+            .left(self.size.travel() * position)
+            .flex()
+            .items_center()
+            .justify_center()
+            .size(self.size.thumb())
+            .rounded(theme.radius.full)
+            .bg(Hsla::from(colors.background))
+            .shadow_sm()
+            .debug_selector(|| format!("{}-thumb", self.id))
+            .children(icon.map(|icon| icon.size(IconSize::Xs).color(icon_color).decorative()));
+        let switch = div()
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::Switch),
+                label: self.label,
+                disabled: self.disabled,
+                ..Default::default()
+            })
+            .aria_checked(if self.checked {
+                AriaChecked::True
+            } else {
+                AriaChecked::False
+            })
+            .id(self.id)
+            .relative()
+            .flex_none()
+            .w(self.size.track().width)
+            .h(self.size.track().height)
+            .rounded(theme.radius.full)
+            .border(TRACK_INSET)
+            .border_color(track_colors.border)
+            .bg(track_colors.background)
+            .child(thumb);
+
+        interactive_button(
+            switch,
+            &track_colors,
+            &theme,
+            !self.disabled,
+            false,
+            on_click,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Switch, SwitchSize};
+    use crate::media_query::MediaQuery;
+    use crate::test_util::{draw_view, redraw};
+    use crate::theme::ActiveTheme;
+    use gpui::{
+        div, point, px, size, KeyUpEvent, Keystroke, Modifiers, ParentElement, Pixels, Styled,
+        TestAppContext, VisualTestContext,
+    };
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn test_switch_toggles_from_click_and_space(cx: &mut TestAppContext) {
+        let on = Rc::new(Cell::new(false));
+        let current = on.clone();
+
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, _| {
+            let current = current.clone();
+            div().flex().flex_col().children([
+                Switch::new("switch")
+                    .checked(current.get())
+                    .on_change(move |checked, _, _| current.set(*checked)),
+                Switch::new("disabled")
+                    .size(SwitchSize::Sm)
+                    .disabled(true)
+                    .on_change(|_, _, _| panic!("disabled switch changed")),
+            ])
+        });
+
+        cx.simulate_click(point(px(10.), px(12.)), Modifiers::default());
+        assert!(on.get());
+
+        redraw(cx);
+        cx.simulate_click(point(px(34.), px(12.)), Modifiers::default());
+        assert!(!on.get());
+
+        redraw(cx);
+        cx.update(|window, _| window.focus_next());
+        cx.run_until_parked();
+        cx.simulate_event(KeyUpEvent {
+            keystroke: Keystroke::parse("space").unwrap(),
+        });
+        assert!(on.get());
+
+        cx.simulate_click(point(px(10.), px(34.)), Modifiers::default());
+    }
+
+    #[gpui::test]
+    fn test_switch_thumb_slides_over_fast_motion(cx: &mut TestAppContext) {
+        let on = Rc::new(Cell::new(false));
+        let current = on.clone();
+
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, _| {
+            let current = current.clone();
+            Switch::new("switch")
+                .checked(current.get())
+                .on_change(move |checked, _, _| current.set(*checked))
+        });
+        let fast = cx.update(|_, cx| cx.theme().motion.fast);
+        let thumb_x = |cx: &mut VisualTestContext| -> Pixels {
+            cx.debug_bounds("switch-thumb").unwrap().origin.x
+        };
+        // Inside the 2px border, with 20px to travel
+        assert_eq!(thumb_x(cx), px(2.));
+
+        cx.simulate_click(point(px(10.), px(12.)), Modifiers::default());
+        redraw(cx);
+        assert_eq!(thumb_x(cx), px(2.));
+
+        cx.executor().advance_clock(fast / 2);
+        redraw(cx);
+        let halfway = thumb_x(cx);
+        assert!(
+            halfway > px(2.) && halfway < px(22.),
+            "thumb at {halfway:?}"
+        );
+
+        cx.executor().advance_clock(fast);
+        redraw(cx);
+        assert_eq!(thumb_x(cx), px(22.));
+
+        // With reduced motion the thumb jumps to the new end
+        cx.update(|_, cx| MediaQuery::set_reduce_motion(cx, true));
+        cx.simulate_click(point(px(10.), px(12.)), Modifiers::default());
+        redraw(cx);
+        assert!(!on.get());
+        assert_eq!(thumb_x(cx), px(2.));
+    }
+
+    #[test]
+    fn test_switch_thumb_travels_inside_the_track() {
+        for switch_size in [SwitchSize::Sm, SwitchSize::Md] {
+            let track = switch_size.track();
+            let end = super::TRACK_INSET * 2. + switch_size.travel() + switch_size.thumb();
+            assert_eq!(end, track.width);
+            assert_eq!(switch_size.thumb() + super::TRACK_INSET * 2., track.height);
+        }
+    }
+}
@@ -1,3 +1,613 @@
 // crates/ui/src/components/text.rs
 
-pub struct Text;
+use crate::layout::TextDirection;
+use crate::media_query::MediaQuery;
+use crate::theme::{ActiveTheme, Theme};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use std::ops::Range;
+
+/// Flutter-style TextStyle: how a run of text looks
+///
+/// The named constructors read the theme's typography, so text follows
+/// the active theme; the setters adjust a style from there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextStyle {
+    pub font_family: SharedString,
+    pub font_size: Pixels,
+    pub font_weight: FontWeight,
+    pub color: Hsla,
+    /// Line height as a multiple of the font size
+    pub line_height: f32,
+    pub italic: bool,
+}
+
+impl TextStyle {
+    /// Running text: base size, normal weight, foreground color
+    pub fn body(theme: &Theme) -> Self {
+        let typography = &theme.typography;
+        Self {
+            font_family: typography.font_family.clone().into(),
+            font_size: typography.size_base,
+            font_weight: FontWeight(typography.weight_normal as f32),
+            color: theme.colors.foreground.into(),
+            line_height: 1.5,
+            italic: false,
+        }
+    }
+
+    /// Section and page headings
+    pub fn headline(theme: &Theme) -> Self {
+        Self::body(theme)
+            .size(theme.typography.size_xl)
+            .weight(FontWeight(theme.typography.weight_bold as f32))
+            .line_height(1.25)
+    }
+
+    /// Headings of cards, dialogs and list items
+    pub fn title(theme: &Theme) -> Self {
+        Self::body(theme)
+            .size(theme.typography.size_lg)
+            .weight(FontWeight(theme.typography.weight_semibold as f32))
+            .line_height(1.25)
+    }
+
+    /// Labels of controls and form fields
+    pub fn label(theme: &Theme) -> Self {
+        Self::body(theme)
+            .size(theme.typography.size_sm)
+            .weight(FontWeight(theme.typography.weight_medium as f32))
+    }
+
+    /// Secondary text in the muted foreground color
+    pub fn muted(theme: &Theme) -> Self {
+        Self::body(theme)
+            .size(theme.typography.size_sm)
+            .color(theme.colors.muted_foreground)
+    }
+
+    /// Fine print: the smallest size, muted
+    pub fn caption(theme: &Theme) -> Self {
+        Self::muted(theme).size(theme.typography.size_xs)
+    }
+
+    /// Code and other monospaced text
+    pub fn code(theme: &Theme) -> Self {
+        Self {
+            font_family: theme.typography.font_mono.clone().into(),
+            ..Self::body(theme).size(theme.typography.size_sm)
+        }
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.font_size = size.into();
+        self
+    }
+
+    pub fn weight(mut self, weight: FontWeight) -> Self {
+        self.font_weight = weight;
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    /// Apply the style to the text of an element
+    pub(crate) fn apply<E: Styled>(&self, element: E, scale: f32) -> E {
+        let element = element
+            .font_family(self.font_family.clone())
+            .text_size(self.font_size * scale)
+            .font_weight(self.font_weight)
+            .text_color(self.color)
+            .line_height(relative(self.line_height));
+        if self.italic {
+            element.italic()
+        } else {
+            element
+        }
+    }
+}
+
+/// How text that does not fit within `max_lines` or its width is cut off
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextOverflow {
+    /// Cut off at the edge
+    #[default]
+    Clip,
+    /// End the last visible line with "…"
+    Ellipsis,
+    /// Fade the end of the last visible line into the background
+    Fade,
+}
+
+/// Width of the fade at the end of overflowing text, in multiples of the font size
+const FADE_WIDTH: f32 = 3.;
+
+/// Flutter-style Text widget
+///
+/// Displays a string in a [`TextStyle`], by default the theme's body style.
+/// The named constructors ([`Text::headline`], [`Text::muted`], ...) pick
+/// the matching theme style. Font sizes are multiplied by the
+/// `text_scale_factor`, which defaults to [`MediaQuery`]'s.
+///
+/// With `max_lines`, or `soft_wrap(false)` for a single line, text that
+/// does not fit is cut off as set by `overflow`. Alignment defaults to the
+/// leading edge for the current [`TextDirection`].
+///
+/// A `selectable` text can be selected by dragging and copied with
+/// Cmd/Ctrl+C; Cmd/Ctrl+A selects all of it.
+///
+/// ```ignore
+/// Text::headline("Settings")
+/// Text::new(description).max_lines(2).overflow(TextOverflow::Ellipsis)
+/// Text::muted(path).selectable(true)
+/// ```
+#[derive(IntoElement)]
+pub struct Text {
+    id: ElementId,
+    content: SharedString,
+    base_style: fn(&Theme) -> TextStyle,
+    style: Option<TextStyle>,
+    max_lines: Option<usize>,
+    overflow: TextOverflow,
+    text_align: Option<TextAlign>,
+    soft_wrap: bool,
+    text_scale_factor: Option<f32>,
+    selectable: bool,
+}
+
+impl Text {
+    /// Without an explicit [`id`](Self::id), the selection of a selectable
+    /// text is keyed by the call site.
+    #[track_caller]
+    pub fn new(content: impl Into<SharedString>) -> Self {
+        Self {
+            id: ElementId::CodeLocation(*core::panic::Location::caller()),
+            content: content.into(),
+            base_style: TextStyle::body,
+            style: None,
+            max_lines: None,
+            overflow: TextOverflow::default(),
+            text_align: None,
+            soft_wrap: true,
+            text_scale_factor: None,
+            selectable: false,
+        }
+    }
+
+    #[track_caller]
+    pub fn headline(content: impl Into<SharedString>) -> Self {
+        Self::new(content).with_base_style(TextStyle::headline)
+    }
+
+    #[track_caller]
+    pub fn title(content: impl Into<SharedString>) -> Self {
+        Self::new(content).with_base_style(TextStyle::title)
+    }
+
+    #[track_caller]
+    pub fn body(content: impl Into<SharedString>) -> Self {
+        Self::new(content)
+    }
+
+    #[track_caller]
+    pub fn label(content: impl Into<SharedString>) -> Self {
+        Self::new(content).with_base_style(TextStyle::label)
+    }
+
+    #[track_caller]
+    pub fn muted(content: impl Into<SharedString>) -> Self {
+        Self::new(content).with_base_style(TextStyle::muted)
+    }
+
+    #[track_caller]
+    pub fn caption(content: impl Into<SharedString>) -> Self {
+        Self::new(content).with_base_style(TextStyle::caption)
+    }
+
+    #[track_caller]
+    pub fn code(content: impl Into<SharedString>) -> Self {
+        Self::new(content).with_base_style(TextStyle::code)
+    }
+
+    fn with_base_style(mut self, base_style: fn(&Theme) -> TextStyle) -> Self {
+        self.base_style = base_style;
+        self
+    }
+
+    /// Key under which the selection is kept between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Replace the theme style picked by the constructor
+    pub fn style(mut self, style: TextStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines.max(1));
+        self
+    }
+
+    pub fn overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn text_align(mut self, align: TextAlign) -> Self {
+        self.text_align = Some(align);
+        self
+    }
+
+    /// Whether lines wrap at the available width; without wrapping the
+    /// text is a single line, cut off as set by `overflow`
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    pub fn text_scale_factor(mut self, factor: f32) -> Self {
+        self.text_scale_factor = Some(factor.max(0.1));
+        self
+    }
+
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// Lines shown before the text is cut off, if any
+    fn line_limit(&self) -> Option<usize> {
+        if self.soft_wrap {
+            self.max_lines
+        } else {
+            Some(1)
+        }
+    }
+
+    /// The styled, aligned and clipped block that shows `text`
+    fn paragraph(&self, text: StyledText, window: &Window, cx: &App) -> Div {
+        let theme = cx.theme();
+        let style = self
+            .style
+            .clone()
+            .unwrap_or_else(|| (self.base_style)(&theme));
+        let scale = self
+            .text_scale_factor
+            .unwrap_or_else(|| MediaQuery::of(window, cx).text_scale_factor);
+        let align = self.text_align.unwrap_or(match TextDirection::of(cx) {
+            TextDirection::Ltr => TextAlign::Left,
+            TextDirection::Rtl => TextAlign::Right,
+        });
+        let line_limit = self.line_limit();
+        let layout = text.layout().clone();
+
+        style
+            .apply(div(), scale)
+            .relative()
+            .text_align(align)
+            .when(!self.soft_wrap, |element| element.whitespace_nowrap())
+            .when_some(line_limit, |element, lines| {
+                element.line_clamp(lines).overflow_hidden()
+            })
+            .when(self.overflow == TextOverflow::Ellipsis, |element| {
+                element.text_ellipsis()
+            })
+            .child(text)
+            .when_some(
+                line_limit.filter(|_| self.overflow == TextOverflow::Fade),
+                |element, lines| {
+                    let background: Hsla = theme.colors.background.into();
+                    let font_size = style.font_size * scale;
+                    let fade = size(font_size * FADE_WIDTH, font_size * style.line_height);
+                    // Whether the text overflows is only known once it is laid out
+                    let overlay = canvas(
+                        |_, _, _| {},
+                        move |bounds, _, window, _| {
+                            if !overflows(&layout, lines) {
+                                return;
+                            }
+                            let origin = bounds.bottom_right() - point(fade.width, fade.height);
+                            window.paint_quad(fill(
+                                Bounds::new(origin, fade),
+                                linear_gradient(
+                                    90.,
+                                    linear_color_stop(background.opacity(0.), 0.),
+                                    linear_color_stop(background, 1.),
+                                ),
+                            ));
+                        },
+                    );
+                    element.child(overlay.absolute().top_0().left_0().size_full())
+                },
+            )
+    }
+}
+
+/// Whether laid-out text runs past the width of its box or onto a line
+/// after the first `line_limit`
+fn overflows(layout: &TextLayout, line_limit: usize) -> bool {
+    let bounds = layout.bounds();
+    let line_height = layout.line_height();
+    layout.position_for_index(layout.len()).is_some_and(|end| {
+        end.x > bounds.right() + px(0.5)
+            || end.y - bounds.top() > line_height * (line_limit as f32 - 0.5)
+    })
+}
+
+/// Selection of a selectable [`Text`], as byte offsets into its content
+struct TextSelection {
+    focus_handle: FocusHandle,
+    /// Where the selection started
+    anchor: usize,
+    /// Where the selection ends, following the pointer while dragging
+    head: usize,
+    dragging: bool,
+}
+
+impl TextSelection {
+    fn range(&self) -> Range<usize> {
+        self.anchor.min(self.head)..self.anchor.max(self.head)
+    }
+}
+
+impl RenderOnce for Text {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let selection = self.selectable.then(|| {
+            window.use_keyed_state(self.id.clone(), cx, |_, cx| TextSelection {
+                focus_handle: cx.focus_handle(),
+                anchor: 0,
+                head: 0,
+                dragging: false,
+            })
+        });
+        let highlight = selection
+            .as_ref()
+            .map(|selection| selection.read(cx).range())
+            .filter(|range| !range.is_empty())
+            .map(|range| {
+                let highlight = HighlightStyle {
+                    background_color: Some(Hsla::from(theme.colors.primary).opacity(0.25)),
+                    ..Default::default()
+                };
+                (range, highlight)
+            });
+        let text = StyledText::new(self.content.clone()).with_highlights(highlight);
+        let layout = text.layout().clone();
+        let element = self.paragraph(text, window, cx).id(self.id);
+
+        let Some(selection) = selection else {
+            return element;
+        };
+        let content = self.content;
+        let index_at = move |position| {
+            layout
+                .index_for_position(position)
+                .unwrap_or_else(|index| index)
+        };
+        let focus_handle = selection.read(cx).focus_handle.clone();
+
+        element
+            .track_focus(&focus_handle)
+            .cursor(CursorStyle::IBeam)
+            .on_mouse_down(MouseButton::Left, {
+                let selection = selection.clone();
+                let index_at = index_at.clone();
+                move |event, _, cx| {
+                    let index = index_at(event.position);
+                    selection.update(cx, |selection, cx| {
+                        selection.anchor = index;
+                        selection.head = index;
+                        selection.dragging = true;
+                        cx.notify();
+                    });
+                }
+            })
+            .on_mouse_move({
+                let selection = selection.clone();
+                move |event, _, cx| {
+                    if !selection.read(cx).dragging
+                        || event.pressed_button != Some(MouseButton::Left)
+                    {
+                        return;
+                    }
+                    let index = index_at(event.position);
+                    selection.update(cx, |selection, cx| {
+                        if selection.head != index {
+                            selection.head = index;
+                            cx.notify();
+                        }
+                    });
+                }
+            })
+            .on_mouse_up(MouseButton::Left, {
+                let selection = selection.clone();
+                move |_, _, cx| selection.update(cx, |selection, _| selection.dragging = false)
+            })
+            .on_key_down(move |event, _, cx| {
+                let keystroke = &event.keystroke;
+                if !(keystroke.modifiers.platform || keystroke.modifiers.control) {
+                    return;
+                }
+                match keystroke.key.as_str() {
+                    "c" => {
+                        let range = selection.read(cx).range();
+                        if !range.is_empty() {
+                            cx.write_to_clipboard(ClipboardItem::new_string(
+                                content[range].to_string(),
+                            ));
+                            cx.stop_propagation();
+                        }
+                    }
+                    "a" => {
+                        selection.update(cx, |selection, cx| {
+                            selection.anchor = 0;
+                            selection.head = content.len();
+                            cx.notify();
+                        });
+                        cx.stop_propagation();
+                    }
+                    _ => {}
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{overflows, Text, TextOverflow, TextStyle};
+    use crate::test_util::{draw, draw_view, ProbeBounds};
+    use crate::theme::ActiveTheme;
+    use gpui::{
+        canvas, div, point, px, size, Hsla, Modifiers, MouseButton, ParentElement, Styled,
+        StyledText, TestAppContext, TextLayout,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Lay out each text in a 60px wide column, returning the layouts of
+    /// their paragraphs
+    fn lay_out(cx: &mut TestAppContext, texts: Vec<Text>) -> Vec<TextLayout> {
+        let layouts: Rc<RefCell<Vec<TextLayout>>> = Rc::default();
+        let recorded = layouts.clone();
+        draw(cx, size(px(60.), px(400.)), move |window, cx| {
+            div().w(px(60.)).children(texts.into_iter().map(|text| {
+                let styled = StyledText::new(text.content.clone());
+                recorded.borrow_mut().push(styled.layout().clone());
+                text.text_scale_factor(1.).paragraph(styled, window, cx)
+            }))
+        });
+        layouts.take()
+    }
+
+    #[gpui::test]
+    fn test_named_constructors_follow_theme(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let theme = cx.theme();
+            let headline = TextStyle::headline(&theme);
+            assert_eq!(headline.font_size, theme.typography.size_xl);
+            assert_eq!(
+                TextStyle::muted(&theme).color,
+                Hsla::from(theme.colors.muted_foreground)
+            );
+            assert_eq!(
+                TextStyle::code(&theme).font_family.as_ref(),
+                theme.typography.font_mono
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_max_lines_limits_height(cx: &mut TestAppContext) {
+        let after_bounds = ProbeBounds::default();
+        let after = after_bounds.clone();
+
+        draw_view(cx, size(px(60.), px(400.)), move |_, _| {
+            let after = after.clone();
+            div()
+                .w(px(60.))
+                .child(
+                    Text::new("one two three four five six seven eight nine ten")
+                        .text_scale_factor(1.)
+                        .max_lines(2)
+                        .overflow(TextOverflow::Ellipsis),
+                )
+                .child(canvas(move |b, _, _| after.set(b), |_, _, _, _| {}).size(px(1.)))
+        });
+
+        // Two lines of 16px body text at a line height of 1.5
+        assert_eq!(after_bounds.get().origin.y, px(48.));
+    }
+
+    #[gpui::test]
+    fn test_fade_only_when_text_overflows(cx: &mut TestAppContext) {
+        let texts = vec![
+            Text::new("one two").max_lines(2),
+            Text::new("one two three four five six seven eight nine ten").max_lines(2),
+            Text::new("one two three").soft_wrap(false),
+            Text::new("one\ntwo\nthree").max_lines(2),
+        ];
+        let limits: Vec<_> = texts
+            .iter()
+            .map(|text| text.line_limit().unwrap())
+            .collect();
+        let texts = texts
+            .into_iter()
+            .map(|text| text.overflow(TextOverflow::Fade))
+            .collect();
+
+        let faded: Vec<_> = lay_out(cx, texts)
+            .iter()
+            .zip(limits)
+            .map(|(layout, lines)| overflows(layout, lines))
+            .collect();
+        assert_eq!(faded, [false, true, true, true]);
+    }
+
+    #[gpui::test]
+    fn test_ellipsis_ends_only_overflowing_text(cx: &mut TestAppContext) {
+        let texts = vec![
+            Text::new("one").soft_wrap(false),
+            Text::new("one two three four").soft_wrap(false),
+        ];
+        let texts = texts
+            .into_iter()
+            .map(|text| text.overflow(TextOverflow::Ellipsis))
+            .collect();
+
+        let shown: Vec<_> = lay_out(cx, texts)
+            .iter()
+            .map(|layout| layout.text())
+            .collect();
+        assert_eq!(shown[0], "one");
+        assert!(shown[1].ends_with('…'), "shown {:?}", shown[1]);
+        assert!(shown[1].len() < "one two three four".len());
+    }
+
+    #[gpui::test]
+    fn test_selectable_text_copies_selection(cx: &mut TestAppContext) {
+        let cx = draw_view(cx, size(px(400.), px(100.)), |_, _| {
+            Text::new("Hello world")
+                .id("greeting")
+                .text_scale_factor(1.)
+                .selectable(true)
+        });
+
+        // Drag across everything, then copy
+        cx.simulate_mouse_down(
+            point(px(0.), px(5.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_move(
+            point(px(399.), px(5.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_up(
+            point(px(399.), px(5.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_keystrokes("ctrl-c");
+
+        let copied = cx.read_from_clipboard().and_then(|item| item.text());
+        assert_eq!(copied.as_deref(), Some("Hello world"));
+    }
+}
@@ -0,0 +1,945 @@
+// crates/ui/src/components/text_area.rs
+
+use super::input_decoration::{InputDecoration, InputState};
+use super::text_input::*;
+use crate::accessibility::{AccessibilityProps, Accessible, AriaRole};
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use std::ops::Range;
+use std::rc::Rc;
+
+actions!(
+    text_area,
+    [
+        Newline,
+        Up,
+        Down,
+        SelectUp,
+        SelectDown,
+        DocumentStart,
+        DocumentEnd,
+        SelectToDocumentStart,
+        SelectToDocumentEnd
+    ]
+);
+
+/// Key context of a focused [`TextArea`], for custom bindings
+pub const TEXT_AREA_CONTEXT: &str = "TextArea";
+
+/// Multi-line editable text that grows with its content
+///
+/// Like [`TextField`](super::TextField), the text area is a view created
+/// once with `cx.new`, with the same editing, clipboard, undo and input
+/// method support, the same [`InputDecoration`] and the same `validator`
+/// and `on_change` hooks. Its key bindings are registered once per app
+/// with [`TextArea::bind_keys`]; Enter inserts a line break.
+///
+/// Text wraps at the width of the area. The area is `min_lines` tall when
+/// empty and grows a line at a time up to `max_lines`, after which its
+/// content scrolls. `show_counter` adds a character count, against
+/// `max_length` if one is set.
+///
+/// ```ignore
+/// let comment = cx.new(|cx| {
+///     TextArea::new(window, cx)
+///         .placeholder("Add a comment…")
+///         .min_lines(2)
+///         .max_lines(6)
+///         .max_length(500)
+///         .show_counter(true)
+/// });
+/// ```
+pub struct TextArea {
+    focus_handle: FocusHandle,
+    buffer: EditBuffer,
+    placeholder: SharedString,
+    decoration: InputDecoration,
+    min_lines: usize,
+    max_lines: Option<usize>,
+    show_counter: bool,
+    validator: Option<Validator>,
+    validation_error: Option<SharedString>,
+    on_change: Option<TextHandler>,
+    scroll_y: Pixels,
+    /// Cursor and text length the scroll position was last fitted to, so
+    /// the area only scrolls to the cursor after it moves
+    scrolled_for: (usize, usize),
+    last_layout: Option<TextAreaLayout>,
+    last_bounds: Option<Bounds<Pixels>>,
+    is_selecting: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TextArea {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![
+            cx.on_focus(&focus_handle, window, |_, _, cx| cx.notify()),
+            cx.on_blur(&focus_handle, window, |this, _, cx| {
+                this.buffer.marked_range = None;
+                cx.notify()
+            }),
+        ];
+        Self {
+            focus_handle,
+            buffer: EditBuffer::default(),
+            placeholder: SharedString::default(),
+            decoration: InputDecoration::default(),
+            min_lines: 3,
+            max_lines: None,
+            show_counter: false,
+            validator: None,
+            validation_error: None,
+            on_change: None,
+            scroll_y: px(0.),
+            scrolled_for: (0, 0),
+            last_layout: None,
+            last_bounds: None,
+            is_selecting: false,
+            _subscriptions,
+        }
+    }
+
+    /// Register the text area's key bindings with the app
+    pub fn bind_keys(cx: &mut App) {
+        let context = Some(TEXT_AREA_CONTEXT);
+        let mut bindings = editing_bindings(TEXT_AREA_CONTEXT);
+        bindings.extend([
+            KeyBinding::new("enter", Newline, context),
+            KeyBinding::new("shift-enter", Newline, context),
+            KeyBinding::new("up", Up, context),
+            KeyBinding::new("down", Down, context),
+            KeyBinding::new("shift-up", SelectUp, context),
+            KeyBinding::new("shift-down", SelectDown, context),
+        ]);
+        if cfg!(target_os = "macos") {
+            bindings.extend([
+                KeyBinding::new("cmd-up", DocumentStart, context),
+                KeyBinding::new("cmd-down", DocumentEnd, context),
+                KeyBinding::new("cmd-shift-up", SelectToDocumentStart, context),
+                KeyBinding::new("cmd-shift-down", SelectToDocumentEnd, context),
+            ]);
+        } else {
+            bindings.extend([
+                KeyBinding::new("ctrl-home", DocumentStart, context),
+                KeyBinding::new("ctrl-end", DocumentEnd, context),
+                KeyBinding::new("ctrl-shift-home", SelectToDocumentStart, context),
+                KeyBinding::new("ctrl-shift-end", SelectToDocumentEnd, context),
+            ]);
+        }
+        cx.bind_keys(bindings);
+    }
+
+    /// Text shown while the area is empty
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn decoration(mut self, decoration: InputDecoration) -> Self {
+        self.decoration = decoration;
+        self
+    }
+
+    /// Height in lines while the text is shorter; 3 by default
+    pub fn min_lines(mut self, min_lines: usize) -> Self {
+        self.min_lines = min_lines.max(1);
+        self
+    }
+
+    /// Height in lines beyond which the text scrolls; unlimited by default
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines.max(1));
+        self
+    }
+
+    /// Longest text allowed, in characters; longer input is cut off
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.buffer.max_length = Some(max_length);
+        self
+    }
+
+    /// Show the number of characters below the area
+    pub fn show_counter(mut self, show_counter: bool) -> Self {
+        self.show_counter = show_counter;
+        self
+    }
+
+    /// Check the text after each change, returning an error message if it
+    /// is invalid
+    pub fn validator(mut self, validator: impl Fn(&str) -> Option<SharedString> + 'static) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Called with the new text after each edit by the user
+    pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.buffer.content
+    }
+
+    /// Replace the text, moving the cursor to its end
+    ///
+    /// Unlike an edit by the user, this does not call `on_change`, but the
+    /// replacement can be undone.
+    pub fn set_text(&mut self, text: &str, cx: &mut Context<Self>) {
+        self.buffer.set_content(&normalize_newlines(text));
+        self.validate();
+        cx.notify();
+    }
+
+    /// Replace the decoration, as when its error text changes
+    pub fn set_decoration(&mut self, decoration: InputDecoration, cx: &mut Context<Self>) {
+        self.decoration = decoration;
+        cx.notify();
+    }
+
+    /// The error shown below the area, if any
+    pub fn error(&self) -> Option<SharedString> {
+        self.decoration
+            .error()
+            .cloned()
+            .or_else(|| self.validation_error.clone())
+    }
+
+    fn validate(&mut self) {
+        self.validation_error = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator(&self.buffer.content));
+    }
+
+    fn counter(&self) -> Option<SharedString> {
+        if !self.show_counter {
+            return None;
+        }
+        let count = self.buffer.content.chars().count();
+        Some(match self.buffer.max_length {
+            Some(max_length) => format!("{count}/{max_length}").into(),
+            None => count.to_string().into(),
+        })
+    }
+
+    /// Offset one visual line above or below the cursor, at the same x
+    fn vertical_offset(&self, rows: f32) -> usize {
+        let Some(layout) = self.last_layout.as_ref() else {
+            return self.buffer.cursor();
+        };
+        let cursor = layout.position_for_offset(self.buffer.cursor());
+        let y = cursor.y + layout.line_height * (rows + 0.5);
+        if y < px(0.) {
+            0
+        } else if y >= layout.height {
+            self.buffer.content.len()
+        } else {
+            layout.offset_for_position(point(cursor.x, y))
+        }
+    }
+
+    fn line_start(&self) -> usize {
+        let cursor = self.buffer.cursor();
+        self.buffer.content[..cursor]
+            .rfind('\n')
+            .map_or(0, |ix| ix + 1)
+    }
+
+    fn line_end(&self) -> usize {
+        let cursor = self.buffer.cursor();
+        self.buffer.content[cursor..]
+            .find('\n')
+            .map_or(self.buffer.content.len(), |ix| cursor + ix)
+    }
+
+    fn newline(&mut self, _: &Newline, window: &mut Window, cx: &mut Context<Self>) {
+        self.insert("\n", window, cx);
+    }
+
+    fn up(&mut self, _: &Up, _: &mut Window, cx: &mut Context<Self>) {
+        let offset = self.vertical_offset(-1.);
+        self.select(cx, |buffer| buffer.move_to(offset));
+    }
+
+    fn down(&mut self, _: &Down, _: &mut Window, cx: &mut Context<Self>) {
+        let offset = self.vertical_offset(1.);
+        self.select(cx, |buffer| buffer.move_to(offset));
+    }
+
+    fn select_up(&mut self, _: &SelectUp, _: &mut Window, cx: &mut Context<Self>) {
+        let offset = self.vertical_offset(-1.);
+        self.select(cx, |buffer| buffer.select_to(offset));
+    }
+
+    fn select_down(&mut self, _: &SelectDown, _: &mut Window, cx: &mut Context<Self>) {
+        let offset = self.vertical_offset(1.);
+        self.select(cx, |buffer| buffer.select_to(offset));
+    }
+
+    fn home(&mut self, _: &Home, _: &mut Window, cx: &mut Context<Self>) {
+        let start = self.line_start();
+        self.select(cx, |buffer| buffer.move_to(start));
+    }
+
+    fn end(&mut self, _: &End, _: &mut Window, cx: &mut Context<Self>) {
+        let end = self.line_end();
+        self.select(cx, |buffer| buffer.move_to(end));
+    }
+
+    fn select_to_home(&mut self, _: &SelectToHome, _: &mut Window, cx: &mut Context<Self>) {
+        let start = self.line_start();
+        self.select(cx, |buffer| buffer.select_to(start));
+    }
+
+    fn select_to_end(&mut self, _: &SelectToEnd, _: &mut Window, cx: &mut Context<Self>) {
+        let end = self.line_end();
+        self.select(cx, |buffer| buffer.select_to(end));
+    }
+
+    fn document_start(&mut self, _: &DocumentStart, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.move_to(0));
+    }
+
+    fn document_end(&mut self, _: &DocumentEnd, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.move_to(buffer.content.len()));
+    }
+
+    fn select_to_document_start(
+        &mut self,
+        _: &SelectToDocumentStart,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.select(cx, |buffer| buffer.select_to(0));
+    }
+
+    fn select_to_document_end(
+        &mut self,
+        _: &SelectToDocumentEnd,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.select(cx, |buffer| buffer.select_to(buffer.content.len()));
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.is_selecting = true;
+        self.select_at(self.index_for_position(event.position), event, cx);
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if self.is_selecting {
+            let offset = self.index_for_position(event.position);
+            self.select(cx, |buffer| buffer.select_to(offset));
+        }
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut Window, _: &mut Context<Self>) {
+        self.is_selecting = false;
+    }
+
+    fn on_scroll_wheel(
+        &mut self,
+        event: &ScrollWheelEvent,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let (Some(layout), Some(bounds)) = (self.last_layout.as_ref(), self.last_bounds) else {
+            return;
+        };
+        let max_scroll = (layout.height - bounds.size.height).max(px(0.));
+        if max_scroll > px(0.) {
+            let delta = event.delta.pixel_delta(layout.line_height);
+            self.scroll_y = (self.scroll_y - delta.y).clamp(px(0.), max_scroll);
+            cx.stop_propagation();
+            cx.notify();
+        }
+    }
+
+    fn index_for_position(&self, position: Point<Pixels>) -> usize {
+        let (Some(bounds), Some(layout)) = (self.last_bounds, self.last_layout.as_ref()) else {
+            return 0;
+        };
+        if self.buffer.content.is_empty() {
+            return 0;
+        }
+        let local = position - bounds.origin + point(px(0.), self.scroll_y);
+        layout.offset_for_position(local)
+    }
+}
+
+/// Windows and classic Mac line breaks as `\n`
+fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+impl TextInput for TextArea {
+    fn buffer(&self) -> &EditBuffer {
+        &self.buffer
+    }
+
+    fn buffer_mut(&mut self) -> &mut EditBuffer {
+        &mut self.buffer
+    }
+
+    fn text_changed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.validate();
+        if let Some(handler) = self.on_change.clone() {
+            let text = self.buffer.content.clone();
+            window.defer(cx, move |window, cx| handler(&text, window, cx));
+        }
+    }
+
+    fn sanitize<'a>(&self, text: &'a str) -> std::borrow::Cow<'a, str> {
+        if text.contains('\r') {
+            normalize_newlines(text).into()
+        } else {
+            text.into()
+        }
+    }
+}
+
+impl Focusable for TextArea {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EntityInputHandler for TextArea {
+    fn text_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        actual_range: &mut Option<Range<usize>>,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<String> {
+        self.input_text_for_range(range_utf16, actual_range)
+    }
+
+    fn selected_text_range(
+        &mut self,
+        _ignore_disabled_input: bool,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<UTF16Selection> {
+        self.input_selected_text_range()
+    }
+
+    fn marked_text_range(&self, _: &mut Window, _: &mut Context<Self>) -> Option<Range<usize>> {
+        self.input_marked_text_range()
+    }
+
+    fn unmark_text(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.marked_range = None);
+    }
+
+    fn replace_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.input_replace_text_in_range(range_utf16, text, window, cx);
+    }
+
+    fn replace_and_mark_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        new_selected_range_utf16: Option<Range<usize>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.input_replace_and_mark_text_in_range(
+            range_utf16,
+            text,
+            new_selected_range_utf16,
+            window,
+            cx,
+        );
+    }
+
+    fn bounds_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        bounds: Bounds<Pixels>,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<Bounds<Pixels>> {
+        let layout = self.last_layout.as_ref()?;
+        let range = self.buffer.range_from_utf16(&range_utf16);
+        let scroll = point(px(0.), self.scroll_y);
+        let start = layout.position_for_offset(range.start);
+        let end = layout.position_for_offset(range.end);
+        let end_x = if end.y == start.y {
+            end.x
+        } else {
+            bounds.size.width
+        };
+        Some(Bounds::from_corners(
+            bounds.origin + start - scroll,
+            bounds.origin + point(end_x, start.y + layout.line_height) - scroll,
+        ))
+    }
+
+    fn character_index_for_point(
+        &mut self,
+        point: Point<Pixels>,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<usize> {
+        let bounds = self.last_bounds?;
+        bounds.localize(&point)?;
+        let offset = self.index_for_position(point);
+        Some(self.buffer.offset_to_utf16(offset))
+    }
+}
+
+impl Render for TextArea {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let focused = self.focus_handle.is_focused(window);
+        let error = self.error();
+
+        self.decoration
+            .decorate(
+                TextAreaElement { area: cx.entity() },
+                InputState {
+                    focused,
+                    error: error.clone(),
+                    counter: self.counter(),
+                    multiline: true,
+                },
+                &theme,
+            )
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::Textbox),
+                label: self.decoration.label_text().cloned(),
+                description: error.clone(),
+                invalid: error.is_some(),
+                ..Default::default()
+            })
+            .key_context(TEXT_AREA_CONTEXT)
+            .track_focus(&self.focus_handle)
+            .cursor(CursorStyle::IBeam)
+            .map(|input| on_editing_actions(input, cx))
+            .on_action(cx.listener(Self::newline))
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::select_up))
+            .on_action(cx.listener(Self::select_down))
+            .on_action(cx.listener(Self::home))
+            .on_action(cx.listener(Self::end))
+            .on_action(cx.listener(Self::select_to_home))
+            .on_action(cx.listener(Self::select_to_end))
+            .on_action(cx.listener(Self::document_start))
+            .on_action(cx.listener(Self::document_end))
+            .on_action(cx.listener(Self::select_to_document_start))
+            .on_action(cx.listener(Self::select_to_document_end))
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
+    }
+}
+
+/// Wrapped lines of a [`TextArea`] and where they sit in its content
+#[derive(Clone)]
+struct TextAreaLayout {
+    lines: Vec<WrappedLine>,
+    /// Offset in the text where each line starts
+    starts: Vec<usize>,
+    /// Top of each line in the content
+    tops: Vec<Pixels>,
+    line_height: Pixels,
+    /// Height of all the lines
+    height: Pixels,
+}
+
+impl TextAreaLayout {
+    fn new(lines: Vec<WrappedLine>, line_height: Pixels) -> Self {
+        let (mut starts, mut tops) = (Vec::new(), Vec::new());
+        let (mut start, mut top) = (0, px(0.));
+        for line in &lines {
+            starts.push(start);
+            tops.push(top);
+            // Each line but the last ends at a newline
+            start += line.len() + 1;
+            top += line.size(line_height).height;
+        }
+        Self {
+            lines,
+            starts,
+            tops,
+            line_height,
+            height: top,
+        }
+    }
+
+    /// Visual lines the text wraps to
+    fn rows(lines: &[WrappedLine]) -> usize {
+        lines
+            .iter()
+            .map(|line| line.wrap_boundaries().len() + 1)
+            .sum()
+    }
+
+    fn line_for_offset(&self, offset: usize) -> usize {
+        self.starts
+            .partition_point(|start| *start <= offset)
+            .saturating_sub(1)
+    }
+
+    /// Top left of the character at `offset`, in content coordinates
+    fn position_for_offset(&self, offset: usize) -> Point<Pixels> {
+        let ix = self.line_for_offset(offset);
+        let Some(line) = self.lines.get(ix) else {
+            return Point::default();
+        };
+        let position = line
+            .position_for_index(offset - self.starts[ix], self.line_height)
+            .unwrap_or_default();
+        point(position.x, self.tops[ix] + position.y)
+    }
+
+    /// Closest offset to a point in content coordinates
+    fn offset_for_position(&self, position: Point<Pixels>) -> usize {
+        let ix = self
+            .tops
+            .partition_point(|top| *top <= position.y)
+            .saturating_sub(1);
+        let Some(line) = self.lines.get(ix) else {
+            return 0;
+        };
+        let height = line.size(self.line_height).height;
+        let y = (position.y - self.tops[ix]).clamp(px(0.), height - px(1.));
+        let index = match line.closest_index_for_position(point(position.x, y), self.line_height) {
+            Ok(index) | Err(index) => index,
+        };
+        self.starts[ix] + index
+    }
+}
+
+/// The text inside a [`TextArea`], with its selection and cursor
+struct TextAreaElement {
+    area: Entity<TextArea>,
+}
+
+struct TextAreaPrepaint {
+    layout: TextAreaLayout,
+    scroll_y: Pixels,
+    selection: Vec<PaintQuad>,
+    cursor: PaintQuad,
+}
+
+impl TextAreaElement {
+    /// The text to lay out, with the style of each run
+    fn text_and_runs(&self, window: &Window, cx: &App) -> (SharedString, Vec<TextRun>) {
+        let area = self.area.read(cx);
+        let style = window.text_style();
+        let (text, color) = if area.buffer.content.is_empty() {
+            (
+                area.placeholder.clone(),
+                cx.theme().colors.muted_foreground.into(),
+            )
+        } else {
+            (SharedString::from(area.buffer.content.clone()), style.color)
+        };
+        let run = TextRun {
+            len: text.len(),
+            font: style.font(),
+            color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let runs = match area.buffer.marked_range.clone() {
+            Some(marked) => [
+                (0..marked.start, None),
+                (
+                    marked.clone(),
+                    Some(UnderlineStyle {
+                        color: Some(color),
+                        thickness: px(1.),
+                        wavy: false,
+                    }),
+                ),
+                (marked.end..text.len(), None),
+            ]
+            .into_iter()
+            .filter(|(range, _)| !range.is_empty())
+            .map(|(range, underline)| TextRun {
+                len: range.len(),
+                underline,
+                ..run.clone()
+            })
+            .collect(),
+            None => vec![run],
+        };
+        (text, runs)
+    }
+}
+
+impl IntoElement for TextAreaElement {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for TextAreaElement {
+    type RequestLayoutState = ();
+    type PrepaintState = TextAreaPrepaint;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, ()) {
+        let (text, runs) = self.text_and_runs(window, cx);
+        let area = self.area.read(cx);
+        let (min_lines, max_lines) = (area.min_lines, area.max_lines.unwrap_or(usize::MAX));
+        let font_size = window.text_style().font_size.to_pixels(window.rem_size());
+        let line_height = window.line_height();
+        let style = Style {
+            size: Size {
+                width: relative(1.).into(),
+                height: Length::Auto,
+            },
+            ..Default::default()
+        };
+
+        let layout_id =
+            window.request_measured_layout(style, move |known, available, window, _| {
+                let width = known.width.or(match available.width {
+                    AvailableSpace::Definite(width) => Some(width),
+                    _ => None,
+                });
+                let rows = window
+                    .text_system()
+                    .shape_text(text.clone(), font_size, &runs, width, None)
+                    .map_or(1, |lines| TextAreaLayout::rows(&lines));
+                let rows = rows.clamp(min_lines, max_lines.max(min_lines));
+                size(width.unwrap_or_default(), line_height * rows as f32)
+            });
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut (),
+        window: &mut Window,
+        cx: &mut App,
+    ) -> TextAreaPrepaint {
+        let (text, runs) = self.text_and_runs(window, cx);
+        let font_size = window.text_style().font_size.to_pixels(window.rem_size());
+        let line_height = window.line_height();
+        let lines = window
+            .text_system()
+            .shape_text(text, font_size, &runs, Some(bounds.size.width), None)
+            .map(|lines| lines.into_vec())
+            .unwrap_or_default();
+        let layout = TextAreaLayout::new(lines, line_height);
+
+        let theme = cx.theme();
+        let area = self.area.read(cx);
+        let (selected, cursor) = if area.buffer.content.is_empty() {
+            (0..0, 0)
+        } else {
+            (area.buffer.selected_range.clone(), area.buffer.cursor())
+        };
+        let cursor_position = layout.position_for_offset(cursor);
+
+        // Follow the cursor when it moves, otherwise keep the scroll
+        // position, which the wheel may have changed
+        let max_scroll = (layout.height - bounds.size.height).max(px(0.));
+        let fitted_to = (cursor, area.buffer.content.len());
+        let mut scroll_y = area.scroll_y;
+        if area.scrolled_for != fitted_to {
+            scroll_y = scroll_y
+                .min(cursor_position.y)
+                .max(cursor_position.y + line_height - bounds.size.height);
+        }
+        let scroll_y = scroll_y.clamp(px(0.), max_scroll);
+        self.area
+            .update(cx, |area, _| area.scrolled_for = fitted_to);
+
+        let origin = bounds.origin - point(px(0.), scroll_y);
+        let highlight = Hsla::from(theme.colors.primary).opacity(0.25);
+        let row = |left: Pixels, right: Pixels, top: Pixels, rows: f32| {
+            fill(
+                Bounds::from_corners(
+                    origin + point(left, top),
+                    origin + point(right, top + line_height * rows),
+                ),
+                highlight,
+            )
+        };
+        let mut selection = Vec::new();
+        if !selected.is_empty() {
+            let start = layout.position_for_offset(selected.start);
+            let end = layout.position_for_offset(selected.end);
+            if start.y == end.y {
+                selection.push(row(start.x, end.x, start.y, 1.));
+            } else {
+                let width = bounds.size.width;
+                let middle = (end.y - start.y) / line_height - 1.;
+                selection.push(row(start.x, width, start.y, 1.));
+                if middle > 0. {
+                    selection.push(row(px(0.), width, start.y + line_height, middle));
+                }
+                selection.push(row(px(0.), end.x, end.y, 1.));
+            }
+        }
+        let cursor = fill(
+            Bounds::new(origin + cursor_position, size(px(1.5), line_height)),
+            Hsla::from(theme.colors.foreground),
+        );
+
+        TextAreaPrepaint {
+            layout,
+            scroll_y,
+            selection,
+            cursor,
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut (),
+        prepaint: &mut TextAreaPrepaint,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let focus_handle = self.area.read(cx).focus_handle.clone();
+        let focused = focus_handle.is_focused(window);
+        window.handle_input(
+            &focus_handle,
+            ElementInputHandler::new(bounds, self.area.clone()),
+            cx,
+        );
+
+        let layout = &prepaint.layout;
+        let line_height = layout.line_height;
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            if focused {
+                for quad in prepaint.selection.drain(..) {
+                    window.paint_quad(quad);
+                }
+            }
+            for (line, top) in layout.lines.iter().zip(&layout.tops) {
+                let origin = point(bounds.left(), bounds.top() + *top - prepaint.scroll_y);
+                let height = line.size(line_height).height;
+                if origin.y + height < bounds.top() || origin.y > bounds.bottom() {
+                    continue;
+                }
+                line.paint(
+                    origin,
+                    line_height,
+                    TextAlign::Left,
+                    Some(bounds),
+                    window,
+                    cx,
+                )
+                .ok();
+            }
+            if focused && prepaint.selection.is_empty() {
+                window.paint_quad(prepaint.cursor.clone());
+            }
+        });
+
+        let layout = prepaint.layout.clone();
+        let scroll_y = prepaint.scroll_y;
+        self.area.update(cx, |area, _| {
+            area.last_layout = Some(layout);
+            area.last_bounds = Some(bounds);
+            area.scroll_y = scroll_y;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextArea;
+    use gpui::{px, size, Entity, Focusable, TestAppContext, VisualTestContext};
+
+    fn focused_area(
+        cx: &mut TestAppContext,
+        build: impl FnOnce(TextArea) -> TextArea,
+    ) -> (Entity<TextArea>, &mut VisualTestContext) {
+        cx.update(TextArea::bind_keys);
+        let (area, cx) = cx.add_window_view(|window, cx| build(TextArea::new(window, cx)));
+        cx.simulate_resize(size(px(300.), px(400.)));
+        cx.update(|window, cx| area.focus_handle(cx).focus(window));
+        cx.run_until_parked();
+        (area, cx)
+    }
+
+    fn height(area: &Entity<TextArea>, cx: &mut VisualTestContext) -> f32 {
+        area.read_with(cx, |area, _| area.last_bounds.unwrap().size.height.into())
+    }
+
+    #[gpui::test]
+    fn test_grows_between_min_and_max_lines(cx: &mut TestAppContext) {
+        let (area, cx) = focused_area(cx, |area| area.min_lines(2).max_lines(4));
+        let two_lines = height(&area, cx);
+
+        cx.simulate_input("one");
+        cx.simulate_keystrokes("enter");
+        cx.simulate_input("two");
+        cx.simulate_keystrokes("enter");
+        cx.simulate_input("three");
+        assert_eq!(height(&area, cx), two_lines * 1.5);
+
+        for _ in 0..4 {
+            cx.simulate_keystrokes("enter");
+            cx.simulate_input("more");
+        }
+        assert_eq!(height(&area, cx), two_lines * 2.);
+        // Past max_lines the content scrolls to keep the cursor in view
+        area.read_with(cx, |area, _| {
+            assert_eq!(area.text().lines().count(), 7);
+            assert!(area.scroll_y > px(0.));
+        });
+    }
+
+    #[gpui::test]
+    fn test_vertical_movement_and_line_keys(cx: &mut TestAppContext) {
+        let (area, cx) = focused_area(cx, |area| area.max_length(20).show_counter(true));
+
+        cx.simulate_input("first");
+        cx.simulate_keystrokes("enter");
+        cx.simulate_input("second");
+        cx.simulate_keystrokes("up home");
+        cx.simulate_input("> ");
+        cx.simulate_keystrokes("down end");
+        cx.simulate_input("!");
+
+        area.read_with(cx, |area, _| {
+            assert_eq!(area.text(), "> first\nsecond!");
+            assert_eq!(area.counter(), Some("15/20".into()));
+        });
+    }
+}
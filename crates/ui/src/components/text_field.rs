@@ -0,0 +1,703 @@
+// crates/ui/src/components/text_field.rs
+
+use super::input_decoration::{InputDecoration, InputState};
+use super::text_input::*;
+use crate::accessibility::{AccessibilityProps, Accessible, AriaRole};
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use std::borrow::Cow;
+use std::ops::Range;
+use std::rc::Rc;
+
+actions!(text_field, [Submit]);
+
+/// Key context of a focused [`TextField`], for custom bindings
+pub const TEXT_FIELD_CONTEXT: &str = "TextField";
+
+/// Character drawn in place of each character of obscured text
+const BULLET: char = '•';
+
+/// Flutter-style TextField: a single line of editable text
+///
+/// The field is a view that owns its text, so it is created once with
+/// `cx.new` and kept by its parent. It supports the usual cursor movement,
+/// selection with the keyboard and mouse, the clipboard, undo and redo,
+/// and composition through the platform's input method. Key bindings are
+/// registered once per app with [`TextField::bind_keys`].
+///
+/// With `obscure_text` the text is drawn as bullets and cannot be copied,
+/// for passwords. A `validator` is run on each change and its message is
+/// shown as the decoration's error text.
+///
+/// ```ignore
+/// let email = cx.new(|cx| {
+///     TextField::new(window, cx)
+///         .placeholder("you@example.com")
+///         .decoration(InputDecoration::new().label("Email"))
+///         .validator(|text| (!text.contains('@')).then(|| "Enter an email".into()))
+///         .on_submit(|text, _, cx| subscribe(text, cx))
+/// });
+/// ```
+pub struct TextField {
+    focus_handle: FocusHandle,
+    buffer: EditBuffer,
+    placeholder: SharedString,
+    obscured: bool,
+    decoration: InputDecoration,
+    validator: Option<Validator>,
+    validation_error: Option<SharedString>,
+    on_change: Option<TextHandler>,
+    on_submit: Option<TextHandler>,
+    /// Horizontal scroll that keeps the cursor in view
+    scroll_x: Pixels,
+    last_layout: Option<ShapedLine>,
+    last_bounds: Option<Bounds<Pixels>>,
+    is_selecting: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TextField {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![
+            cx.on_focus(&focus_handle, window, |_, _, cx| cx.notify()),
+            cx.on_blur(&focus_handle, window, |this, _, cx| {
+                this.buffer.marked_range = None;
+                cx.notify()
+            }),
+        ];
+        Self {
+            focus_handle,
+            buffer: EditBuffer::default(),
+            placeholder: SharedString::default(),
+            obscured: false,
+            decoration: InputDecoration::default(),
+            validator: None,
+            validation_error: None,
+            on_change: None,
+            on_submit: None,
+            scroll_x: px(0.),
+            last_layout: None,
+            last_bounds: None,
+            is_selecting: false,
+            _subscriptions,
+        }
+    }
+
+    /// Register the field's key bindings with the app
+    pub fn bind_keys(cx: &mut App) {
+        let mut bindings = editing_bindings(TEXT_FIELD_CONTEXT);
+        bindings.push(KeyBinding::new("enter", Submit, Some(TEXT_FIELD_CONTEXT)));
+        cx.bind_keys(bindings);
+    }
+
+    /// Text shown while the field is empty
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Draw the text as bullets and disable copying it
+    pub fn obscure_text(mut self, obscured: bool) -> Self {
+        self.obscured = obscured;
+        self
+    }
+
+    /// Longest text allowed, in characters; longer input is cut off
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.buffer.max_length = Some(max_length);
+        self
+    }
+
+    pub fn decoration(mut self, decoration: InputDecoration) -> Self {
+        self.decoration = decoration;
+        self
+    }
+
+    /// Check the text after each change, returning an error message if it
+    /// is invalid
+    pub fn validator(mut self, validator: impl Fn(&str) -> Option<SharedString> + 'static) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Called with the new text after each edit by the user
+    pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called with the text when Enter is pressed
+    pub fn on_submit(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.on_submit = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn text(&self) -> &str {
+        &self.buffer.content
+    }
+
+    /// Replace the text, moving the cursor to its end
+    ///
+    /// Unlike an edit by the user, this does not call `on_change`, but the
+    /// replacement can be undone.
+    pub fn set_text(&mut self, text: &str, cx: &mut Context<Self>) {
+        self.buffer.set_content(&single_line(text));
+        self.validate();
+        cx.notify();
+    }
+
+    /// Replace the decoration, as when its error text changes
+    pub fn set_decoration(&mut self, decoration: InputDecoration, cx: &mut Context<Self>) {
+        self.decoration = decoration;
+        cx.notify();
+    }
+
+    /// The error shown below the field, if any
+    pub fn error(&self) -> Option<SharedString> {
+        self.decoration
+            .error()
+            .cloned()
+            .or_else(|| self.validation_error.clone())
+    }
+
+    fn validate(&mut self) {
+        self.validation_error = self
+            .validator
+            .as_ref()
+            .and_then(|validator| validator(&self.buffer.content));
+    }
+
+    /// Call a handler once the current update is done, so it is free to
+    /// update the field
+    fn notify_handler(&self, handler: Option<TextHandler>, window: &mut Window, cx: &mut App) {
+        if let Some(handler) = handler {
+            let text = self.buffer.content.clone();
+            window.defer(cx, move |window, cx| handler(&text, window, cx));
+        }
+    }
+
+    fn home(&mut self, _: &Home, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.move_to(0));
+    }
+
+    fn end(&mut self, _: &End, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.move_to(buffer.content.len()));
+    }
+
+    fn select_to_home(&mut self, _: &SelectToHome, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.select_to(0));
+    }
+
+    fn select_to_end(&mut self, _: &SelectToEnd, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.select_to(buffer.content.len()));
+    }
+
+    fn submit(&mut self, _: &Submit, window: &mut Window, cx: &mut Context<Self>) {
+        self.notify_handler(self.on_submit.clone(), window, cx);
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, _: &mut Window, cx: &mut Context<Self>) {
+        self.is_selecting = true;
+        self.select_at(self.index_for_position(event.position), event, cx);
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, _: &mut Window, cx: &mut Context<Self>) {
+        if self.is_selecting {
+            let offset = self.index_for_position(event.position);
+            self.select(cx, |buffer| buffer.select_to(offset));
+        }
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut Window, _: &mut Context<Self>) {
+        self.is_selecting = false;
+    }
+
+    fn index_for_position(&self, position: Point<Pixels>) -> usize {
+        let (Some(bounds), Some(line)) = (self.last_bounds, self.last_layout.as_ref()) else {
+            return 0;
+        };
+        if self.buffer.content.is_empty() {
+            return 0;
+        }
+        let x = position.x - bounds.left() + self.scroll_x;
+        self.content_offset(line.closest_index_for_x(x))
+    }
+
+    fn display_text(&self) -> SharedString {
+        if self.obscured {
+            let len = self.buffer.content.chars().count();
+            BULLET.to_string().repeat(len).into()
+        } else {
+            self.buffer.content.clone().into()
+        }
+    }
+
+    /// Offset into the drawn text of an offset into the content
+    fn display_offset(&self, offset: usize) -> usize {
+        if self.obscured {
+            self.buffer.content[..offset].chars().count() * BULLET.len_utf8()
+        } else {
+            offset
+        }
+    }
+
+    /// Offset into the content of an offset into the drawn text
+    fn content_offset(&self, offset: usize) -> usize {
+        if self.obscured {
+            let content = &self.buffer.content;
+            content
+                .char_indices()
+                .nth(offset / BULLET.len_utf8())
+                .map_or(content.len(), |(ix, _)| ix)
+        } else {
+            offset
+        }
+    }
+
+    fn display_range(&self, range: &Range<usize>) -> Range<usize> {
+        self.display_offset(range.start)..self.display_offset(range.end)
+    }
+}
+
+/// Newlines and tabs flattened to spaces, for pasting into a single line
+fn single_line(text: &str) -> Cow<'_, str> {
+    if text.contains(['\n', '\r', '\t']) {
+        Cow::Owned(text.replace("\r\n", " ").replace(['\n', '\r', '\t'], " "))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+impl TextInput for TextField {
+    fn buffer(&self) -> &EditBuffer {
+        &self.buffer
+    }
+
+    fn buffer_mut(&mut self) -> &mut EditBuffer {
+        &mut self.buffer
+    }
+
+    fn text_changed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.validate();
+        self.notify_handler(self.on_change.clone(), window, cx);
+    }
+
+    fn reveals_text(&self) -> bool {
+        !self.obscured
+    }
+
+    fn sanitize<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        single_line(text)
+    }
+}
+
+impl Focusable for TextField {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EntityInputHandler for TextField {
+    fn text_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        actual_range: &mut Option<Range<usize>>,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<String> {
+        self.input_text_for_range(range_utf16, actual_range)
+    }
+
+    fn selected_text_range(
+        &mut self,
+        _ignore_disabled_input: bool,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<UTF16Selection> {
+        self.input_selected_text_range()
+    }
+
+    fn marked_text_range(&self, _: &mut Window, _: &mut Context<Self>) -> Option<Range<usize>> {
+        self.input_marked_text_range()
+    }
+
+    fn unmark_text(&mut self, _: &mut Window, cx: &mut Context<Self>) {
+        self.select(cx, |buffer| buffer.marked_range = None);
+    }
+
+    fn replace_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.input_replace_text_in_range(range_utf16, text, window, cx);
+    }
+
+    fn replace_and_mark_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        new_selected_range_utf16: Option<Range<usize>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.input_replace_and_mark_text_in_range(
+            range_utf16,
+            text,
+            new_selected_range_utf16,
+            window,
+            cx,
+        );
+    }
+
+    fn bounds_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        bounds: Bounds<Pixels>,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<Bounds<Pixels>> {
+        let line = self.last_layout.as_ref()?;
+        let range = self.display_range(&self.buffer.range_from_utf16(&range_utf16));
+        let x = |ix: usize| bounds.left() + line.x_for_index(ix) - self.scroll_x;
+        Some(Bounds::from_corners(
+            point(x(range.start), bounds.top()),
+            point(x(range.end), bounds.bottom()),
+        ))
+    }
+
+    fn character_index_for_point(
+        &mut self,
+        point: Point<Pixels>,
+        _: &mut Window,
+        _: &mut Context<Self>,
+    ) -> Option<usize> {
+        let bounds = self.last_bounds?;
+        bounds.localize(&point)?;
+        let offset = self.index_for_position(point);
+        Some(self.buffer.offset_to_utf16(offset))
+    }
+}
+
+impl Render for TextField {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let focused = self.focus_handle.is_focused(window);
+        let error = self.error();
+
+        self.decoration
+            .decorate(
+                TextFieldElement { field: cx.entity() },
+                InputState {
+                    focused,
+                    error: error.clone(),
+                    ..Default::default()
+                },
+                &theme,
+            )
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::Textbox),
+                label: self.decoration.label_text().cloned(),
+                description: error.clone(),
+                invalid: error.is_some(),
+                ..Default::default()
+            })
+            .key_context(TEXT_FIELD_CONTEXT)
+            .track_focus(&self.focus_handle)
+            .cursor(CursorStyle::IBeam)
+            .map(|input| on_editing_actions(input, cx))
+            .on_action(cx.listener(Self::home))
+            .on_action(cx.listener(Self::end))
+            .on_action(cx.listener(Self::select_to_home))
+            .on_action(cx.listener(Self::select_to_end))
+            .on_action(cx.listener(Self::submit))
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+    }
+}
+
+/// The line of text inside a [`TextField`], with its selection and cursor
+struct TextFieldElement {
+    field: Entity<TextField>,
+}
+
+struct TextFieldPrepaint {
+    line: ShapedLine,
+    scroll_x: Pixels,
+    selection: Option<PaintQuad>,
+    cursor: PaintQuad,
+}
+
+impl IntoElement for TextFieldElement {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for TextFieldElement {
+    type RequestLayoutState = ();
+    type PrepaintState = TextFieldPrepaint;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, ()) {
+        let style = Style {
+            size: size(relative(1.).into(), window.line_height().into()),
+            ..Default::default()
+        };
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut (),
+        window: &mut Window,
+        cx: &mut App,
+    ) -> TextFieldPrepaint {
+        let theme = cx.theme();
+        let field = self.field.read(cx);
+        let style = window.text_style();
+        let (text, color) = if field.buffer.content.is_empty() {
+            (
+                field.placeholder.clone(),
+                theme.colors.muted_foreground.into(),
+            )
+        } else {
+            (field.display_text(), style.color)
+        };
+        let run = TextRun {
+            len: text.len(),
+            font: style.font(),
+            color,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let runs = match field.buffer.marked_range.as_ref() {
+            Some(marked) => {
+                let marked = field.display_range(marked);
+                [
+                    (0..marked.start, None),
+                    (
+                        marked.clone(),
+                        Some(UnderlineStyle {
+                            color: Some(color),
+                            thickness: px(1.),
+                            wavy: false,
+                        }),
+                    ),
+                    (marked.end..text.len(), None),
+                ]
+                .into_iter()
+                .filter(|(range, _)| !range.is_empty())
+                .map(|(range, underline)| TextRun {
+                    len: range.len(),
+                    underline,
+                    ..run.clone()
+                })
+                .collect()
+            }
+            None => vec![run],
+        };
+        let font_size = style.font_size.to_pixels(window.rem_size());
+        let line = window
+            .text_system()
+            .shape_line(text, font_size, &runs, None);
+
+        let (selected, cursor) = if field.buffer.content.is_empty() {
+            (0..0, 0)
+        } else {
+            (
+                field.display_range(&field.buffer.selected_range),
+                field.display_offset(field.buffer.cursor()),
+            )
+        };
+        let cursor_x = line.x_for_index(cursor);
+        let cursor_width = px(1.5);
+        let visible = bounds.size.width - cursor_width;
+        let scroll_x = field
+            .scroll_x
+            .min(cursor_x)
+            .max(cursor_x - visible)
+            .min((line.width - visible).max(px(0.)))
+            .max(px(0.));
+        let x = |ix: usize| bounds.left() + line.x_for_index(ix) - scroll_x;
+
+        let selection = (!selected.is_empty()).then(|| {
+            fill(
+                Bounds::from_corners(
+                    point(x(selected.start), bounds.top()),
+                    point(x(selected.end), bounds.bottom()),
+                ),
+                Hsla::from(theme.colors.primary).opacity(0.25),
+            )
+        });
+        let cursor = fill(
+            Bounds::new(
+                point(x(cursor), bounds.top()),
+                size(cursor_width, bounds.size.height),
+            ),
+            Hsla::from(theme.colors.foreground),
+        );
+
+        TextFieldPrepaint {
+            line,
+            scroll_x,
+            selection,
+            cursor,
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        _: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _: &mut (),
+        prepaint: &mut TextFieldPrepaint,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let focus_handle = self.field.read(cx).focus_handle.clone();
+        let focused = focus_handle.is_focused(window);
+        window.handle_input(
+            &focus_handle,
+            ElementInputHandler::new(bounds, self.field.clone()),
+            cx,
+        );
+
+        let line_height = window.line_height();
+        let origin = point(bounds.left() - prepaint.scroll_x, bounds.top());
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            if focused {
+                if let Some(selection) = prepaint.selection.take() {
+                    window.paint_quad(selection);
+                }
+            }
+            prepaint.line.paint(origin, line_height, window, cx).ok();
+            if focused && prepaint.selection.is_none() {
+                window.paint_quad(prepaint.cursor.clone());
+            }
+        });
+
+        let line = prepaint.line.clone();
+        let scroll_x = prepaint.scroll_x;
+        self.field.update(cx, |field, _| {
+            field.last_layout = Some(line);
+            field.last_bounds = Some(bounds);
+            field.scroll_x = scroll_x;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextField;
+    use crate::components::InputDecoration;
+    use gpui::{px, size, Focusable, TestAppContext, VisualTestContext};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn focused_field(
+        cx: &mut TestAppContext,
+        build: impl FnOnce(TextField) -> TextField,
+    ) -> (gpui::Entity<TextField>, &mut VisualTestContext) {
+        cx.update(TextField::bind_keys);
+        let (field, cx) = cx.add_window_view(|window, cx| build(TextField::new(window, cx)));
+        cx.simulate_resize(size(px(300.), px(100.)));
+        cx.update(|window, cx| field.focus_handle(cx).focus(window));
+        cx.run_until_parked();
+        (field, cx)
+    }
+
+    #[gpui::test]
+    fn test_typing_editing_and_undo(cx: &mut TestAppContext) {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let recorded = changes.clone();
+        let (field, cx) = focused_field(cx, |field| {
+            field.on_change(move |text, _, _| recorded.borrow_mut().push(text.to_string()))
+        });
+
+        cx.simulate_input("hello world");
+        cx.simulate_keystrokes("backspace left left shift-home");
+        cx.simulate_input("say");
+        assert_eq!(
+            field.read_with(cx, |field, _| field.text().to_string()),
+            "sayrl"
+        );
+        assert_eq!(changes.borrow().last().unwrap(), "sayrl");
+
+        cx.simulate_keystrokes("secondary-z");
+        assert_eq!(
+            field.read_with(cx, |field, _| field.text().to_string()),
+            "hello worl"
+        );
+        cx.simulate_keystrokes("secondary-shift-z");
+        assert_eq!(
+            field.read_with(cx, |field, _| field.text().to_string()),
+            "sayrl"
+        );
+    }
+
+    #[gpui::test]
+    fn test_max_length_obscuring_and_submit(cx: &mut TestAppContext) {
+        let submitted = Rc::new(RefCell::new(None));
+        let recorded = submitted.clone();
+        let (field, cx) = focused_field(cx, |field| {
+            field
+                .obscure_text(true)
+                .max_length(6)
+                .on_submit(move |text, _, _| *recorded.borrow_mut() = Some(text.to_string()))
+        });
+
+        cx.simulate_input("hunter2!");
+        cx.simulate_keystrokes("secondary-a secondary-c enter");
+        assert_eq!(submitted.borrow().as_deref(), Some("hunter"));
+        // Obscured text stays out of the clipboard
+        assert!(cx.read_from_clipboard().is_none());
+        field.read_with(cx, |field, _| {
+            assert_eq!(field.display_text().as_ref(), "••••••");
+            assert_eq!(field.content_offset(field.display_offset(3)), 3);
+        });
+    }
+
+    #[gpui::test]
+    fn test_validator_shows_error(cx: &mut TestAppContext) {
+        let (field, cx) = focused_field(cx, |field| {
+            field
+                .decoration(InputDecoration::new().label("Email"))
+                .validator(|text| (!text.contains('@')).then(|| "Enter an email".into()))
+        });
+
+        cx.simulate_input("me");
+        assert_eq!(
+            field.read_with(cx, |field, _| field.error()),
+            Some("Enter an email".into())
+        );
+        cx.simulate_input("@example.com");
+        assert_eq!(field.read_with(cx, |field, _| field.error()), None);
+    }
+}
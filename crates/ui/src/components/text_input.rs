@@ -0,0 +1,696 @@
+// crates/ui/src/components/text_input.rs
+
+//! Editing state and behavior shared by the text input components
+
+use gpui::*;
+use std::borrow::Cow;
+use std::ops::Range;
+use std::rc::Rc;
+
+actions!(
+    text_input,
+    [
+        Backspace,
+        Delete,
+        DeleteWordLeft,
+        DeleteWordRight,
+        Left,
+        Right,
+        WordLeft,
+        WordRight,
+        SelectLeft,
+        SelectRight,
+        SelectWordLeft,
+        SelectWordRight,
+        SelectAll,
+        Home,
+        End,
+        SelectToHome,
+        SelectToEnd,
+        Copy,
+        Cut,
+        Paste,
+        Undo,
+        Redo,
+        ShowCharacterPalette,
+    ]
+);
+
+/// Bindings for the editing actions within the key context `context`
+pub(super) fn editing_bindings(context: &str) -> Vec<KeyBinding> {
+    let context = Some(context);
+    let mut bindings = vec![
+        KeyBinding::new("backspace", Backspace, context),
+        KeyBinding::new("delete", Delete, context),
+        KeyBinding::new("left", Left, context),
+        KeyBinding::new("right", Right, context),
+        KeyBinding::new("shift-left", SelectLeft, context),
+        KeyBinding::new("shift-right", SelectRight, context),
+        KeyBinding::new("home", Home, context),
+        KeyBinding::new("end", End, context),
+        KeyBinding::new("shift-home", SelectToHome, context),
+        KeyBinding::new("shift-end", SelectToEnd, context),
+        KeyBinding::new("secondary-a", SelectAll, context),
+        KeyBinding::new("secondary-c", Copy, context),
+        KeyBinding::new("secondary-x", Cut, context),
+        KeyBinding::new("secondary-v", Paste, context),
+        KeyBinding::new("secondary-z", Undo, context),
+        KeyBinding::new("secondary-shift-z", Redo, context),
+    ];
+    if cfg!(target_os = "macos") {
+        bindings.extend([
+            KeyBinding::new("alt-backspace", DeleteWordLeft, context),
+            KeyBinding::new("alt-delete", DeleteWordRight, context),
+            KeyBinding::new("alt-left", WordLeft, context),
+            KeyBinding::new("alt-right", WordRight, context),
+            KeyBinding::new("alt-shift-left", SelectWordLeft, context),
+            KeyBinding::new("alt-shift-right", SelectWordRight, context),
+            KeyBinding::new("cmd-left", Home, context),
+            KeyBinding::new("cmd-right", End, context),
+            KeyBinding::new("cmd-shift-left", SelectToHome, context),
+            KeyBinding::new("cmd-shift-right", SelectToEnd, context),
+            KeyBinding::new("ctrl-cmd-space", ShowCharacterPalette, context),
+        ]);
+    } else {
+        bindings.extend([
+            KeyBinding::new("ctrl-backspace", DeleteWordLeft, context),
+            KeyBinding::new("ctrl-delete", DeleteWordRight, context),
+            KeyBinding::new("ctrl-left", WordLeft, context),
+            KeyBinding::new("ctrl-right", WordRight, context),
+            KeyBinding::new("ctrl-shift-left", SelectWordLeft, context),
+            KeyBinding::new("ctrl-shift-right", SelectWordRight, context),
+            KeyBinding::new("ctrl-y", Redo, context),
+        ]);
+    }
+    bindings
+}
+
+/// Undo history kept per input
+const MAX_UNDO: usize = 100;
+
+/// Content and selection before an edit, restored by undo
+#[derive(Debug, Clone, PartialEq)]
+struct Snapshot {
+    content: String,
+    selected_range: Range<usize>,
+}
+
+/// The text being edited, its selection and its undo history
+///
+/// Offsets are UTF-8 byte offsets into `content` and always fall on char
+/// boundaries. The platform's input handler speaks UTF-16, so conversions
+/// are provided for both directions.
+#[derive(Debug, Default)]
+pub(super) struct EditBuffer {
+    pub content: String,
+    pub selected_range: Range<usize>,
+    /// Whether the cursor is at the start of the selection
+    pub selection_reversed: bool,
+    /// Text being composed by an input method, shown underlined
+    pub marked_range: Option<Range<usize>>,
+    /// Longest content allowed, counted in chars
+    pub max_length: Option<usize>,
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    /// Whether the next typed text joins the last undo step
+    coalesce_typing: bool,
+}
+
+impl EditBuffer {
+    pub fn cursor(&self) -> usize {
+        if self.selection_reversed {
+            self.selected_range.start
+        } else {
+            self.selected_range.end
+        }
+    }
+
+    pub fn selected_text(&self) -> &str {
+        &self.content[self.selected_range.clone()]
+    }
+
+    /// Collapse the selection to `offset`
+    pub fn move_to(&mut self, offset: usize) {
+        let offset = offset.min(self.content.len());
+        self.selected_range = offset..offset;
+        self.selection_reversed = false;
+        self.coalesce_typing = false;
+    }
+
+    /// Extend the selection so the cursor ends up at `offset`
+    pub fn select_to(&mut self, offset: usize) {
+        let offset = offset.min(self.content.len());
+        if self.selection_reversed {
+            self.selected_range.start = offset;
+        } else {
+            self.selected_range.end = offset;
+        }
+        if self.selected_range.end < self.selected_range.start {
+            self.selection_reversed = !self.selection_reversed;
+            self.selected_range = self.selected_range.end..self.selected_range.start;
+        }
+        self.coalesce_typing = false;
+    }
+
+    pub fn select_all(&mut self) {
+        self.move_to(0);
+        self.select_to(self.content.len());
+    }
+
+    pub fn previous_boundary(&self, offset: usize) -> usize {
+        self.content[..offset]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(ix, _)| ix)
+    }
+
+    pub fn next_boundary(&self, offset: usize) -> usize {
+        self.content[offset..]
+            .chars()
+            .next()
+            .map_or(self.content.len(), |ch| offset + ch.len_utf8())
+    }
+
+    /// Start of the word before `offset`, skipping any whitespace first
+    pub fn previous_word_boundary(&self, offset: usize) -> usize {
+        let mut chars = self.content[..offset].char_indices().rev().peekable();
+        while chars.next_if(|(_, ch)| !is_word_char(*ch)).is_some() {}
+        let mut start = chars.peek().map_or(0, |(ix, _)| *ix);
+        while let Some((ix, _)) = chars.next_if(|(_, ch)| is_word_char(*ch)) {
+            start = ix;
+        }
+        start
+    }
+
+    /// End of the word after `offset`, skipping any whitespace first
+    pub fn next_word_boundary(&self, offset: usize) -> usize {
+        let mut chars = self.content[offset..].char_indices().peekable();
+        while chars.next_if(|(_, ch)| !is_word_char(*ch)).is_some() {}
+        while chars.next_if(|(_, ch)| is_word_char(*ch)).is_some() {}
+        chars
+            .peek()
+            .map_or(self.content.len(), |(ix, _)| offset + ix)
+    }
+
+    /// Replace `range` with `text` and put the cursor after it
+    ///
+    /// The range defaults to the marked text, then the selection. Text that
+    /// would take the content past `max_length` is cut off. Returns whether
+    /// the content changed.
+    pub fn replace(&mut self, range: Option<Range<usize>>, text: &str) -> bool {
+        let range = self.edit_range(range);
+        let text = self.fit(&range, text);
+        let typing = !text.is_empty() && self.marked_range.is_none();
+        // Committing a composition finishes the undo step it started
+        let join_undo =
+            typing && range.is_empty() && self.coalesce_typing || self.marked_range.is_some();
+        let changed = self.splice(range.clone(), text, join_undo);
+        let end = range.start + text.len();
+        self.selected_range = end..end;
+        self.selection_reversed = false;
+        self.marked_range = None;
+        self.coalesce_typing = typing;
+        changed
+    }
+
+    /// Replace `range` with composition text from an input method
+    ///
+    /// The new text is marked, and `selected` is a range within it that
+    /// becomes the selection. Returns whether the content changed.
+    pub fn replace_and_mark(
+        &mut self,
+        range: Option<Range<usize>>,
+        text: &str,
+        selected: Option<Range<usize>>,
+    ) -> bool {
+        let range = self.edit_range(range);
+        let text = self.fit(&range, text);
+        // A composition is one undo step, saved when it starts
+        let composing = self.marked_range.is_some();
+        let changed = self.splice(range.clone(), text, composing);
+        self.marked_range = (!text.is_empty()).then(|| range.start..range.start + text.len());
+        self.selected_range = match selected {
+            Some(selected) => {
+                let clamp = |offset: usize| range.start + offset.min(text.len());
+                clamp(selected.start)..clamp(selected.end)
+            }
+            None => range.start + text.len()..range.start + text.len(),
+        };
+        self.selection_reversed = false;
+        self.coalesce_typing = false;
+        changed
+    }
+
+    /// Replace the whole content, as when set from outside the input
+    pub fn set_content(&mut self, content: &str) {
+        let content = self.fit(&(0..self.content.len()), content);
+        if content != self.content {
+            self.push_undo();
+            self.content = content.to_string();
+        }
+        self.marked_range = None;
+        self.move_to(self.content.len());
+    }
+
+    pub fn undo(&mut self) -> bool {
+        self.restore(false)
+    }
+
+    pub fn redo(&mut self) -> bool {
+        self.restore(true)
+    }
+
+    fn restore(&mut self, redo: bool) -> bool {
+        let (from, to) = if redo {
+            (&mut self.redo_stack, &mut self.undo_stack)
+        } else {
+            (&mut self.undo_stack, &mut self.redo_stack)
+        };
+        let Some(snapshot) = from.pop() else {
+            return false;
+        };
+        to.push(Snapshot {
+            content: std::mem::take(&mut self.content),
+            selected_range: self.selected_range.clone(),
+        });
+        self.content = snapshot.content;
+        self.selected_range = snapshot.selected_range;
+        self.selection_reversed = false;
+        self.marked_range = None;
+        self.coalesce_typing = false;
+        true
+    }
+
+    fn edit_range(&self, range: Option<Range<usize>>) -> Range<usize> {
+        let range = range
+            .or_else(|| self.marked_range.clone())
+            .unwrap_or_else(|| self.selected_range.clone());
+        let end = range.end.min(self.content.len());
+        range.start.min(end)..end
+    }
+
+    /// The longest prefix of `text` that fits in place of `range`
+    fn fit<'a>(&self, range: &Range<usize>, text: &'a str) -> &'a str {
+        let Some(max_length) = self.max_length else {
+            return text;
+        };
+        let kept = self.content.chars().count() - self.content[range.clone()].chars().count();
+        let room = max_length.saturating_sub(kept);
+        text.char_indices()
+            .nth(room)
+            .map_or(text, |(ix, _)| &text[..ix])
+    }
+
+    fn splice(&mut self, range: Range<usize>, text: &str, join_undo: bool) -> bool {
+        if self.content[range.clone()] == *text {
+            return false;
+        }
+        if !join_undo {
+            self.push_undo();
+        }
+        self.content.replace_range(range, text);
+        true
+    }
+
+    fn push_undo(&mut self) {
+        self.redo_stack.clear();
+        if self.undo_stack.len() == MAX_UNDO {
+            self.undo_stack.remove(0);
+        }
+        self.undo_stack.push(Snapshot {
+            content: self.content.clone(),
+            selected_range: self.selected_range.clone(),
+        });
+    }
+
+    pub fn offset_from_utf16(&self, offset: usize) -> usize {
+        let mut utf16 = 0;
+        for (ix, ch) in self.content.char_indices() {
+            if utf16 >= offset {
+                return ix;
+            }
+            utf16 += ch.len_utf16();
+        }
+        self.content.len()
+    }
+
+    pub fn offset_to_utf16(&self, offset: usize) -> usize {
+        self.content[..offset.min(self.content.len())]
+            .chars()
+            .map(char::len_utf16)
+            .sum()
+    }
+
+    pub fn range_from_utf16(&self, range: &Range<usize>) -> Range<usize> {
+        self.offset_from_utf16(range.start)..self.offset_from_utf16(range.end)
+    }
+
+    pub fn range_to_utf16(&self, range: &Range<usize>) -> Range<usize> {
+        self.offset_to_utf16(range.start)..self.offset_to_utf16(range.end)
+    }
+}
+
+/// Handler called with an input's text
+pub(super) type TextHandler = Rc<dyn Fn(&str, &mut Window, &mut App)>;
+/// Check of an input's text, returning the error to show
+pub(super) type Validator = Box<dyn Fn(&str) -> Option<SharedString>>;
+
+/// A view that edits an [`EditBuffer`]
+///
+/// Implementors get the editing actions from [`on_editing_actions`] and
+/// most of `EntityInputHandler` from the `input_*` methods, leaving them
+/// to lay out and paint the text.
+pub(super) trait TextInput: Sized + 'static {
+    fn buffer(&self) -> &EditBuffer;
+
+    fn buffer_mut(&mut self) -> &mut EditBuffer;
+
+    /// Called after an edit by the user changed the text
+    fn text_changed(&mut self, window: &mut Window, cx: &mut Context<Self>);
+
+    /// Whether the text may be copied and moved through by word, which a
+    /// password field does not allow
+    fn reveals_text(&self) -> bool {
+        true
+    }
+
+    /// Text as it should be inserted
+    fn sanitize<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        Cow::Borrowed(text)
+    }
+
+    /// Apply an edit, calling `text_changed` if the text changed
+    fn edit(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+        edit: impl FnOnce(&mut EditBuffer) -> bool,
+    ) {
+        if edit(self.buffer_mut()) {
+            self.text_changed(window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Move or extend the selection without editing
+    fn select(&mut self, cx: &mut Context<Self>, select: impl FnOnce(&mut EditBuffer)) {
+        select(self.buffer_mut());
+        cx.notify();
+    }
+
+    /// Insert text, as from the keyboard or clipboard
+    fn insert(&mut self, text: &str, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.sanitize(text).into_owned();
+        self.edit(window, cx, |buffer| buffer.replace(None, &text));
+    }
+
+    /// Delete the selection, or from the cursor to `boundary` if there is
+    /// none
+    fn delete_to(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+        boundary: impl FnOnce(&EditBuffer, usize) -> usize,
+    ) {
+        self.edit(window, cx, |buffer| {
+            if buffer.selected_range.is_empty() {
+                buffer.select_to(boundary(buffer, buffer.cursor()));
+            }
+            buffer.replace(None, "")
+        });
+    }
+
+    /// Start of the word before `offset`, or of the text if words are
+    /// hidden
+    fn word_start(&self, offset: usize) -> usize {
+        if self.reveals_text() {
+            self.buffer().previous_word_boundary(offset)
+        } else {
+            0
+        }
+    }
+
+    /// End of the word after `offset`, or of the text if words are hidden
+    fn word_end(&self, offset: usize) -> usize {
+        if self.reveals_text() {
+            self.buffer().next_word_boundary(offset)
+        } else {
+            self.buffer().content.len()
+        }
+    }
+
+    /// Place the cursor for a press at `offset`: a double click selects a
+    /// word, a triple click everything and shift extends the selection
+    fn select_at(&mut self, offset: usize, event: &MouseDownEvent, cx: &mut Context<Self>) {
+        let (start, end) = (
+            self.word_start(self.buffer().next_boundary(offset)),
+            self.word_end(offset),
+        );
+        self.select(cx, |buffer| match event.click_count {
+            2 => {
+                buffer.move_to(start);
+                buffer.select_to(end);
+            }
+            3.. => buffer.select_all(),
+            _ if event.modifiers.shift => buffer.select_to(offset),
+            _ => buffer.move_to(offset),
+        });
+    }
+
+    fn input_text_for_range(
+        &self,
+        range_utf16: Range<usize>,
+        actual_range: &mut Option<Range<usize>>,
+    ) -> Option<String> {
+        let buffer = self.buffer();
+        let range = buffer.range_from_utf16(&range_utf16);
+        actual_range.replace(buffer.range_to_utf16(&range));
+        Some(buffer.content[range].to_string())
+    }
+
+    fn input_selected_text_range(&self) -> Option<UTF16Selection> {
+        let buffer = self.buffer();
+        Some(UTF16Selection {
+            range: buffer.range_to_utf16(&buffer.selected_range),
+            reversed: buffer.selection_reversed,
+        })
+    }
+
+    fn input_marked_text_range(&self) -> Option<Range<usize>> {
+        let buffer = self.buffer();
+        buffer
+            .marked_range
+            .as_ref()
+            .map(|range| buffer.range_to_utf16(range))
+    }
+
+    fn input_replace_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let range = range_utf16.map(|range| self.buffer().range_from_utf16(&range));
+        let text = self.sanitize(text).into_owned();
+        self.edit(window, cx, |buffer| buffer.replace(range, &text));
+    }
+
+    fn input_replace_and_mark_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        new_selected_range_utf16: Option<Range<usize>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let range = range_utf16.map(|range| self.buffer().range_from_utf16(&range));
+        let text = self.sanitize(text).into_owned();
+        // The selection is given in UTF-16 units within the new text
+        let offset = |utf16: usize| {
+            let mut units = 0;
+            for (ix, ch) in text.char_indices() {
+                if units >= utf16 {
+                    return ix;
+                }
+                units += ch.len_utf16();
+            }
+            text.len()
+        };
+        let selected =
+            new_selected_range_utf16.map(|selected| offset(selected.start)..offset(selected.end));
+        self.edit(window, cx, |buffer| {
+            buffer.replace_and_mark(range, &text, selected)
+        });
+    }
+}
+
+/// Listen for the shared editing actions on a text input's root element
+pub(super) fn on_editing_actions<T: TextInput, E: InteractiveElement>(
+    element: E,
+    cx: &mut Context<T>,
+) -> E {
+    element
+        .on_action(cx.listener(|this: &mut T, _: &Backspace, window, cx| {
+            this.delete_to(window, cx, EditBuffer::previous_boundary)
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &Delete, window, cx| {
+            this.delete_to(window, cx, EditBuffer::next_boundary)
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &DeleteWordLeft, window, cx| {
+            let start = this.word_start(this.buffer().cursor());
+            this.delete_to(window, cx, |_, _| start)
+        }))
+        .on_action(
+            cx.listener(|this: &mut T, _: &DeleteWordRight, window, cx| {
+                let end = this.word_end(this.buffer().cursor());
+                this.delete_to(window, cx, |_, _| end)
+            }),
+        )
+        .on_action(cx.listener(|this: &mut T, _: &Left, _, cx| {
+            this.select(cx, |buffer| {
+                if buffer.selected_range.is_empty() {
+                    buffer.move_to(buffer.previous_boundary(buffer.cursor()));
+                } else {
+                    buffer.move_to(buffer.selected_range.start);
+                }
+            })
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &Right, _, cx| {
+            this.select(cx, |buffer| {
+                if buffer.selected_range.is_empty() {
+                    buffer.move_to(buffer.next_boundary(buffer.cursor()));
+                } else {
+                    buffer.move_to(buffer.selected_range.end);
+                }
+            })
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &WordLeft, _, cx| {
+            let start = this.word_start(this.buffer().cursor());
+            this.select(cx, |buffer| buffer.move_to(start))
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &WordRight, _, cx| {
+            let end = this.word_end(this.buffer().cursor());
+            this.select(cx, |buffer| buffer.move_to(end))
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &SelectLeft, _, cx| {
+            this.select(cx, |buffer| {
+                buffer.select_to(buffer.previous_boundary(buffer.cursor()))
+            })
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &SelectRight, _, cx| {
+            this.select(cx, |buffer| {
+                buffer.select_to(buffer.next_boundary(buffer.cursor()))
+            })
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &SelectWordLeft, _, cx| {
+            let start = this.word_start(this.buffer().cursor());
+            this.select(cx, |buffer| buffer.select_to(start))
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &SelectWordRight, _, cx| {
+            let end = this.word_end(this.buffer().cursor());
+            this.select(cx, |buffer| buffer.select_to(end))
+        }))
+        .on_action(
+            cx.listener(|this: &mut T, _: &SelectAll, _, cx| {
+                this.select(cx, EditBuffer::select_all)
+            }),
+        )
+        .on_action(cx.listener(|this: &mut T, _: &Copy, _, cx| {
+            let buffer = this.buffer();
+            if this.reveals_text() && !buffer.selected_range.is_empty() {
+                cx.write_to_clipboard(ClipboardItem::new_string(
+                    buffer.selected_text().to_string(),
+                ));
+            }
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &Cut, window, cx| {
+            let buffer = this.buffer();
+            if this.reveals_text() && !buffer.selected_range.is_empty() {
+                cx.write_to_clipboard(ClipboardItem::new_string(
+                    buffer.selected_text().to_string(),
+                ));
+                this.edit(window, cx, |buffer| buffer.replace(None, ""));
+            }
+        }))
+        .on_action(cx.listener(|this: &mut T, _: &Paste, window, cx| {
+            if let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) {
+                this.insert(&text, window, cx);
+            }
+        }))
+        .on_action(
+            cx.listener(|this: &mut T, _: &Undo, window, cx| {
+                this.edit(window, cx, EditBuffer::undo)
+            }),
+        )
+        .on_action(
+            cx.listener(|this: &mut T, _: &Redo, window, cx| {
+                this.edit(window, cx, EditBuffer::redo)
+            }),
+        )
+        .on_action(
+            cx.listener(|_: &mut T, _: &ShowCharacterPalette, window, _| {
+                window.show_character_palette()
+            }),
+        )
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EditBuffer;
+
+    fn typed(text: &str) -> EditBuffer {
+        let mut buffer = EditBuffer::default();
+        for ch in text.chars() {
+            buffer.replace(None, ch.encode_utf8(&mut [0; 4]));
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_typing_is_one_undo_step() {
+        let mut buffer = typed("hello");
+        buffer.move_to(0);
+        buffer.replace(None, "> ");
+
+        assert!(buffer.undo());
+        assert_eq!(buffer.content, "hello");
+        assert!(buffer.undo());
+        assert_eq!(buffer.content, "");
+        assert!(!buffer.undo());
+
+        assert!(buffer.redo());
+        assert_eq!(buffer.content, "hello");
+    }
+
+    #[test]
+    fn test_max_length_counts_chars() {
+        let mut buffer = EditBuffer {
+            max_length: Some(4),
+            ..Default::default()
+        };
+        buffer.replace(None, "héllo");
+        assert_eq!(buffer.content, "héll");
+
+        buffer.select_all();
+        buffer.replace(None, "ab");
+        assert_eq!(buffer.content, "ab");
+    }
+
+    #[test]
+    fn test_word_boundaries_and_utf16() {
+        let buffer = typed("one  two😀 three");
+        assert_eq!(buffer.previous_word_boundary(buffer.content.len()), 13);
+        assert_eq!(buffer.previous_word_boundary(5), 0);
+        assert_eq!(buffer.next_word_boundary(3), 8);
+
+        // The emoji is two UTF-16 units and four bytes
+        assert_eq!(buffer.offset_to_utf16(12), 10);
+        assert_eq!(buffer.offset_from_utf16(10), 12);
+        assert_eq!(buffer.previous_boundary(12), 8);
+    }
+}
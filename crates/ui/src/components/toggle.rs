@@ -0,0 +1,183 @@
+// crates/ui/src/components/toggle.rs
+
+use super::button::{interactive_button, VariantColors};
+use super::{ButtonSize, ButtonVariant};
+use crate::accessibility::{AccessibilityProps, Accessible, AriaRole};
+use crate::icons::Icon;
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+type ChangeHandler = Box<dyn Fn(bool, &mut Window, &mut App)>;
+
+/// A shadcn-style toggle: a button that stays pressed
+///
+/// The pressed state is owned by the caller, who passes it in with
+/// `pressed` and updates it from `on_change`, which receives the new state
+/// on each click or Enter/Space press. A pressed toggle takes the theme's
+/// accent colors and reports `aria-pressed`.
+///
+/// With `icon_only`, the label is not shown but is still the toggle's
+/// accessible name.
+///
+/// ```ignore
+/// Toggle::new("bold", "Bold")
+///     .icon(Icon::new(LucideIcon::Bold))
+///     .icon_only(true)
+///     .pressed(self.bold)
+///     .on_change(cx.listener(|this, pressed, _, _| this.bold = *pressed))
+/// ```
+#[derive(IntoElement)]
+pub struct Toggle {
+    id: ElementId,
+    label: SharedString,
+    icon: Option<Icon>,
+    icon_only: bool,
+    pressed: bool,
+    variant: ButtonVariant,
+    size: ButtonSize,
+    on_change: Option<ChangeHandler>,
+    disabled: bool,
+}
+
+/// The name Material and Flutter use for a [`Toggle`]
+pub type ToggleButton = Toggle;
+
+impl Toggle {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            icon_only: false,
+            pressed: false,
+            variant: ButtonVariant::Ghost,
+            size: ButtonSize::default(),
+            on_change: None,
+            disabled: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Show only the icon, keeping the label as the accessible name
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+
+    pub fn pressed(mut self, pressed: bool) -> Self {
+        self.pressed = pressed;
+        self
+    }
+
+    /// Appearance while not pressed: [`ButtonVariant::Ghost`] by default, or
+    /// [`ButtonVariant::Outline`] for a bordered toggle
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Called with the state the toggle should switch to
+    pub fn on_change(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Box::new(move |pressed, window, cx| {
+            handler(&pressed, window, cx)
+        }));
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl RenderOnce for Toggle {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let mut colors = self.variant.colors(&theme);
+        if self.pressed {
+            colors = VariantColors {
+                background: theme.colors.accent.into(),
+                foreground: theme.colors.accent_foreground.into(),
+                ..colors
+            };
+        }
+        let height = self.size.height();
+        let pressed = self.pressed;
+        let on_click = self.on_change.map(|handler| {
+            Box::new(move |_: &ClickEvent, window: &mut Window, cx: &mut App| {
+                handler(!pressed, window, cx)
+            }) as _
+        });
+
+        let toggle = div()
+            .accessibility(AccessibilityProps {
+                role: Some(AriaRole::Button),
+                label: Some(self.label.clone()),
+                disabled: self.disabled,
+                ..Default::default()
+            })
+            .aria_pressed(pressed)
+            .id(self.id)
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .gap(theme.spacing.sm)
+            .h(height)
+            .min_w(height)
+            .px(self.size.padding_x() / 2.)
+            .rounded(theme.radius.md)
+            .border_1()
+            .border_color(colors.border)
+            .bg(colors.background)
+            .text_color(colors.foreground)
+            .text_size(theme.typography.size_sm)
+            .font_weight(FontWeight(theme.typography.weight_medium as f32))
+            .children(
+                self.icon
+                    .map(|icon| icon.size(self.size.icon_size()).color(colors.foreground)),
+            )
+            .when(!self.icon_only, |toggle| toggle.child(self.label));
+
+        interactive_button(toggle, &colors, &theme, !self.disabled, false, on_click)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Toggle;
+    use crate::test_util::{draw_view, redraw};
+    use gpui::{point, px, size, Modifiers, TestAppContext};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn test_toggle_reports_next_state(cx: &mut TestAppContext) {
+        let pressed = Rc::new(Cell::new(false));
+        let state = pressed.clone();
+
+        let cx = draw_view(cx, size(px(200.), px(200.)), move |_, _| {
+            let state = state.clone();
+            Toggle::new("bold", "Bold")
+                .pressed(state.get())
+                .on_change(move |next, _, _| state.set(*next))
+        });
+
+        cx.simulate_click(point(px(10.), px(10.)), Modifiers::default());
+        assert!(pressed.get());
+
+        redraw(cx);
+        cx.simulate_click(point(px(10.), px(10.)), Modifiers::default());
+        assert!(!pressed.get());
+    }
+}
@@ -1,14 +1,17 @@
 // crates/ui/src/icons/mod.rs
 
+use crate::layout::TextDirection;
 use crate::theme::ActiveTheme;
 use gpui::*;
 pub use lucide_icons::Icon as LucideIcon;
 
 #[derive(IntoElement, Clone)]
 pub struct Icon {
+    #[allow(dead_code)] // Unused until glyph rendering lands (see `render`)
     icon: LucideIcon,
     size: IconSize,
     color: Option<Hsla>,
+    match_text_direction: bool,
 
     // Accessibility
     label: Option<SharedString>,
@@ -42,6 +45,7 @@ impl Icon {
             icon,
             size: IconSize::Lg,
             color: None,
+            match_text_direction: false,
             label: None,
             decorative: false,
         }
@@ -57,6 +61,23 @@ impl Icon {
         self
     }
 
+    /// Flip the glyph horizontally in RTL, for directional icons such as
+    /// chevrons and arrows
+    ///
+    /// Icons still render as a plain placeholder box, which looks the same
+    /// mirrored, so this has no visible effect until glyphs are drawn.
+    /// Components that draw their own directional shapes can check
+    /// [`is_mirrored`](Self::is_mirrored) in the meantime.
+    pub fn match_text_direction(mut self, matches: bool) -> Self {
+        self.match_text_direction = matches;
+        self
+    }
+
+    /// Whether the glyph is drawn mirrored under the given direction
+    pub fn is_mirrored(&self, direction: TextDirection) -> bool {
+        self.match_text_direction && direction == TextDirection::Rtl
+    }
+
     pub fn label(mut self, label: impl Into<SharedString>) -> Self {
         self.label = Some(label.into());
         self.decorative = false;
@@ -76,8 +97,27 @@ impl RenderOnce for Icon {
         let color = self.color.unwrap_or(theme.colors.foreground.into());
         let size = self.size.to_pixels();
 
-        // For now, render as a simple colored box
-        // TODO: Implement actual SVG rendering when GPUI supports it
+        // For now, render as a simple colored box, which needs no flipping
+        // under `match_text_direction`
+        // TODO: Implement actual SVG rendering when GPUI supports it, with an
+        // `svg().with_transformation(..)` mirror when
+        // `is_mirrored(TextDirection::of(cx))`
         div().size(size).bg(color).rounded(px(2.)).flex_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Icon, LucideIcon};
+    use crate::layout::TextDirection;
+
+    #[test]
+    fn test_icon_mirrors_only_when_matching_rtl() {
+        let chevron = Icon::new(LucideIcon::ChevronRight);
+        assert!(!chevron.is_mirrored(TextDirection::Rtl));
+
+        let chevron = chevron.match_text_direction(true);
+        assert!(!chevron.is_mirrored(TextDirection::Ltr));
+        assert!(chevron.is_mirrored(TextDirection::Rtl));
+    }
+}
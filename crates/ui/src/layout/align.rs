@@ -1,25 +1,62 @@
 // crates/ui/src/layout/align.rs
 
-use super::Alignment;
+use super::{Alignment, AlignmentGeometry, TextDirection};
 use gpui::*;
+use std::cell::Cell;
+use std::rc::Rc;
 
 /// Flutter-style Align widget
-#[derive(IntoElement)]
+///
+/// Positions its child at a fractional [`Alignment`] within itself. Without
+/// size factors it fills its parent; with `width_factor` / `height_factor`
+/// it is that multiple of the child's size on the given axis.
+///
+/// The child is laid out with loose constraints: it may be any size up to
+/// the Align's own, and shrink-wraps its content like a flex item.
 pub struct Align {
-    child: AnyElement,
-    alignment: Alignment,
+    child: Option<AnyElement>,
+    /// The child once taken over for layout, kept until it is painted
+    loose: Option<LooseChild>,
+    alignment: AlignmentGeometry,
+    text_direction: Option<TextDirection>,
+    width_factor: Option<f32>,
+    height_factor: Option<f32>,
 }
 
 impl Align {
     pub fn new(child: impl IntoElement) -> Self {
         Self {
-            child: child.into_any_element(),
-            alignment: Alignment::Center,
+            child: Some(child.into_any_element()),
+            loose: None,
+            alignment: AlignmentGeometry::default(),
+            text_direction: None,
+            width_factor: None,
+            height_factor: None,
         }
     }
 
-    pub fn alignment(mut self, alignment: Alignment) -> Self {
-        self.alignment = alignment;
+    /// Accepts an [`Alignment`] or an [`AlignmentDirectional`](super::AlignmentDirectional)
+    pub fn alignment(mut self, alignment: impl Into<AlignmentGeometry>) -> Self {
+        self.alignment = alignment.into();
+        self
+    }
+
+    /// Direction used to resolve a directional alignment, instead of the
+    /// one set by the enclosing [`Directionality`](super::Directionality)
+    pub fn text_direction(mut self, direction: TextDirection) -> Self {
+        self.text_direction = Some(direction);
+        self
+    }
+
+    /// Make the width this multiple of the child's width instead of filling the parent
+    pub fn width_factor(mut self, factor: f32) -> Self {
+        self.width_factor = Some(factor.max(0.));
+        self
+    }
+
+    /// Make the height this multiple of the child's height instead of filling the parent
+    pub fn height_factor(mut self, factor: f32) -> Self {
+        self.height_factor = Some(factor.max(0.));
         self
     }
 
@@ -61,22 +98,314 @@ impl Align {
     }
 }
 
-impl RenderOnce for Align {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let element = div().flex().w_full().h_full();
-
-        let element = match self.alignment {
-            Alignment::TopLeft => element.justify_start().items_start(),
-            Alignment::TopCenter => element.justify_center().items_start(),
-            Alignment::TopRight => element.justify_end().items_start(),
-            Alignment::CenterLeft => element.justify_start().items_center(),
-            Alignment::Center => element.justify_center().items_center(),
-            Alignment::CenterRight => element.justify_end().items_center(),
-            Alignment::BottomLeft => element.justify_start().items_end(),
-            Alignment::BottomCenter => element.justify_center().items_end(),
-            Alignment::BottomRight => element.justify_end().items_end(),
+impl IntoElement for Align {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Align {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        self.loose = self.child.take().map(LooseChild::new);
+        let natural_size = self
+            .loose
+            .as_mut()
+            .map(|child| {
+                child.layout(
+                    size(AvailableSpace::MaxContent, AvailableSpace::MaxContent),
+                    window,
+                    cx,
+                )
+            })
+            .unwrap_or_default();
+
+        let fill = |factor: Option<f32>| match factor {
+            Some(_) => Length::Auto,
+            None => relative(1.).into(),
+        };
+        let style = Style {
+            size: size(fill(self.width_factor), fill(self.height_factor)),
+            ..Default::default()
+        };
+
+        // Only consulted on axes the style leaves unresolved: a factor axis,
+        // or a fill axis whose parent has no definite size
+        let (width_factor, height_factor) = (self.width_factor, self.height_factor);
+        let layout_id = window.request_measured_layout(
+            style,
+            move |known_dimensions, _available_space, _window, _cx| {
+                size(
+                    known_dimensions
+                        .width
+                        .unwrap_or(natural_size.width * width_factor.unwrap_or(1.)),
+                    known_dimensions
+                        .height
+                        .unwrap_or(natural_size.height * height_factor.unwrap_or(1.)),
+                )
+            },
+        );
+
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let Some(child) = self.loose.as_mut() else {
+            return;
+        };
+
+        let child_size = child.layout(bounds.size.into(), window, cx);
+        let direction = self.text_direction.unwrap_or_else(|| TextDirection::of(cx));
+        let alignment = self.alignment.resolve(direction);
+        let child_bounds = alignment.inscribe(child_size, bounds);
+        child.prepaint_at(child_bounds.origin, window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(child) = self.loose.as_mut() {
+            child.paint(window, cx);
+        }
+    }
+}
+
+/// A child laid out as its own layout root under loose constraints
+///
+/// The child sits at the start of a flex container that fills the offered
+/// space, so percentages resolve against that space while the child itself
+/// shrink-wraps. Its resulting size is read back through a pass-through
+/// element that records the child's layout node.
+pub(crate) struct LooseChild {
+    root: AnyElement,
+    layout_id: Rc<Cell<Option<LayoutId>>>,
+}
+
+impl LooseChild {
+    pub(crate) fn new(child: AnyElement) -> Self {
+        let layout_id = Rc::new(Cell::new(None));
+        let tracked = Tracked {
+            child,
+            layout_id: layout_id.clone(),
         };
 
-        element.child(self.child)
+        Self {
+            root: div()
+                .flex()
+                .size_full()
+                .items_start()
+                .justify_start()
+                .child(tracked)
+                .into_any_element(),
+            layout_id,
+        }
+    }
+
+    /// Lay the child out within `space` and return its size
+    pub(crate) fn layout(
+        &mut self,
+        space: Size<AvailableSpace>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Size<Pixels> {
+        self.root.layout_as_root(space, window, cx);
+        match self.layout_id.get() {
+            Some(layout_id) => window.layout_bounds(layout_id).size,
+            None => Size::default(),
+        }
+    }
+
+    /// Prepaint with the child's top-left corner at `origin`
+    pub(crate) fn prepaint_at(&mut self, origin: Point<Pixels>, window: &mut Window, cx: &mut App) {
+        self.root.prepaint_at(origin, window, cx);
+    }
+
+    pub(crate) fn paint(&mut self, window: &mut Window, cx: &mut App) {
+        self.root.paint(window, cx);
+    }
+}
+
+/// Passes layout straight through to its child, remembering the child's node
+struct Tracked {
+    child: AnyElement,
+    layout_id: Rc<Cell<Option<LayoutId>>>,
+}
+
+impl IntoElement for Tracked {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Tracked {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let layout_id = self.child.request_layout(window, cx);
+        self.layout_id.set(Some(layout_id));
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.paint(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Align;
+    use crate::layout::{Alignment, AlignmentDirectional, Directionality, SizedBox, TextDirection};
+    use crate::test_util::{draw, probe, sized_probe};
+    use gpui::{div, point, px, size, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_align_fractional_position(cx: &mut TestAppContext) {
+        let (child, bounds) = sized_probe(20., 40.);
+
+        draw(cx, size(px(100.), px(100.)), move |_, _| {
+            Align::new(child).alignment(Alignment::new(-0.5, 1.0))
+        });
+
+        assert_eq!(bounds.get().origin, point(px(20.), px(60.)));
+        assert_eq!(bounds.get().size, size(px(20.), px(40.)));
+    }
+
+    #[gpui::test]
+    fn test_align_child_shrink_wraps_and_resolves_percentages(cx: &mut TestAppContext) {
+        let (inner, inner_bounds) = probe();
+
+        draw(cx, size(px(200.), px(100.)), move |_, _| {
+            Align::new(div().w_1_2().h(px(10.)).child(inner)).bottom_right()
+        });
+
+        assert_eq!(
+            inner_bounds.get(),
+            gpui::Bounds {
+                origin: point(px(100.), px(90.)),
+                size: size(px(100.), px(10.)),
+            }
+        );
+    }
+
+    #[gpui::test]
+    fn test_align_directional_follows_text_direction(cx: &mut TestAppContext) {
+        let (ltr, ltr_bounds) = sized_probe(20., 20.);
+        let (rtl, rtl_bounds) = sized_probe(20., 20.);
+        let (ambient, ambient_bounds) = sized_probe(20., 20.);
+
+        draw(cx, size(px(100.), px(200.)), move |_, _| {
+            div()
+                .flex()
+                .flex_col()
+                .size_full()
+                .child(
+                    SizedBox::new()
+                        .size(100., 50.)
+                        .child(Align::new(ltr).alignment(AlignmentDirectional::CenterStart)),
+                )
+                .child(
+                    SizedBox::new().size(100., 50.).child(
+                        Align::new(rtl)
+                            .alignment(AlignmentDirectional::CenterStart)
+                            .text_direction(TextDirection::Rtl),
+                    ),
+                )
+                .child(Directionality::rtl(SizedBox::new().size(100., 50.).child(
+                    Align::new(ambient).alignment(AlignmentDirectional::CenterStart),
+                )))
+        });
+
+        assert_eq!(ltr_bounds.get().origin, point(px(0.), px(15.)));
+        assert_eq!(rtl_bounds.get().origin, point(px(80.), px(65.)));
+        assert_eq!(ambient_bounds.get().origin, point(px(80.), px(115.)));
+    }
+
+    #[gpui::test]
+    fn test_align_size_factors(cx: &mut TestAppContext) {
+        let (child, child_bounds) = sized_probe(30., 10.);
+        let (align, align_bounds) = probe();
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            div().flex().items_start().child(
+                div()
+                    .relative()
+                    .child(Align::new(child).width_factor(2.).height_factor(3.))
+                    .child(div().absolute().size_full().child(align)),
+            )
+        });
+
+        assert_eq!(align_bounds.get().size, size(px(60.), px(30.)));
+        assert_eq!(child_bounds.get().origin, point(px(15.), px(10.)));
     }
 }
@@ -0,0 +1,187 @@
+// crates/ui/src/layout/alignment.rs
+
+use gpui::{point, Bounds, Pixels, Size};
+
+/// The reading direction of text and of layouts that follow it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDirection {
+    /// Left to right (English, French, ...)
+    #[default]
+    Ltr,
+    /// Right to left (Arabic, Hebrew, ...)
+    Rtl,
+}
+
+/// A point within a box, Flutter-style
+///
+/// `x` and `y` range from -1.0 (left/top edge) to 1.0 (right/bottom edge),
+/// with 0.0 at the center. Values outside that range place the child
+/// outside the box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alignment {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[allow(non_upper_case_globals)]
+impl Alignment {
+    pub const TopLeft: Alignment = Alignment::new(-1.0, -1.0);
+    pub const TopCenter: Alignment = Alignment::new(0.0, -1.0);
+    pub const TopRight: Alignment = Alignment::new(1.0, -1.0);
+    pub const CenterLeft: Alignment = Alignment::new(-1.0, 0.0);
+    pub const Center: Alignment = Alignment::new(0.0, 0.0);
+    pub const CenterRight: Alignment = Alignment::new(1.0, 0.0);
+    pub const BottomLeft: Alignment = Alignment::new(-1.0, 1.0);
+    pub const BottomCenter: Alignment = Alignment::new(0.0, 1.0);
+    pub const BottomRight: Alignment = Alignment::new(1.0, 1.0);
+}
+
+impl Alignment {
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Place a box of the given size inside `container` according to this alignment
+    pub fn inscribe(self, child: Size<Pixels>, container: Bounds<Pixels>) -> Bounds<Pixels> {
+        let free_width = container.size.width - child.width;
+        let free_height = container.size.height - child.height;
+
+        Bounds {
+            origin: container.origin
+                + point(
+                    free_width * ((self.x + 1.0) / 2.0),
+                    free_height * ((self.y + 1.0) / 2.0),
+                ),
+            size: child,
+        }
+    }
+}
+
+impl Default for Alignment {
+    fn default() -> Self {
+        Self::Center
+    }
+}
+
+/// An alignment whose horizontal component follows the text direction
+///
+/// `start` is -1.0 at the leading edge and 1.0 at the trailing edge: the
+/// left and right edges respectively in LTR, mirrored in RTL.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlignmentDirectional {
+    pub start: f32,
+    pub y: f32,
+}
+
+#[allow(non_upper_case_globals)]
+impl AlignmentDirectional {
+    pub const TopStart: AlignmentDirectional = AlignmentDirectional::new(-1.0, -1.0);
+    pub const TopCenter: AlignmentDirectional = AlignmentDirectional::new(0.0, -1.0);
+    pub const TopEnd: AlignmentDirectional = AlignmentDirectional::new(1.0, -1.0);
+    pub const CenterStart: AlignmentDirectional = AlignmentDirectional::new(-1.0, 0.0);
+    pub const Center: AlignmentDirectional = AlignmentDirectional::new(0.0, 0.0);
+    pub const CenterEnd: AlignmentDirectional = AlignmentDirectional::new(1.0, 0.0);
+    pub const BottomStart: AlignmentDirectional = AlignmentDirectional::new(-1.0, 1.0);
+    pub const BottomCenter: AlignmentDirectional = AlignmentDirectional::new(0.0, 1.0);
+    pub const BottomEnd: AlignmentDirectional = AlignmentDirectional::new(1.0, 1.0);
+}
+
+impl AlignmentDirectional {
+    pub const fn new(start: f32, y: f32) -> Self {
+        Self { start, y }
+    }
+
+    /// Convert to an absolute alignment for the given text direction
+    pub fn resolve(self, direction: TextDirection) -> Alignment {
+        match direction {
+            TextDirection::Ltr => Alignment::new(self.start, self.y),
+            TextDirection::Rtl => Alignment::new(-self.start, self.y),
+        }
+    }
+}
+
+/// Either an absolute or a direction-dependent alignment
+///
+/// Widgets take `impl Into<AlignmentGeometry>` so both kinds can be passed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlignmentGeometry {
+    Absolute(Alignment),
+    Directional(AlignmentDirectional),
+}
+
+impl AlignmentGeometry {
+    pub fn resolve(self, direction: TextDirection) -> Alignment {
+        match self {
+            AlignmentGeometry::Absolute(alignment) => alignment,
+            AlignmentGeometry::Directional(alignment) => alignment.resolve(direction),
+        }
+    }
+}
+
+impl Default for AlignmentGeometry {
+    fn default() -> Self {
+        AlignmentGeometry::Absolute(Alignment::Center)
+    }
+}
+
+impl From<Alignment> for AlignmentGeometry {
+    fn from(alignment: Alignment) -> Self {
+        AlignmentGeometry::Absolute(alignment)
+    }
+}
+
+impl From<AlignmentDirectional> for AlignmentGeometry {
+    fn from(alignment: AlignmentDirectional) -> Self {
+        AlignmentGeometry::Directional(alignment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alignment, AlignmentDirectional, AlignmentGeometry, TextDirection};
+    use gpui::{point, px, size, Bounds};
+
+    #[test]
+    fn test_alignment_inscribe() {
+        let container = Bounds {
+            origin: point(px(10.), px(10.)),
+            size: size(px(100.), px(100.)),
+        };
+        let child = size(px(20.), px(40.));
+
+        assert_eq!(
+            Alignment::Center.inscribe(child, container).origin,
+            point(px(50.), px(40.))
+        );
+        assert_eq!(
+            Alignment::BottomRight.inscribe(child, container).origin,
+            point(px(90.), px(70.))
+        );
+        // A quarter of the way from the left, at the top
+        assert_eq!(
+            Alignment::new(-0.5, -1.0).inscribe(child, container).origin,
+            point(px(30.), px(10.))
+        );
+    }
+
+    #[test]
+    fn test_directional_alignment_resolves_by_direction() {
+        assert_eq!(
+            AlignmentDirectional::TopStart.resolve(TextDirection::Ltr),
+            Alignment::TopLeft
+        );
+        assert_eq!(
+            AlignmentDirectional::TopStart.resolve(TextDirection::Rtl),
+            Alignment::TopRight
+        );
+
+        let geometry: AlignmentGeometry = AlignmentDirectional::new(-0.5, 0.0).into();
+        assert_eq!(
+            geometry.resolve(TextDirection::Rtl),
+            Alignment::new(0.5, 0.0)
+        );
+
+        let absolute: AlignmentGeometry = Alignment::CenterLeft.into();
+        assert_eq!(absolute.resolve(TextDirection::Rtl), Alignment::CenterLeft);
+    }
+}
@@ -0,0 +1,181 @@
+// crates/ui/src/layout/aspect_ratio.rs
+
+use super::BoxConstraints;
+use gpui::*;
+
+/// Flutter-style AspectRatio widget
+///
+/// Sizes itself to the largest box with `width / height == aspect_ratio`
+/// that fits the space offered by its parent, then fills it with the child.
+pub struct AspectRatio {
+    child: AnyElement,
+    aspect_ratio: f32,
+}
+
+impl AspectRatio {
+    pub fn new(aspect_ratio: f32, child: impl IntoElement) -> Self {
+        debug_assert!(
+            aspect_ratio.is_finite() && aspect_ratio > 0.,
+            "aspect ratio must be a positive finite number"
+        );
+
+        Self {
+            child: div().size_full().child(child).into_any_element(),
+            aspect_ratio,
+        }
+    }
+
+    /// Compute the size this widget takes under the given constraints
+    pub fn apply(aspect_ratio: f32, constraints: BoxConstraints) -> Size<Pixels> {
+        if constraints.is_tight() {
+            return constraints.smallest();
+        }
+
+        let mut width;
+        let mut height;
+
+        if constraints.has_bounded_width() {
+            width = constraints.max_width;
+            height = width / aspect_ratio;
+        } else if constraints.has_bounded_height() {
+            height = constraints.max_height;
+            width = height * aspect_ratio;
+        } else {
+            // Unbounded on both axes: nothing to derive a size from
+            return constraints.smallest();
+        }
+
+        if width > constraints.max_width {
+            width = constraints.max_width;
+            height = width / aspect_ratio;
+        }
+
+        if height > constraints.max_height {
+            height = constraints.max_height;
+            width = height * aspect_ratio;
+        }
+
+        if width < constraints.min_width {
+            width = constraints.min_width;
+            height = width / aspect_ratio;
+        }
+
+        if height < constraints.min_height {
+            height = constraints.min_height;
+            width = height * aspect_ratio;
+        }
+
+        constraints.constrain(size(width, height))
+    }
+}
+
+impl IntoElement for AspectRatio {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for AspectRatio {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        _cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let aspect_ratio = self.aspect_ratio;
+        let layout_id = window.request_measured_layout(
+            Style::default(),
+            move |known_dimensions, available_space, _window, _cx| {
+                let mut constraints = BoxConstraints::from_available_space(available_space);
+                if let Some(width) = known_dimensions.width {
+                    constraints.min_width = width;
+                    constraints.max_width = width;
+                }
+                if let Some(height) = known_dimensions.height {
+                    constraints.min_height = height;
+                    constraints.max_height = height;
+                }
+                AspectRatio::apply(aspect_ratio, constraints)
+            },
+        );
+
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child
+            .prepaint_as_root(bounds.origin, bounds.size.into(), window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.paint(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AspectRatio;
+    use crate::layout::BoxConstraints;
+    use crate::test_util::{draw, probe};
+    use gpui::{div, px, size, ParentElement, Styled, TestAppContext};
+
+    #[test]
+    fn test_apply_uses_max_width() {
+        let constraints = BoxConstraints::loose(size(px(400.), px(400.)));
+        assert_eq!(
+            AspectRatio::apply(16. / 9., constraints),
+            size(px(400.), px(225.))
+        );
+    }
+
+    #[test]
+    fn test_apply_shrinks_to_fit_height() {
+        let constraints = BoxConstraints::loose(size(px(400.), px(100.)));
+        assert_eq!(
+            AspectRatio::apply(2., constraints),
+            size(px(200.), px(100.))
+        );
+    }
+
+    #[gpui::test]
+    fn test_aspect_ratio_layout(cx: &mut TestAppContext) {
+        let (child, bounds) = probe();
+
+        draw(cx, size(px(300.), px(600.)), move |_, _| {
+            div().size_full().child(AspectRatio::new(3. / 2., child))
+        });
+
+        assert_eq!(bounds.get().size, size(px(300.), px(200.)));
+    }
+}
@@ -0,0 +1,187 @@
+// crates/ui/src/layout/constraints.rs
+
+use gpui::*;
+
+/// Flutter-style BoxConstraints
+///
+/// Describes the range of sizes a widget is allowed to take. An unbounded
+/// axis uses `Pixels::MAX` as its maximum.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxConstraints {
+    pub min_width: Pixels,
+    pub max_width: Pixels,
+    pub min_height: Pixels,
+    pub max_height: Pixels,
+}
+
+impl BoxConstraints {
+    pub fn new(
+        min_width: Pixels,
+        max_width: Pixels,
+        min_height: Pixels,
+        max_height: Pixels,
+    ) -> Self {
+        Self {
+            min_width,
+            max_width,
+            min_height,
+            max_height,
+        }
+    }
+
+    /// Constraints that only allow exactly the given size
+    pub fn tight(size: Size<Pixels>) -> Self {
+        Self::new(size.width, size.width, size.height, size.height)
+    }
+
+    /// Constraints that allow any size up to the given size
+    pub fn loose(size: Size<Pixels>) -> Self {
+        Self::new(px(0.), size.width, px(0.), size.height)
+    }
+
+    /// Constraints with no upper bound on either axis
+    pub fn unbounded() -> Self {
+        Self::new(px(0.), Pixels::MAX, px(0.), Pixels::MAX)
+    }
+
+    /// Build constraints from the space GPUI offers an element during layout
+    pub fn from_available_space(space: Size<AvailableSpace>) -> Self {
+        let max = |space: AvailableSpace| match space {
+            AvailableSpace::Definite(value) => value,
+            AvailableSpace::MinContent | AvailableSpace::MaxContent => Pixels::MAX,
+        };
+
+        Self::new(px(0.), max(space.width), px(0.), max(space.height))
+    }
+
+    pub fn has_bounded_width(&self) -> bool {
+        self.max_width < Pixels::MAX
+    }
+
+    pub fn has_bounded_height(&self) -> bool {
+        self.max_height < Pixels::MAX
+    }
+
+    pub fn has_tight_width(&self) -> bool {
+        self.min_width >= self.max_width
+    }
+
+    pub fn has_tight_height(&self) -> bool {
+        self.min_height >= self.max_height
+    }
+
+    pub fn is_tight(&self) -> bool {
+        self.has_tight_width() && self.has_tight_height()
+    }
+
+    /// The largest size that satisfies the constraints
+    pub fn biggest(&self) -> Size<Pixels> {
+        size(self.max_width, self.max_height)
+    }
+
+    /// The smallest size that satisfies the constraints
+    pub fn smallest(&self) -> Size<Pixels> {
+        size(self.min_width, self.min_height)
+    }
+
+    pub fn constrain_width(&self, width: Pixels) -> Pixels {
+        width.clamp(self.min_width, self.max_width)
+    }
+
+    pub fn constrain_height(&self, height: Pixels) -> Pixels {
+        height.clamp(self.min_height, self.max_height)
+    }
+
+    /// Clamp a size into the constraints
+    pub fn constrain(&self, size: Size<Pixels>) -> Size<Pixels> {
+        gpui::size(
+            self.constrain_width(size.width),
+            self.constrain_height(size.height),
+        )
+    }
+
+    /// Clamp a size into the constraints, shrinking the other axis to keep
+    /// the aspect ratio where the constraints allow it
+    pub fn constrain_size_and_attempt_to_preserve_aspect_ratio(
+        &self,
+        size: Size<Pixels>,
+    ) -> Size<Pixels> {
+        if self.is_tight() {
+            return self.smallest();
+        }
+
+        if size.width <= px(0.) || size.height <= px(0.) {
+            return self.constrain(size);
+        }
+
+        let aspect_ratio = size.width / size.height;
+        let mut width = size.width;
+        let mut height = size.height;
+
+        if width > self.max_width {
+            width = self.max_width;
+            height = width / aspect_ratio;
+        }
+
+        if height > self.max_height {
+            height = self.max_height;
+            width = height * aspect_ratio;
+        }
+
+        if width < self.min_width {
+            width = self.min_width;
+            height = width / aspect_ratio;
+        }
+
+        if height < self.min_height {
+            height = self.min_height;
+            width = height * aspect_ratio;
+        }
+
+        self.constrain(gpui::size(width, height))
+    }
+}
+
+impl Default for BoxConstraints {
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoxConstraints;
+    use gpui::{px, size, AvailableSpace};
+
+    #[test]
+    fn test_from_available_space() {
+        let constraints = BoxConstraints::from_available_space(size(
+            AvailableSpace::Definite(px(200.)),
+            AvailableSpace::MaxContent,
+        ));
+
+        assert!(constraints.has_bounded_width());
+        assert!(!constraints.has_bounded_height());
+        assert_eq!(constraints.max_width, px(200.));
+    }
+
+    #[test]
+    fn test_constrain_preserves_aspect_ratio() {
+        let constraints = BoxConstraints::loose(size(px(100.), px(100.)));
+        let constrained = constraints
+            .constrain_size_and_attempt_to_preserve_aspect_ratio(size(px(400.), px(200.)));
+
+        assert_eq!(constrained, size(px(100.), px(50.)));
+    }
+
+    #[test]
+    fn test_tight_constraints_ignore_size() {
+        let constraints = BoxConstraints::tight(size(px(30.), px(40.)));
+
+        assert!(constraints.is_tight());
+        assert_eq!(
+            constraints.constrain_size_and_attempt_to_preserve_aspect_ratio(size(px(10.), px(10.))),
+            size(px(30.), px(40.))
+        );
+    }
+}
@@ -0,0 +1,168 @@
+// crates/ui/src/layout/directionality.rs
+
+use super::TextDirection;
+use gpui::*;
+
+/// Flutter-style Directionality widget
+///
+/// Sets the [`TextDirection`] for everything inside it. Direction-aware
+/// widgets ([`Row`](crate::components::Row), [`Padding`](super::Padding)'s
+/// start/end insets, [`Align`](super::Align), [`Positioned`](super::Positioned),
+/// mirrored icons) read it with [`TextDirection::of`] while they are laid
+/// out and painted. Outside any Directionality the direction is LTR.
+///
+/// ```ignore
+/// Directionality::rtl(
+///     Row::new().child(Icon::new(LucideIcon::ChevronRight).match_text_direction(true)).child("التالي"),
+/// )
+/// ```
+pub struct Directionality {
+    direction: TextDirection,
+    child: AnyElement,
+}
+
+impl Directionality {
+    pub fn new(direction: TextDirection, child: impl IntoElement) -> Self {
+        Self {
+            direction,
+            child: child.into_any_element(),
+        }
+    }
+
+    pub fn ltr(child: impl IntoElement) -> Self {
+        Self::new(TextDirection::Ltr, child)
+    }
+
+    pub fn rtl(child: impl IntoElement) -> Self {
+        Self::new(TextDirection::Rtl, child)
+    }
+}
+
+/// Directions of the enclosing Directionality elements, innermost last
+#[derive(Default)]
+struct DirectionStack(Vec<TextDirection>);
+
+impl Global for DirectionStack {}
+
+impl TextDirection {
+    /// Direction set by the innermost enclosing [`Directionality`]
+    ///
+    /// Only meaningful while elements are rendered, laid out or painted.
+    pub fn of(cx: &App) -> TextDirection {
+        cx.try_global::<DirectionStack>()
+            .and_then(|stack| stack.0.last().copied())
+            .unwrap_or_default()
+    }
+}
+
+fn with_text_direction<R>(
+    direction: TextDirection,
+    window: &mut Window,
+    cx: &mut App,
+    f: impl FnOnce(&mut Window, &mut App) -> R,
+) -> R {
+    cx.default_global::<DirectionStack>().0.push(direction);
+    let result = f(window, cx);
+    cx.global_mut::<DirectionStack>().0.pop();
+    result
+}
+
+impl IntoElement for Directionality {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Directionality {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let layout_id = with_text_direction(self.direction, window, cx, |window, cx| {
+            self.child.request_layout(window, cx)
+        });
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        with_text_direction(self.direction, window, cx, |window, cx| {
+            self.child.prepaint(window, cx);
+        });
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        with_text_direction(self.direction, window, cx, |window, cx| {
+            self.child.paint(window, cx);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Directionality;
+    use crate::layout::TextDirection;
+    use crate::test_util::draw;
+    use gpui::{canvas, div, px, size, ParentElement, Styled, TestAppContext};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn test_directionality_nests_and_restores(cx: &mut TestAppContext) {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let record = |seen: &Rc<RefCell<Vec<TextDirection>>>| {
+            let seen = seen.clone();
+            canvas(
+                move |_, _, cx| seen.borrow_mut().push(TextDirection::of(cx)),
+                |_, _, _, _| {},
+            )
+            .size(px(1.))
+        };
+        let (outer, inner, after) = (record(&seen), record(&seen), record(&seen));
+
+        draw(cx, size(px(100.), px(100.)), move |_, _| {
+            div()
+                .child(Directionality::rtl(
+                    div().child(outer).child(Directionality::ltr(inner)),
+                ))
+                .child(after)
+        });
+
+        assert_eq!(
+            *seen.borrow(),
+            [TextDirection::Rtl, TextDirection::Ltr, TextDirection::Ltr]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+// crates/ui/src/layout/edge_insets.rs
+
+use gpui::*;
+
+/// Flutter-style EdgeInsets: an offset for each of the four sides of a box
+///
+/// A plain `Pixels` value (such as a theme spacing token) converts into
+/// equal insets on every side.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EdgeInsets {
+    pub top: Pixels,
+    pub right: Pixels,
+    pub bottom: Pixels,
+    pub left: Pixels,
+}
+
+impl EdgeInsets {
+    pub const ZERO: EdgeInsets = EdgeInsets {
+        top: Pixels::ZERO,
+        right: Pixels::ZERO,
+        bottom: Pixels::ZERO,
+        left: Pixels::ZERO,
+    };
+
+    /// The same inset on every side
+    pub fn all(value: impl Into<Pixels>) -> Self {
+        let value = value.into();
+        Self {
+            top: value,
+            right: value,
+            bottom: value,
+            left: value,
+        }
+    }
+
+    /// Equal left/right and top/bottom insets
+    pub fn symmetric(horizontal: impl Into<Pixels>, vertical: impl Into<Pixels>) -> Self {
+        let horizontal = horizontal.into();
+        let vertical = vertical.into();
+        Self {
+            top: vertical,
+            right: horizontal,
+            bottom: vertical,
+            left: horizontal,
+        }
+    }
+
+    /// Insets given clockwise from the left, like Flutter's `EdgeInsets.fromLTRB`
+    pub fn from_ltrb(
+        left: impl Into<Pixels>,
+        top: impl Into<Pixels>,
+        right: impl Into<Pixels>,
+        bottom: impl Into<Pixels>,
+    ) -> Self {
+        Self {
+            top: top.into(),
+            right: right.into(),
+            bottom: bottom.into(),
+            left: left.into(),
+        }
+    }
+
+    pub fn top(mut self, value: impl Into<Pixels>) -> Self {
+        self.top = value.into();
+        self
+    }
+
+    pub fn right(mut self, value: impl Into<Pixels>) -> Self {
+        self.right = value.into();
+        self
+    }
+
+    pub fn bottom(mut self, value: impl Into<Pixels>) -> Self {
+        self.bottom = value.into();
+        self
+    }
+
+    pub fn left(mut self, value: impl Into<Pixels>) -> Self {
+        self.left = value.into();
+        self
+    }
+
+    /// Total inset along the horizontal axis
+    pub fn horizontal(&self) -> Pixels {
+        self.left + self.right
+    }
+
+    /// Total inset along the vertical axis
+    pub fn vertical(&self) -> Pixels {
+        self.top + self.bottom
+    }
+
+    /// Shrink a rectangle by these insets
+    pub fn deflate(&self, bounds: Bounds<Pixels>) -> Bounds<Pixels> {
+        Bounds {
+            origin: bounds.origin + point(self.left, self.top),
+            size: size(
+                (bounds.size.width - self.horizontal()).max(Pixels::ZERO),
+                (bounds.size.height - self.vertical()).max(Pixels::ZERO),
+            ),
+        }
+    }
+}
+
+impl From<Pixels> for EdgeInsets {
+    fn from(value: Pixels) -> Self {
+        Self::all(value)
+    }
+}
+
+impl From<EdgeInsets> for Edges<Pixels> {
+    fn from(insets: EdgeInsets) -> Self {
+        Edges {
+            top: insets.top,
+            right: insets.right,
+            bottom: insets.bottom,
+            left: insets.left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EdgeInsets;
+    use gpui::{point, px, size, Bounds};
+
+    #[test]
+    fn test_edge_insets_deflate() {
+        let insets = EdgeInsets::symmetric(px(10.), px(5.)).left(px(20.));
+        let bounds = Bounds {
+            origin: point(px(0.), px(0.)),
+            size: size(px(100.), px(50.)),
+        };
+
+        assert_eq!(insets.horizontal(), px(30.));
+        assert_eq!(
+            insets.deflate(bounds),
+            Bounds {
+                origin: point(px(20.), px(5.)),
+                size: size(px(70.), px(40.)),
+            }
+        );
+        assert_eq!(EdgeInsets::from(px(8.)), EdgeInsets::all(px(8.)));
+    }
+}
@@ -0,0 +1,205 @@
+// crates/ui/src/layout/fitted_box.rs
+
+use super::{Alignment, BoxConstraints, BoxFit};
+use gpui::*;
+
+/// Flutter-style FittedBox widget
+///
+/// Fills its parent when the parent has a definite size (otherwise takes the
+/// child's natural size) and fits the child into it according to a [`BoxFit`].
+///
+/// GPUI cannot apply a paint transform to an arbitrary element tree, so the
+/// child is laid out again at the fitted size instead of being scaled.
+/// Children that size themselves relative to their parent follow the fit;
+/// fixed-size content keeps its size. Anything overflowing the box is clipped.
+pub struct FittedBox {
+    child: AnyElement,
+    fit: BoxFit,
+    alignment: Alignment,
+}
+
+impl FittedBox {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: div().size_full().child(child).into_any_element(),
+            fit: BoxFit::Contain,
+            alignment: Alignment::Center,
+        }
+    }
+
+    pub fn fit(mut self, fit: BoxFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    // Shortcut methods
+    pub fn contain(self) -> Self {
+        self.fit(BoxFit::Contain)
+    }
+
+    pub fn cover(self) -> Self {
+        self.fit(BoxFit::Cover)
+    }
+
+    pub fn fill(self) -> Self {
+        self.fit(BoxFit::Fill)
+    }
+
+    pub fn fit_width(self) -> Self {
+        self.fit(BoxFit::FitWidth)
+    }
+
+    pub fn fit_height(self) -> Self {
+        self.fit(BoxFit::FitHeight)
+    }
+
+    pub fn scale_down(self) -> Self {
+        self.fit(BoxFit::ScaleDown)
+    }
+}
+
+impl IntoElement for FittedBox {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for FittedBox {
+    /// The child's natural size
+    type RequestLayoutState = Size<Pixels>;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let natural_size = self.child.layout_as_root(
+            size(AvailableSpace::MaxContent, AvailableSpace::MaxContent),
+            window,
+            cx,
+        );
+
+        let style = Style {
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Default::default()
+        };
+
+        let layout_id = window.request_measured_layout(
+            style,
+            move |known_dimensions, available_space, _window, _cx| {
+                let mut constraints = BoxConstraints::from_available_space(available_space);
+                if let Some(width) = known_dimensions.width {
+                    constraints.min_width = width;
+                    constraints.max_width = width;
+                }
+                if let Some(height) = known_dimensions.height {
+                    constraints.min_height = height;
+                    constraints.max_height = height;
+                }
+                constraints.constrain_size_and_attempt_to_preserve_aspect_ratio(natural_size)
+            },
+        );
+
+        (layout_id, natural_size)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        natural_size: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let fitted = self.fit.apply(*natural_size, bounds.size);
+        let child_bounds = self.alignment.inscribe(fitted, bounds);
+
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            self.child
+                .prepaint_as_root(child_bounds.origin, fitted.into(), window, cx);
+        });
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _natural_size: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            self.child.paint(window, cx);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FittedBox;
+    use crate::layout::{BoxFit, SizedBox};
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{point, px, size, Bounds, Pixels, Styled, TestAppContext};
+
+    fn fitted(fit: BoxFit, cx: &mut TestAppContext) -> Bounds<Pixels> {
+        let (child, bounds) = sized_probe(200., 50.);
+
+        draw(cx, size(px(400.), px(400.)), move |_, _| {
+            // Measures 200x50 on its own, then follows the fitted size
+            let content = child.min_w_full().max_w_full().min_h_full().max_h_full();
+
+            SizedBox::new()
+                .size(100., 100.)
+                .child(FittedBox::new(content).fit(fit))
+        });
+
+        bounds.get()
+    }
+
+    #[gpui::test]
+    fn test_fitted_box_contain(cx: &mut TestAppContext) {
+        let bounds = fitted(BoxFit::Contain, cx);
+        assert_eq!(bounds.size, size(px(100.), px(25.)));
+        assert_eq!(bounds.origin, point(px(0.), px(37.5)));
+    }
+
+    #[gpui::test]
+    fn test_fitted_box_cover(cx: &mut TestAppContext) {
+        let bounds = fitted(BoxFit::Cover, cx);
+        assert_eq!(bounds.size, size(px(400.), px(100.)));
+        assert_eq!(bounds.origin, point(px(-150.), px(0.)));
+    }
+
+    #[gpui::test]
+    fn test_fitted_box_fill(cx: &mut TestAppContext) {
+        let bounds = fitted(BoxFit::Fill, cx);
+        assert_eq!(bounds.size, size(px(100.), px(100.)));
+    }
+
+    #[gpui::test]
+    fn test_fitted_box_scale_down(cx: &mut TestAppContext) {
+        let bounds = fitted(BoxFit::ScaleDown, cx);
+        assert_eq!(bounds.size, size(px(100.), px(25.)));
+    }
+}
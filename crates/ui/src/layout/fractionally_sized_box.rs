@@ -0,0 +1,100 @@
+// crates/ui/src/layout/fractionally_sized_box.rs
+
+use super::{Align, AlignmentGeometry};
+use gpui::*;
+
+/// Flutter-style FractionallySizedBox widget
+///
+/// Sizes its child to a fraction of the parent's size. A factor left unset
+/// lets the child size itself on that axis.
+#[derive(IntoElement)]
+pub struct FractionallySizedBox {
+    child: AnyElement,
+    width_factor: Option<f32>,
+    height_factor: Option<f32>,
+    alignment: AlignmentGeometry,
+}
+
+impl FractionallySizedBox {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            width_factor: None,
+            height_factor: None,
+            alignment: AlignmentGeometry::default(),
+        }
+    }
+
+    /// Fraction of the parent width given to the child (e.g. 0.5 for half)
+    pub fn width_factor(mut self, factor: f32) -> Self {
+        self.width_factor = Some(factor.max(0.));
+        self
+    }
+
+    /// Fraction of the parent height given to the child
+    pub fn height_factor(mut self, factor: f32) -> Self {
+        self.height_factor = Some(factor.max(0.));
+        self
+    }
+
+    /// Set both factors at once
+    pub fn factor(self, factor: f32) -> Self {
+        self.width_factor(factor).height_factor(factor)
+    }
+
+    pub fn alignment(mut self, alignment: impl Into<AlignmentGeometry>) -> Self {
+        self.alignment = alignment.into();
+        self
+    }
+}
+
+impl RenderOnce for FractionallySizedBox {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let mut child = div().flex().flex_none().child(self.child);
+
+        if let Some(factor) = self.width_factor {
+            child = child.w(relative(factor));
+        }
+
+        if let Some(factor) = self.height_factor {
+            child = child.h(relative(factor));
+        }
+
+        Align::new(child).alignment(self.alignment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FractionallySizedBox;
+    use crate::layout::Alignment;
+    use crate::test_util::{draw, probe};
+    use gpui::{point, px, size, TestAppContext};
+
+    #[gpui::test]
+    fn test_fractional_size(cx: &mut TestAppContext) {
+        let (child, bounds) = probe();
+
+        draw(cx, size(px(400.), px(200.)), move |_, _| {
+            FractionallySizedBox::new(child)
+                .width_factor(0.5)
+                .height_factor(0.25)
+        });
+
+        assert_eq!(bounds.get().size, size(px(200.), px(50.)));
+        assert_eq!(bounds.get().origin, point(px(100.), px(75.)));
+    }
+
+    #[gpui::test]
+    fn test_fractional_alignment(cx: &mut TestAppContext) {
+        let (child, bounds) = probe();
+
+        draw(cx, size(px(400.), px(200.)), move |_, _| {
+            FractionallySizedBox::new(child)
+                .factor(0.5)
+                .alignment(Alignment::BottomRight)
+        });
+
+        assert_eq!(bounds.get().origin, point(px(200.), px(100.)));
+    }
+}
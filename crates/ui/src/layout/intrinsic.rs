@@ -0,0 +1,350 @@
+// crates/ui/src/layout/intrinsic.rs
+
+use gpui::*;
+
+/// Flutter-style IntrinsicWidth widget
+///
+/// Sizes its child to the child's max-content width, so that children of
+/// the child which would otherwise stretch to the parent (dividers, full
+/// width buttons) line up with the widest of their siblings instead.
+///
+/// The height is the child's height at that width. `step_width` and
+/// `step_height` round either up to a multiple of the step.
+pub struct IntrinsicWidth {
+    child: AnyElement,
+    step_width: Option<Pixels>,
+    step_height: Option<Pixels>,
+}
+
+impl IntrinsicWidth {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            step_width: None,
+            step_height: None,
+        }
+    }
+
+    /// Round the width up to a multiple of this value
+    pub fn step_width(mut self, step: f32) -> Self {
+        self.step_width = (step > 0.).then(|| px(step));
+        self
+    }
+
+    /// Round the height up to a multiple of this value
+    pub fn step_height(mut self, step: f32) -> Self {
+        self.step_height = (step > 0.).then(|| px(step));
+        self
+    }
+}
+
+/// Flutter-style IntrinsicHeight widget
+///
+/// Sizes its child to the child's max-content height at the width it is
+/// given, and makes that height definite. Children of the child sized as a
+/// fraction of its height (`h_full` dividers, stretched cards) then match
+/// the tallest of their siblings instead of collapsing.
+///
+/// ```ignore
+/// IntrinsicHeight::new(
+///     div().flex().child(card_a).child(div().w_px().h_full().bg(border)).child(card_b),
+/// )
+/// ```
+pub struct IntrinsicHeight {
+    child: AnyElement,
+}
+
+impl IntrinsicHeight {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+        }
+    }
+}
+
+/// Size of an already requested layout when laid out on its own
+///
+/// The node is placed under a throwaway parent, so the absolute bounds
+/// cached for the node itself stay untouched for the real layout.
+pub(super) fn measure_detached(
+    layout_id: LayoutId,
+    available: Size<AvailableSpace>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Size<Pixels> {
+    let probe = window.request_layout(Style::default(), [layout_id], cx);
+    window.compute_layout(probe, available, cx);
+    let measured = window.layout_bounds(probe).size;
+
+    // Taffy reuses a node's last layout for any later request whose size
+    // matches its result, so the node would keep the percentages it
+    // resolved while being measured. Laying it out once at zero size makes
+    // the real pass lay it out afresh.
+    let evict = window.request_layout(cell_style(px(0.).into(), px(0.).into()), [layout_id], cx);
+    window.compute_layout(evict, size(px(0.), px(0.)).into(), cx);
+
+    measured
+}
+
+fn round_up(value: Pixels, step: Option<Pixels>) -> Pixels {
+    match step {
+        Some(step) => step * (value / step).ceil(),
+        None => value,
+    }
+}
+
+/// A single grid cell of the given size, stretching its child over it
+///
+/// An auto size sizes the cell to the child's content and still gives the
+/// child a definite size to resolve percentages against.
+fn cell_style(width: Length, height: Length) -> Style {
+    Style {
+        display: Display::Grid,
+        grid_cols: Some(1),
+        grid_rows: Some(1),
+        size: size(width, height),
+        ..Default::default()
+    }
+}
+
+impl IntoElement for IntrinsicWidth {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for IntrinsicWidth {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let child = self.child.request_layout(window, cx);
+
+        let natural = measure_detached(
+            child,
+            size(AvailableSpace::MaxContent, AvailableSpace::MaxContent),
+            window,
+            cx,
+        );
+        let width = round_up(natural.width, self.step_width);
+        let available = size(AvailableSpace::Definite(width), AvailableSpace::MaxContent);
+        let height = round_up(
+            measure_detached(child, available, window, cx).height,
+            self.step_height,
+        );
+
+        let style = cell_style(width.into(), height.into());
+        let layout_id = window.request_layout(style, [child], cx);
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.paint(window, cx);
+    }
+}
+
+impl IntoElement for IntrinsicHeight {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for IntrinsicHeight {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        // The height depends on the width this element ends up with, which is
+        // only known during layout; an implicit auto grid row measures the
+        // child's content at that width before stretching the child over it.
+        // (An explicit `1fr` row collapses children that center their content.)
+        let child = self.child.request_layout(window, cx);
+        let style = Style {
+            grid_rows: None,
+            ..cell_style(Length::Auto, Length::Auto)
+        };
+        let layout_id = window.request_layout(style, [child], cx);
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.paint(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntrinsicHeight, IntrinsicWidth};
+    use crate::test_util::{draw, probe, sized_probe};
+    use gpui::{div, point, px, size, Bounds, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_intrinsic_width_matches_widest_child(cx: &mut TestAppContext) {
+        let (label, _) = sized_probe(50., 10.);
+        let (divider, divider_bounds) = probe();
+        let (content, _) = sized_probe(50., 20.);
+        let (stepped, stepped_bounds) = probe();
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            div()
+                .flex()
+                .flex_col()
+                .child(IntrinsicWidth::new(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .child(label)
+                        .child(divider.h(px(10.))),
+                ))
+                .child(
+                    IntrinsicWidth::new(
+                        div()
+                            .relative()
+                            .child(content)
+                            .child(stepped.absolute().top_0().left_0()),
+                    )
+                    .step_width(32.)
+                    .step_height(16.),
+                )
+        });
+
+        // Without the wrapper the divider would span the whole column
+        assert_eq!(
+            divider_bounds.get(),
+            Bounds::new(point(px(0.), px(10.)), size(px(50.), px(10.)))
+        );
+        // 50 rounds up to 64 and 20 to 32; the child is stretched over both
+        assert_eq!(
+            stepped_bounds.get(),
+            Bounds::new(point(px(0.), px(20.)), size(px(64.), px(32.)))
+        );
+    }
+
+    #[gpui::test]
+    fn test_intrinsic_height_stretches_dividers(cx: &mut TestAppContext) {
+        let (card, _) = sized_probe(100., 60.);
+        let (divider, divider_bounds) = probe();
+        let (after, after_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            div()
+                .flex()
+                .flex_col()
+                .child(IntrinsicHeight::new(
+                    div()
+                        .flex()
+                        .child(card)
+                        .child(div().w(px(1.)).h_full().child(divider)),
+                ))
+                .child(after)
+        });
+
+        assert_eq!(
+            divider_bounds.get(),
+            Bounds::new(point(px(100.), px(0.)), size(px(1.), px(60.)))
+        );
+        assert_eq!(after_bounds.get().origin, point(px(0.), px(60.)));
+    }
+
+    #[gpui::test]
+    fn test_intrinsic_height_keeps_centered_children(cx: &mut TestAppContext) {
+        let (card, _) = sized_probe(100., 60.);
+        let (divider, divider_bounds) = probe();
+        let (after, after_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            div()
+                .w(px(400.))
+                .child(IntrinsicHeight::new(
+                    div()
+                        .flex()
+                        .items_center()
+                        .child(card)
+                        .child(div().w(px(1.)).h_full().child(divider)),
+                ))
+                .child(after)
+        });
+
+        // Under a block parent, centering the row's children must not
+        // collapse the full-height divider to nothing
+        assert_eq!(
+            divider_bounds.get(),
+            Bounds::new(point(px(100.), px(0.)), size(px(1.), px(60.)))
+        );
+        assert_eq!(after_bounds.get().origin, point(px(0.), px(60.)));
+    }
+}
@@ -0,0 +1,211 @@
+// crates/ui/src/layout/layout_builder.rs
+
+use super::BoxConstraints;
+use gpui::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+type BuildFn = Box<dyn FnOnce(BoxConstraints, &mut Window, &mut App) -> AnyElement>;
+
+/// Flutter-style LayoutBuilder widget
+///
+/// Fills the space offered by its parent and builds its child once that space
+/// is known, so the child can adapt to the size of its pane rather than the
+/// window. On an axis where the parent offers no definite size the
+/// constraints are unbounded and the builder takes no space along it.
+///
+/// ```ignore
+/// LayoutBuilder::new(|constraints, _window, _cx| {
+///     if constraints.max_width < px(600.) {
+///         list_view().into_any_element()
+///     } else {
+///         grid_view().into_any_element()
+///     }
+/// })
+/// ```
+pub struct LayoutBuilder {
+    builder: Option<BuildFn>,
+}
+
+impl LayoutBuilder {
+    pub fn new<E: IntoElement>(
+        builder: impl FnOnce(BoxConstraints, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        Self {
+            builder: Some(Box::new(move |constraints, window, cx| {
+                builder(constraints, window, cx).into_any_element()
+            })),
+        }
+    }
+}
+
+impl IntoElement for LayoutBuilder {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for LayoutBuilder {
+    /// Whether the parent offered a definite size on each axis
+    type RequestLayoutState = Rc<Cell<Size<bool>>>;
+    type PrepaintState = Option<AnyElement>;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        _cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let bounded = Rc::new(Cell::new(size(true, true)));
+        let style = Style {
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Default::default()
+        };
+
+        // The final layout pass reports whether each axis had a definite size
+        let measured = bounded.clone();
+        let layout_id = window.request_measured_layout(
+            style,
+            move |known_dimensions, available_space, _window, _cx| {
+                let resolve = |known: Option<Pixels>, available: AvailableSpace| match known {
+                    Some(value) => (value, true),
+                    None => match available {
+                        AvailableSpace::Definite(value) => (value, true),
+                        AvailableSpace::MinContent | AvailableSpace::MaxContent => (px(0.), false),
+                    },
+                };
+
+                let (width, bounded_width) = resolve(known_dimensions.width, available_space.width);
+                let (height, bounded_height) =
+                    resolve(known_dimensions.height, available_space.height);
+
+                measured.set(size(bounded_width, bounded_height));
+                size(width, height)
+            },
+        );
+
+        (layout_id, bounded)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        bounded: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let builder = self.builder.take()?;
+        let bounded = bounded.get();
+
+        let max_width = if bounded.width {
+            bounds.size.width
+        } else {
+            Pixels::MAX
+        };
+        let max_height = if bounded.height {
+            bounds.size.height
+        } else {
+            Pixels::MAX
+        };
+        let constraints = BoxConstraints::new(px(0.), max_width, px(0.), max_height);
+
+        let available_space = size(
+            if bounded.width {
+                AvailableSpace::Definite(bounds.size.width)
+            } else {
+                AvailableSpace::MaxContent
+            },
+            if bounded.height {
+                AvailableSpace::Definite(bounds.size.height)
+            } else {
+                AvailableSpace::MaxContent
+            },
+        );
+
+        let mut child = builder(constraints, window, cx);
+        child.prepaint_as_root(bounds.origin, available_space, window, cx);
+        Some(child)
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _bounded: &mut Self::RequestLayoutState,
+        child: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(child) = child {
+            child.paint(window, cx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LayoutBuilder;
+    use crate::layout::{BoxConstraints, SizedBox};
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{div, px, size, IntoElement, ParentElement, Styled, TestAppContext};
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[gpui::test]
+    fn test_layout_builder_receives_parent_size(cx: &mut TestAppContext) {
+        let received = Rc::new(Cell::new(BoxConstraints::default()));
+        let recorded = received.clone();
+
+        draw(cx, size(px(800.), px(600.)), move |_, _| {
+            SizedBox::new()
+                .size(320., 240.)
+                .child(LayoutBuilder::new(move |constraints, _, _| {
+                    recorded.set(constraints);
+                    div()
+                }))
+        });
+
+        assert_eq!(
+            received.get(),
+            BoxConstraints::loose(size(px(320.), px(240.)))
+        );
+    }
+
+    #[gpui::test]
+    fn test_layout_builder_switches_at_breakpoint(cx: &mut TestAppContext) {
+        let (narrow, narrow_bounds) = sized_probe(10., 10.);
+        let (wide, wide_bounds) = sized_probe(20., 20.);
+
+        draw(cx, size(px(800.), px(600.)), move |_, _| {
+            div()
+                .flex()
+                .size_full()
+                .child(div().w(px(400.)).h_full().child(LayoutBuilder::new(
+                    move |constraints, _, _| {
+                        if constraints.max_width < px(600.) {
+                            narrow.into_any_element()
+                        } else {
+                            wide.into_any_element()
+                        }
+                    },
+                )))
+        });
+
+        assert_eq!(narrow_bounds.get().size, size(px(10.), px(10.)));
+        assert_eq!(wide_bounds.get().size, size(px(0.), px(0.)));
+    }
+}
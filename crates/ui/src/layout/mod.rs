@@ -1,35 +1,54 @@
 // crates/ui/src/layout/mod.rs
 
 mod align;
+mod alignment;
+mod aspect_ratio;
 mod center;
+mod constraints;
+mod directionality;
+mod edge_insets;
+mod fitted_box;
 mod flexible;
+mod fractionally_sized_box;
+mod intrinsic;
+mod layout_builder;
+mod opacity;
 mod padding;
+mod positioned;
+mod safe_area;
 mod sized_box;
 mod spacer;
+mod table;
+mod visibility;
+mod wrap;
 
 pub use align::Align;
+pub use alignment::{Alignment, AlignmentDirectional, AlignmentGeometry, TextDirection};
+pub use aspect_ratio::AspectRatio;
 pub use center::Center;
+pub use constraints::BoxConstraints;
+pub use directionality::Directionality;
+pub use edge_insets::EdgeInsets;
+pub use fitted_box::FittedBox;
 pub use flexible::{Expanded, Flexible};
+pub use fractionally_sized_box::FractionallySizedBox;
+pub use intrinsic::{IntrinsicHeight, IntrinsicWidth};
+pub use layout_builder::LayoutBuilder;
+pub(crate) use opacity::Tween;
+pub use opacity::{AnimatedOpacity, Opacity};
 pub use padding::Padding;
+pub use positioned::Positioned;
+pub use safe_area::SafeArea;
 pub use sized_box::SizedBox;
 pub use spacer::Spacer;
+pub use table::{
+    BorderSide, FixedColumnWidth, FlexColumnWidth, FractionColumnWidth, IntrinsicColumnWidth,
+    MaxColumnWidth, MinColumnWidth, Table, TableBorder, TableColumnWidth, TableRow,
+};
+pub use visibility::{Offstage, Visibility};
+pub use wrap::Wrap;
 
-/// High-level alignment enum for positioning elements (Flutter-style)
-///
-/// This is a semantic wrapper over GPUI's flexbox alignment primitives,
-/// combining justify_content and align_items for common positioning patterns.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Alignment {
-    TopLeft,
-    TopCenter,
-    TopRight,
-    CenterLeft,
-    Center,
-    CenterRight,
-    BottomLeft,
-    BottomCenter,
-    BottomRight,
-}
+use gpui::{size, AlignContent, AlignItems, JustifyContent, Pixels, Size};
 
 /// Determines how a flex child should fit within its allocated space
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,3 +58,211 @@ pub enum FlexFit {
     /// Child must fill all flex space (uses flex_grow with flex_basis: 0)
     Tight,
 }
+
+/// How the children of a Row or Column are distributed along its main axis
+///
+/// `Start` and `End` follow the text direction in a Row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MainAxisAlignment {
+    #[default]
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+impl MainAxisAlignment {
+    pub(crate) fn justify_content(self) -> JustifyContent {
+        match self {
+            MainAxisAlignment::Start => JustifyContent::FlexStart,
+            MainAxisAlignment::End => JustifyContent::FlexEnd,
+            MainAxisAlignment::Center => JustifyContent::Center,
+            MainAxisAlignment::SpaceBetween => JustifyContent::SpaceBetween,
+            MainAxisAlignment::SpaceAround => JustifyContent::SpaceAround,
+            MainAxisAlignment::SpaceEvenly => JustifyContent::SpaceEvenly,
+        }
+    }
+}
+
+/// How the children of a Row or Column are placed on its cross axis
+///
+/// `Start` and `End` follow the text direction in a Column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrossAxisAlignment {
+    Start,
+    End,
+    #[default]
+    Center,
+    /// Make every child as large as the cross axis
+    Stretch,
+    /// Line up the first baselines of the children (Row only)
+    Baseline,
+}
+
+impl CrossAxisAlignment {
+    /// `mirrored` swaps the start and end edges
+    pub(crate) fn align_items(self, mirrored: bool) -> AlignItems {
+        match (self, mirrored) {
+            (CrossAxisAlignment::Start, false) | (CrossAxisAlignment::End, true) => {
+                AlignItems::FlexStart
+            }
+            (CrossAxisAlignment::End, false) | (CrossAxisAlignment::Start, true) => {
+                AlignItems::FlexEnd
+            }
+            (CrossAxisAlignment::Center, _) => AlignItems::Center,
+            (CrossAxisAlignment::Stretch, _) => AlignItems::Stretch,
+            (CrossAxisAlignment::Baseline, _) => AlignItems::Baseline,
+        }
+    }
+}
+
+/// How much space a Row or Column takes along its main axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MainAxisSize {
+    /// Only as much as the children need
+    Min,
+    /// All of the space the parent offers
+    #[default]
+    Max,
+}
+
+/// How children (or runs of children) are distributed along an axis of a Wrap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapAlignment {
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+impl WrapAlignment {
+    fn justify_content(self) -> JustifyContent {
+        match self {
+            WrapAlignment::Start => JustifyContent::FlexStart,
+            WrapAlignment::End => JustifyContent::FlexEnd,
+            WrapAlignment::Center => JustifyContent::Center,
+            WrapAlignment::SpaceBetween => JustifyContent::SpaceBetween,
+            WrapAlignment::SpaceAround => JustifyContent::SpaceAround,
+            WrapAlignment::SpaceEvenly => JustifyContent::SpaceEvenly,
+        }
+    }
+
+    fn align_content(self) -> AlignContent {
+        match self {
+            WrapAlignment::Start => AlignContent::FlexStart,
+            WrapAlignment::End => AlignContent::FlexEnd,
+            WrapAlignment::Center => AlignContent::Center,
+            WrapAlignment::SpaceBetween => AlignContent::SpaceBetween,
+            WrapAlignment::SpaceAround => AlignContent::SpaceAround,
+            WrapAlignment::SpaceEvenly => AlignContent::SpaceEvenly,
+        }
+    }
+}
+
+/// How children of different sizes are aligned on the cross axis of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WrapCrossAlignment {
+    Start,
+    End,
+    Center,
+}
+
+impl WrapCrossAlignment {
+    fn align_items(self) -> AlignItems {
+        match self {
+            WrapCrossAlignment::Start => AlignItems::FlexStart,
+            WrapCrossAlignment::End => AlignItems::FlexEnd,
+            WrapCrossAlignment::Center => AlignItems::Center,
+        }
+    }
+}
+
+/// How a child should be inscribed into the space allocated to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BoxFit {
+    /// Stretch the child to fill the box, distorting its aspect ratio
+    Fill,
+    /// As large as possible while staying entirely inside the box
+    #[default]
+    Contain,
+    /// As small as possible while still covering the entire box
+    Cover,
+    /// Match the box width, even if the child overflows vertically
+    FitWidth,
+    /// Match the box height, even if the child overflows horizontally
+    FitHeight,
+    /// Keep the child's own size
+    None,
+    /// Like `Contain`, but never scale the child up
+    ScaleDown,
+}
+
+impl BoxFit {
+    /// Compute the size a child of size `input` takes when fitted into `output`
+    pub fn apply(self, input: Size<Pixels>, output: Size<Pixels>) -> Size<Pixels> {
+        if input.width <= Pixels::ZERO || input.height <= Pixels::ZERO {
+            return size(Pixels::ZERO, Pixels::ZERO);
+        }
+
+        let scale_x = output.width / input.width;
+        let scale_y = output.height / input.height;
+
+        match self {
+            BoxFit::Fill => output,
+            BoxFit::Contain => Self::scale(input, scale_x.min(scale_y)),
+            BoxFit::Cover => Self::scale(input, scale_x.max(scale_y)),
+            BoxFit::FitWidth => Self::scale(input, scale_x),
+            BoxFit::FitHeight => Self::scale(input, scale_y),
+            BoxFit::None => input,
+            BoxFit::ScaleDown => Self::scale(input, scale_x.min(scale_y).min(1.0)),
+        }
+    }
+
+    fn scale(input: Size<Pixels>, factor: f32) -> Size<Pixels> {
+        size(input.width * factor, input.height * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::px;
+
+    #[test]
+    fn test_box_fit_apply() {
+        let input = size(px(200.), px(100.));
+        let output = size(px(100.), px(100.));
+
+        assert_eq!(BoxFit::Fill.apply(input, output), output);
+        assert_eq!(
+            BoxFit::Contain.apply(input, output),
+            size(px(100.), px(50.))
+        );
+        assert_eq!(BoxFit::Cover.apply(input, output), size(px(200.), px(100.)));
+        assert_eq!(
+            BoxFit::FitWidth.apply(input, output),
+            size(px(100.), px(50.))
+        );
+        assert_eq!(
+            BoxFit::FitHeight.apply(input, output),
+            size(px(200.), px(100.))
+        );
+        assert_eq!(BoxFit::None.apply(input, output), input);
+    }
+
+    #[test]
+    fn test_box_fit_scale_down_never_grows() {
+        let input = size(px(20.), px(10.));
+        let output = size(px(100.), px(100.));
+
+        assert_eq!(BoxFit::ScaleDown.apply(input, output), input);
+        assert_eq!(
+            BoxFit::Contain.apply(input, output),
+            size(px(100.), px(50.))
+        );
+    }
+}
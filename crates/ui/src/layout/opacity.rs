@@ -0,0 +1,340 @@
+// crates/ui/src/layout/opacity.rs
+
+use crate::accessibility::Accessible;
+use crate::media_query::MediaQuery;
+use crate::theme::ActiveTheme;
+use gpui::*;
+#[cfg(test)]
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// Flutter-style Opacity widget
+///
+/// Paints its child partially transparent. The child keeps its size and
+/// still receives pointer events, even when fully transparent; a fully
+/// transparent child is marked `aria_hidden` unless
+/// `always_include_semantics` is set.
+#[derive(IntoElement)]
+pub struct Opacity {
+    opacity: f32,
+    always_include_semantics: bool,
+    child: AnyElement,
+}
+
+impl Opacity {
+    /// `opacity` is clamped to `0.0..=1.0`
+    pub fn new(opacity: f32, child: impl IntoElement) -> Self {
+        Self {
+            opacity: opacity.clamp(0., 1.),
+            always_include_semantics: false,
+            child: child.into_any_element(),
+        }
+    }
+
+    /// Keep a fully transparent child visible to assistive technology
+    pub fn always_include_semantics(mut self, include: bool) -> Self {
+        self.always_include_semantics = include;
+        self
+    }
+
+    fn hides_semantics(&self) -> bool {
+        self.opacity == 0. && !self.always_include_semantics
+    }
+}
+
+impl RenderOnce for Opacity {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        div()
+            .opacity(self.opacity)
+            .aria_hidden(self.hides_semantics())
+            .child(self.child)
+    }
+}
+
+/// Animated version of [`Opacity`]
+///
+/// Whenever the target opacity changes between frames, the child fades
+/// from its current opacity to the new one over `duration`, the theme's
+/// normal motion duration by default, following `curve`. When the user
+/// prefers reduced motion the opacity changes at once. Like [`Opacity`], a
+/// child faded out completely is marked `aria_hidden`.
+///
+/// ```ignore
+/// AnimatedOpacity::new(if self.loaded { 1. } else { 0. }, content)
+///     .id("content-fade")
+///     .duration(Duration::from_millis(300))
+/// ```
+pub struct AnimatedOpacity {
+    id: ElementId,
+    opacity: f32,
+    duration: Option<Duration>,
+    curve: Rc<dyn Fn(f32) -> f32>,
+    always_include_semantics: bool,
+    child: Option<AnyElement>,
+    /// Receives the opacity of each frame
+    #[cfg(test)]
+    probe: Option<Rc<Cell<f32>>>,
+}
+
+impl AnimatedOpacity {
+    /// Without an explicit [`id`](Self::id) the animation is keyed by the
+    /// call site.
+    #[track_caller]
+    pub fn new(opacity: f32, child: impl IntoElement) -> Self {
+        Self {
+            id: ElementId::CodeLocation(*core::panic::Location::caller()),
+            opacity: opacity.clamp(0., 1.),
+            duration: None,
+            curve: Rc::new(ease_in_out),
+            always_include_semantics: false,
+            child: Some(child.into_any_element()),
+            #[cfg(test)]
+            probe: None,
+        }
+    }
+
+    /// Key under which the animation is tracked between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Easing applied to the animation's progress, such as [`linear`] or
+    /// the default [`ease_in_out`]
+    pub fn curve(mut self, curve: impl Fn(f32) -> f32 + 'static) -> Self {
+        self.curve = Rc::new(curve);
+        self
+    }
+
+    /// Keep a fully transparent child visible to assistive technology
+    pub fn always_include_semantics(mut self, include: bool) -> Self {
+        self.always_include_semantics = include;
+        self
+    }
+}
+
+/// A value animating from `from` to `to`, or settled at `to`
+///
+/// Kept as element state between frames; used for fades here and for other
+/// animated values such as a switch's thumb position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Tween {
+    from: f32,
+    to: f32,
+    start: Instant,
+}
+
+impl Tween {
+    pub(crate) fn settled(value: f32, now: Instant) -> Self {
+        Self {
+            from: value,
+            to: value,
+            start: now,
+        }
+    }
+
+    /// Progress at `now` from 0 to 1, before easing
+    pub(crate) fn progress(&self, now: Instant, duration: Duration) -> f32 {
+        if self.from == self.to || duration.is_zero() {
+            return 1.;
+        }
+        (now.saturating_duration_since(self.start).as_secs_f32() / duration.as_secs_f32())
+            .clamp(0., 1.)
+    }
+
+    /// Head toward `target`, starting from wherever the tween is at `now`
+    pub(crate) fn retarget(
+        &mut self,
+        target: f32,
+        now: Instant,
+        duration: Duration,
+        curve: &dyn Fn(f32) -> f32,
+    ) {
+        if target != self.to {
+            *self = Self {
+                from: self.sample(now, duration, curve),
+                to: target,
+                start: now,
+            };
+        }
+    }
+
+    pub(crate) fn sample(
+        &self,
+        now: Instant,
+        duration: Duration,
+        curve: &dyn Fn(f32) -> f32,
+    ) -> f32 {
+        let t = self.progress(now, duration);
+        if t >= 1. {
+            return self.to;
+        }
+        self.from + (self.to - self.from) * curve(t)
+    }
+}
+
+impl IntoElement for AnimatedOpacity {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for AnimatedOpacity {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let now = cx.background_executor().now();
+        let duration = if MediaQuery::of(window, cx).reduce_motion {
+            Duration::ZERO
+        } else {
+            self.duration.unwrap_or(cx.theme().motion.normal)
+        };
+        let (opacity, animating) =
+            window.with_element_state(id.unwrap(), |fade: Option<Tween>, _| {
+                let mut fade = fade.unwrap_or_else(|| Tween::settled(self.opacity, now));
+                fade.retarget(self.opacity, now, duration, &*self.curve);
+                let opacity = fade.sample(now, duration, &*self.curve);
+                ((opacity, fade.progress(now, duration) < 1.), fade)
+            });
+        if animating {
+            window.request_animation_frame();
+        }
+        #[cfg(test)]
+        if let Some(probe) = &self.probe {
+            probe.set(opacity);
+        }
+
+        let child = self
+            .child
+            .take()
+            .unwrap_or_else(|| Empty.into_any_element());
+        let mut element = Opacity::new(opacity, child)
+            .always_include_semantics(self.always_include_semantics)
+            .into_any_element();
+        (element.request_layout(window, cx), element)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        element.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        element: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        element.paint(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnimatedOpacity, Opacity, Tween};
+    use crate::media_query::MediaQuery;
+    use crate::test_util::{draw_view, redraw};
+    use crate::theme::ActiveTheme;
+    use gpui::{div, ease_in_out, linear, px, size, Styled, TestAppContext};
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_opacity_hides_semantics_when_transparent() {
+        assert!(!Opacity::new(0.5, div()).hides_semantics());
+        assert!(Opacity::new(-1., div()).hides_semantics());
+        assert!(!Opacity::new(0., div())
+            .always_include_semantics(true)
+            .hides_semantics());
+    }
+
+    #[test]
+    fn test_fade_retargets_from_current_value() {
+        let duration = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut fade = Tween::settled(1., start);
+        assert_eq!(fade.sample(start, duration, &linear), 1.);
+
+        fade.retarget(0., start, duration, &linear);
+        assert_eq!(fade.sample(start, duration, &linear), 1.);
+        let halfway = start + Duration::from_millis(50);
+        assert_eq!(fade.sample(halfway, duration, &linear), 0.5);
+
+        // Reversing halfway fades back in from one half, not from zero
+        fade.retarget(1., halfway, duration, &linear);
+        assert_eq!(fade.sample(halfway, duration, &linear), 0.5);
+        let later = halfway + Duration::from_millis(50);
+        assert_eq!(fade.sample(later, duration, &linear), 0.75);
+        assert_eq!(fade.sample(halfway + duration, duration, &ease_in_out), 1.);
+    }
+
+    #[gpui::test]
+    fn test_animated_opacity_fades_over_normal_motion(cx: &mut TestAppContext) {
+        let visible = Rc::new(Cell::new(true));
+        let painted = Rc::new(Cell::new(-1.));
+
+        let (target, probe) = (visible.clone(), painted.clone());
+        let cx = draw_view(cx, size(px(100.), px(100.)), move |_, _| {
+            let opacity = if target.get() { 1. } else { 0. };
+            let mut fade = AnimatedOpacity::new(opacity, div().size(px(10.))).id("fade");
+            fade.probe = Some(probe.clone());
+            fade
+        });
+        let normal = cx.update(|_, cx| cx.theme().motion.normal);
+        assert_eq!(painted.get(), 1.);
+
+        visible.set(false);
+        redraw(cx);
+        assert_eq!(painted.get(), 1.);
+
+        cx.executor().advance_clock(normal / 2);
+        redraw(cx);
+        let halfway = painted.get();
+        assert!(halfway > 0. && halfway < 1., "opacity {halfway}");
+
+        cx.executor().advance_clock(normal);
+        redraw(cx);
+        assert_eq!(painted.get(), 0.);
+
+        // With reduced motion the child reappears at once
+        cx.update(|_, cx| MediaQuery::set_reduce_motion(cx, true));
+        visible.set(true);
+        redraw(cx);
+        assert_eq!(painted.get(), 1.);
+    }
+}
@@ -1,12 +1,19 @@
 // crates/ui/src/layout/padding.rs
 
+use super::{EdgeInsets, TextDirection};
 use gpui::*;
 
 /// Flutter-style Padding widget
+///
+/// `start` and `end` pad the leading and trailing edges for the current
+/// [`TextDirection`]: left and right in LTR, mirrored in RTL. They take
+/// precedence over `left`/`right`.
 #[derive(IntoElement)]
 pub struct Padding {
     child: AnyElement,
     padding: Edges<DefiniteLength>,
+    start: Option<DefiniteLength>,
+    end: Option<DefiniteLength>,
 }
 
 impl Padding {
@@ -14,6 +21,8 @@ impl Padding {
         Self {
             child: child.into_any_element(),
             padding: Edges::default(),
+            start: None,
+            end: None,
         }
     }
 
@@ -28,6 +37,18 @@ impl Padding {
         self
     }
 
+    /// Set padding from [`EdgeInsets`] or a single value such as a theme spacing token
+    pub fn insets(mut self, insets: impl Into<EdgeInsets>) -> Self {
+        let insets = insets.into();
+        self.padding = Edges {
+            top: insets.top.into(),
+            right: insets.right.into(),
+            bottom: insets.bottom.into(),
+            left: insets.left.into(),
+        };
+        self
+    }
+
     /// Set horizontal padding (left and right)
     pub fn horizontal(mut self, value: f32) -> Self {
         self.padding.left = px(value).into();
@@ -59,6 +80,18 @@ impl Padding {
         self
     }
 
+    /// Set padding for the leading edge: left in LTR, right in RTL
+    pub fn start(mut self, value: f32) -> Self {
+        self.start = Some(px(value).into());
+        self
+    }
+
+    /// Set padding for the trailing edge: right in LTR, left in RTL
+    pub fn end(mut self, value: f32) -> Self {
+        self.end = Some(px(value).into());
+        self
+    }
+
     /// Set padding for top edge
     pub fn top(mut self, value: f32) -> Self {
         self.padding.top = px(value).into();
@@ -73,12 +106,56 @@ impl Padding {
 }
 
 impl RenderOnce for Padding {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (left, right) = match TextDirection::of(cx) {
+            TextDirection::Ltr => (
+                self.start.unwrap_or(self.padding.left),
+                self.end.unwrap_or(self.padding.right),
+            ),
+            TextDirection::Rtl => (
+                self.end.unwrap_or(self.padding.left),
+                self.start.unwrap_or(self.padding.right),
+            ),
+        };
+
         div()
             .pt(self.padding.top)
-            .pr(self.padding.right)
+            .pr(right)
             .pb(self.padding.bottom)
-            .pl(self.padding.left)
+            .pl(left)
             .child(self.child)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Padding;
+    use crate::layout::Directionality;
+    use crate::test_util::{draw, probe};
+    use gpui::{div, point, px, size, Bounds, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_padding_start_end_mirror(cx: &mut TestAppContext) {
+        let (ltr, ltr_bounds) = probe();
+        let (rtl, rtl_bounds) = probe();
+
+        draw(cx, size(px(100.), px(100.)), move |_, _| {
+            let padded = |child: gpui::Canvas<()>| {
+                Padding::new(child.h(px(10.))).start(20.).end(5.).left(50.)
+            };
+            div()
+                .child(padded(ltr))
+                .child(Directionality::rtl(padded(rtl)))
+        });
+
+        // Start and end win over left in both directions
+        assert_eq!(
+            ltr_bounds.get(),
+            Bounds::new(point(px(20.), px(0.)), size(px(75.), px(10.)))
+        );
+        assert_eq!(
+            rtl_bounds.get(),
+            Bounds::new(point(px(5.), px(10.)), size(px(75.), px(10.)))
+        );
+    }
+}
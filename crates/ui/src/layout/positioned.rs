@@ -0,0 +1,153 @@
+// crates/ui/src/layout/positioned.rs
+
+use super::TextDirection;
+use gpui::*;
+
+/// Flutter-style Positioned widget
+///
+/// Places its child at fixed offsets from the edges of its parent, out of
+/// the normal flow. `start` and `end` are
+/// offsets from the leading and trailing edges for the current
+/// [`TextDirection`], and take precedence over `left`/`right`.
+///
+/// ```ignore
+/// div()
+///     .relative()
+///     .child(content)
+///     .child(Positioned::new(badge).top(4.).end(4.))
+/// ```
+#[derive(IntoElement)]
+pub struct Positioned {
+    child: AnyElement,
+    left: Option<Pixels>,
+    top: Option<Pixels>,
+    right: Option<Pixels>,
+    bottom: Option<Pixels>,
+    start: Option<Pixels>,
+    end: Option<Pixels>,
+    width: Option<Pixels>,
+    height: Option<Pixels>,
+}
+
+impl Positioned {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            left: None,
+            top: None,
+            right: None,
+            bottom: None,
+            start: None,
+            end: None,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Stretch the child over the whole of its parent
+    pub fn fill(child: impl IntoElement) -> Self {
+        Self::new(child).left(0.).top(0.).right(0.).bottom(0.)
+    }
+
+    pub fn left(mut self, offset: f32) -> Self {
+        self.left = Some(px(offset));
+        self
+    }
+
+    pub fn top(mut self, offset: f32) -> Self {
+        self.top = Some(px(offset));
+        self
+    }
+
+    pub fn right(mut self, offset: f32) -> Self {
+        self.right = Some(px(offset));
+        self
+    }
+
+    pub fn bottom(mut self, offset: f32) -> Self {
+        self.bottom = Some(px(offset));
+        self
+    }
+
+    /// Offset from the leading edge: left in LTR, right in RTL
+    pub fn start(mut self, offset: f32) -> Self {
+        self.start = Some(px(offset));
+        self
+    }
+
+    /// Offset from the trailing edge: right in LTR, left in RTL
+    pub fn end(mut self, offset: f32) -> Self {
+        self.end = Some(px(offset));
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(px(width));
+        self
+    }
+
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(px(height));
+        self
+    }
+}
+
+impl RenderOnce for Positioned {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (left, right) = match TextDirection::of(cx) {
+            TextDirection::Ltr => (self.start.or(self.left), self.end.or(self.right)),
+            TextDirection::Rtl => (self.end.or(self.left), self.start.or(self.right)),
+        };
+
+        let mut element = div().absolute();
+        let style = element.style();
+        style.inset.left = left.map(Into::into);
+        style.inset.top = self.top.map(Into::into);
+        style.inset.right = right.map(Into::into);
+        style.inset.bottom = self.bottom.map(Into::into);
+        style.size.width = self.width.map(Into::into);
+        style.size.height = self.height.map(Into::into);
+        element.child(self.child)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Positioned;
+    use crate::layout::Directionality;
+    use crate::test_util::{draw, probe, sized_probe};
+    use gpui::{div, point, px, size, Bounds, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_positioned_mirrors_start_and_end(cx: &mut TestAppContext) {
+        let (ltr, ltr_bounds) = sized_probe(10., 10.);
+        let (rtl, rtl_bounds) = sized_probe(10., 10.);
+        let (stretched, stretched_bounds) = probe();
+
+        draw(cx, size(px(100.), px(100.)), move |_, _| {
+            div()
+                .relative()
+                .size(px(100.))
+                .child(Positioned::new(ltr).top(5.).start(20.))
+                .child(Directionality::rtl(
+                    div()
+                        .size_full()
+                        .child(Positioned::new(rtl).top(5.).start(20.))
+                        .child(
+                            Positioned::new(stretched)
+                                .bottom(0.)
+                                .start(30.)
+                                .end(10.)
+                                .height(4.),
+                        ),
+                ))
+        });
+
+        assert_eq!(ltr_bounds.get().origin, point(px(20.), px(5.)));
+        assert_eq!(rtl_bounds.get().origin, point(px(70.), px(5.)));
+        assert_eq!(
+            stretched_bounds.get(),
+            Bounds::new(point(px(10.), px(96.)), size(px(60.), px(4.)))
+        );
+    }
+}
@@ -0,0 +1,226 @@
+// crates/ui/src/layout/safe_area.rs
+
+use super::EdgeInsets;
+use gpui::*;
+use std::rc::Rc;
+
+/// Height of the macOS titlebar band holding the traffic lights
+const TITLEBAR_HEIGHT: Pixels = px(28.);
+
+/// Height of a macOS traffic light button
+const TRAFFIC_LIGHT_SIZE: Pixels = px(14.);
+
+/// Flutter-style SafeArea widget
+///
+/// Pads its child by the window's safe area insets so content stays clear
+/// of window controls: the traffic lights and titlebar band of a window
+/// with a transparent titlebar, or the resize borders of client-side
+/// decorations. Each edge can be opted out of with `left`, `top`, `right`
+/// and `bottom`, and `minimum` pads an edge even where the inset is
+/// smaller.
+///
+/// The insets come from [`SafeArea::window_insets`] unless the app installs
+/// its own provider, such as one built from the window's titlebar options:
+///
+/// ```ignore
+/// let titlebar = TitlebarOptions { appears_transparent: true, ..Default::default() };
+/// SafeArea::set_inset_provider(cx, SafeArea::titlebar(&titlebar));
+///
+/// SafeArea::new(content).bottom(false)
+/// ```
+#[derive(IntoElement)]
+pub struct SafeArea {
+    child: AnyElement,
+    left: bool,
+    top: bool,
+    right: bool,
+    bottom: bool,
+    minimum: EdgeInsets,
+}
+
+/// The app's source of safe area insets, if it replaced the default
+struct InsetProvider(Rc<dyn Fn(&Window) -> EdgeInsets>);
+
+impl Global for InsetProvider {}
+
+impl SafeArea {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            left: true,
+            top: true,
+            right: true,
+            bottom: true,
+            minimum: EdgeInsets::ZERO,
+        }
+    }
+
+    /// Whether to pad the left edge
+    pub fn left(mut self, enabled: bool) -> Self {
+        self.left = enabled;
+        self
+    }
+
+    /// Whether to pad the top edge
+    pub fn top(mut self, enabled: bool) -> Self {
+        self.top = enabled;
+        self
+    }
+
+    /// Whether to pad the right edge
+    pub fn right(mut self, enabled: bool) -> Self {
+        self.right = enabled;
+        self
+    }
+
+    /// Whether to pad the bottom edge
+    pub fn bottom(mut self, enabled: bool) -> Self {
+        self.bottom = enabled;
+        self
+    }
+
+    /// Padding applied to each edge even where the window inset is smaller
+    pub fn minimum(mut self, minimum: impl Into<EdgeInsets>) -> Self {
+        self.minimum = minimum.into();
+        self
+    }
+
+    /// Replace where every SafeArea reads the window insets from
+    pub fn set_inset_provider(cx: &mut App, provider: impl Fn(&Window) -> EdgeInsets + 'static) {
+        cx.set_global(InsetProvider(Rc::new(provider)));
+    }
+
+    /// Safe area insets of a window, from the app's provider if it set one
+    pub fn insets_of(window: &Window, cx: &App) -> EdgeInsets {
+        match cx.try_global::<InsetProvider>() {
+            Some(provider) => (provider.0)(window),
+            None => Self::window_insets(window),
+        }
+    }
+
+    /// Insets GPUI reports for a window's decorations
+    ///
+    /// With client-side decorations, the invisible resize border set with
+    /// `Window::set_client_inset` on every edge not tiled against the
+    /// screen; nothing for server-side decorations or in fullscreen.
+    pub fn window_insets(window: &Window) -> EdgeInsets {
+        if window.is_fullscreen() {
+            return EdgeInsets::ZERO;
+        }
+        match (window.window_decorations(), window.client_inset()) {
+            (Decorations::Client { tiling }, Some(inset)) => {
+                let edge = |tiled: bool| if tiled { Pixels::ZERO } else { inset };
+                EdgeInsets {
+                    top: edge(tiling.top),
+                    right: edge(tiling.right),
+                    bottom: edge(tiling.bottom),
+                    left: edge(tiling.left),
+                }
+            }
+            _ => EdgeInsets::ZERO,
+        }
+    }
+
+    /// Inset provider for windows created with the given titlebar options
+    ///
+    /// GPUI does not report the titlebar a window was created with, so the
+    /// app passes the same options it opened the window with. A transparent
+    /// titlebar adds a band at the top tall enough to center the traffic
+    /// lights at their position, except in fullscreen where they are hidden.
+    pub fn titlebar(titlebar: &TitlebarOptions) -> impl Fn(&Window) -> EdgeInsets + 'static {
+        let band = titlebar.appears_transparent.then(|| {
+            titlebar
+                .traffic_light_position
+                .map_or(TITLEBAR_HEIGHT, |position| {
+                    position.y * 2. + TRAFFIC_LIGHT_SIZE
+                })
+        });
+
+        move |window| {
+            let insets = Self::window_insets(window);
+            match band {
+                Some(band) if !window.is_fullscreen() => insets.top(insets.top.max(band)),
+                _ => insets,
+            }
+        }
+    }
+}
+
+impl RenderOnce for SafeArea {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let insets = Self::insets_of(window, cx);
+        let edge = |enabled: bool, inset: Pixels, minimum: Pixels| {
+            if enabled {
+                inset.max(minimum)
+            } else {
+                minimum
+            }
+        };
+
+        div()
+            .pt(edge(self.top, insets.top, self.minimum.top))
+            .pr(edge(self.right, insets.right, self.minimum.right))
+            .pb(edge(self.bottom, insets.bottom, self.minimum.bottom))
+            .pl(edge(self.left, insets.left, self.minimum.left))
+            .child(self.child)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SafeArea;
+    use crate::layout::EdgeInsets;
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{div, point, px, size, ParentElement, Styled, TestAppContext, TitlebarOptions};
+
+    #[gpui::test]
+    fn test_safe_area_pads_enabled_edges(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            SafeArea::set_inset_provider(cx, |_| EdgeInsets::from_ltrb(10., 30., 0., 20.))
+        });
+        let (padded, padded_bounds) = sized_probe(10., 10.);
+        let (opted_out, opted_out_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(100.), px(200.)), move |_, _| {
+            div().w(px(100.)).child(SafeArea::new(padded)).child(
+                SafeArea::new(opted_out)
+                    .top(false)
+                    .left(false)
+                    .minimum(EdgeInsets::all(px(5.))),
+            )
+        });
+
+        assert_eq!(padded_bounds.get().origin, point(px(10.), px(30.)));
+        // Opted-out edges keep only the minimum; below the first area's 20px
+        // bottom inset, 5px on top
+        assert_eq!(opted_out_bounds.get().origin, point(px(5.), px(65.)));
+    }
+
+    #[gpui::test]
+    fn test_titlebar_insets(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        let transparent = TitlebarOptions {
+            appears_transparent: true,
+            ..Default::default()
+        };
+        let moved_lights = TitlebarOptions {
+            appears_transparent: true,
+            traffic_light_position: Some(point(px(12.), px(16.))),
+            ..Default::default()
+        };
+
+        let (default, transparent, moved_lights, opaque) = cx.update(|window, _| {
+            (
+                SafeArea::window_insets(window),
+                SafeArea::titlebar(&transparent)(window),
+                SafeArea::titlebar(&moved_lights)(window),
+                SafeArea::titlebar(&TitlebarOptions::default())(window),
+            )
+        });
+
+        assert_eq!(default, EdgeInsets::ZERO);
+        assert_eq!(transparent, EdgeInsets::ZERO.top(px(28.)));
+        assert_eq!(moved_lights, EdgeInsets::ZERO.top(px(46.)));
+        assert_eq!(opaque, EdgeInsets::ZERO);
+    }
+}
@@ -0,0 +1,877 @@
+// crates/ui/src/layout/table.rs
+
+use super::intrinsic::measure_detached;
+use crate::theme::Theme;
+use gpui::*;
+
+/// How wide a [`Table`] column is
+///
+/// Built from [`FixedColumnWidth`], [`FlexColumnWidth`], [`FractionColumnWidth`],
+/// [`IntrinsicColumnWidth`] and the [`MinColumnWidth`]/[`MaxColumnWidth`]
+/// combinators, each of which converts into this.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableColumnWidth(ColumnWidthKind);
+
+#[derive(Debug, Clone, PartialEq)]
+enum ColumnWidthKind {
+    Fixed(Pixels),
+    Flex(f32),
+    Fraction(f32),
+    Intrinsic { flex: Option<f32> },
+    Min(Box<TableColumnWidth>, Box<TableColumnWidth>),
+    Max(Box<TableColumnWidth>, Box<TableColumnWidth>),
+}
+
+/// A column of exactly this many pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedColumnWidth(pub f32);
+
+/// A column sharing the width left over by the other columns, in
+/// proportion to its flex factor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlexColumnWidth(pub f32);
+
+impl Default for FlexColumnWidth {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// A column taking this fraction of the table's width
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FractionColumnWidth(pub f32);
+
+/// A column as wide as its widest cell
+///
+/// Every cell in the column is measured, so prefer other widths for
+/// tables with many rows. With a flex factor the column also takes a share
+/// of the leftover width, and may shrink down to its cells' minimum widths.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct IntrinsicColumnWidth {
+    flex: Option<f32>,
+}
+
+impl IntrinsicColumnWidth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn flex(mut self, flex: f32) -> Self {
+        self.flex = Some(flex);
+        self
+    }
+}
+
+/// The narrower of two column widths
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinColumnWidth(TableColumnWidth, TableColumnWidth);
+
+impl MinColumnWidth {
+    pub fn new(a: impl Into<TableColumnWidth>, b: impl Into<TableColumnWidth>) -> Self {
+        Self(a.into(), b.into())
+    }
+}
+
+/// The wider of two column widths
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxColumnWidth(TableColumnWidth, TableColumnWidth);
+
+impl MaxColumnWidth {
+    pub fn new(a: impl Into<TableColumnWidth>, b: impl Into<TableColumnWidth>) -> Self {
+        Self(a.into(), b.into())
+    }
+}
+
+impl From<FixedColumnWidth> for TableColumnWidth {
+    fn from(width: FixedColumnWidth) -> Self {
+        Self(ColumnWidthKind::Fixed(px(width.0)))
+    }
+}
+
+impl From<FlexColumnWidth> for TableColumnWidth {
+    fn from(width: FlexColumnWidth) -> Self {
+        Self(ColumnWidthKind::Flex(width.0.max(0.)))
+    }
+}
+
+impl From<FractionColumnWidth> for TableColumnWidth {
+    fn from(width: FractionColumnWidth) -> Self {
+        Self(ColumnWidthKind::Fraction(width.0))
+    }
+}
+
+impl From<IntrinsicColumnWidth> for TableColumnWidth {
+    fn from(width: IntrinsicColumnWidth) -> Self {
+        Self(ColumnWidthKind::Intrinsic { flex: width.flex })
+    }
+}
+
+impl From<MinColumnWidth> for TableColumnWidth {
+    fn from(width: MinColumnWidth) -> Self {
+        Self(ColumnWidthKind::Min(Box::new(width.0), Box::new(width.1)))
+    }
+}
+
+impl From<MaxColumnWidth> for TableColumnWidth {
+    fn from(width: MaxColumnWidth) -> Self {
+        Self(ColumnWidthKind::Max(Box::new(width.0), Box::new(width.1)))
+    }
+}
+
+/// A length that is either absolute or relative to the table's width
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Extent {
+    Px(Pixels),
+    Fraction(f32),
+}
+
+impl Extent {
+    fn length(self) -> Length {
+        match self {
+            Extent::Px(value) => value.into(),
+            Extent::Fraction(fraction) => relative(fraction).into(),
+        }
+    }
+
+    /// The smaller or larger of two extents, when they can be compared
+    /// without knowing the table's width
+    fn pick(self, other: Extent, smaller: bool) -> Option<Extent> {
+        match (self, other) {
+            (Extent::Px(a), Extent::Px(b)) => {
+                Some(Extent::Px(if smaller { a.min(b) } else { a.max(b) }))
+            }
+            (Extent::Fraction(a), Extent::Fraction(b)) => {
+                Some(Extent::Fraction(if smaller { a.min(b) } else { a.max(b) }))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A column width resolved into what the flex layout of a row can express
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ColumnSizing {
+    /// The width, or the flex basis of a flexible column
+    width: Extent,
+    flex: Option<f32>,
+    min: Option<Extent>,
+    max: Option<Extent>,
+}
+
+impl ColumnSizing {
+    fn fixed(width: Extent) -> Self {
+        Self {
+            width,
+            flex: None,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Limit this sizing from above (`smaller`) or below by `limit`
+    fn bound(mut self, limit: Extent, smaller: bool) -> Self {
+        let slot = if smaller {
+            &mut self.max
+        } else {
+            &mut self.min
+        };
+        *slot = Some(
+            slot.and_then(|existing| existing.pick(limit, smaller))
+                .unwrap_or(limit),
+        );
+        self
+    }
+
+    fn combine(a: Self, b: Self, smaller: bool) -> Self {
+        match (a.flex, b.flex) {
+            (None, None) => match a.width.pick(b.width, smaller) {
+                Some(width) => Self { width, ..a },
+                None => a.bound(b.width, smaller),
+            },
+            (Some(_), None) => a.bound(b.width, smaller),
+            (None, Some(_)) => b.bound(a.width, smaller),
+            (Some(flex_a), Some(flex_b)) => Self {
+                width: a.width.pick(b.width, smaller).unwrap_or(a.width),
+                flex: Some(if smaller {
+                    flex_a.min(flex_b)
+                } else {
+                    flex_a.max(flex_b)
+                }),
+                ..a
+            },
+        }
+    }
+
+    fn style(self) -> Style {
+        let mut style = Style {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        };
+        match self.flex {
+            Some(flex) => {
+                style.flex_grow = flex;
+                style.flex_shrink = 1.;
+                style.flex_basis = self.width.length();
+            }
+            None => {
+                style.flex_grow = 0.;
+                style.flex_shrink = 0.;
+                style.size.width = self.width.length();
+            }
+        }
+        // An explicit minimum keeps cell content from widening the column
+        // in one row but not the others
+        style.min_size.width = self.min.map_or(px(0.).into(), Extent::length);
+        if let Some(max) = self.max {
+            style.max_size.width = max.length();
+        }
+        style
+    }
+}
+
+impl TableColumnWidth {
+    fn needs_intrinsic_width(&self) -> bool {
+        match &self.0 {
+            ColumnWidthKind::Intrinsic { .. } => true,
+            ColumnWidthKind::Min(a, b) | ColumnWidthKind::Max(a, b) => {
+                a.needs_intrinsic_width() || b.needs_intrinsic_width()
+            }
+            _ => false,
+        }
+    }
+
+    fn is_flexible(&self) -> bool {
+        match &self.0 {
+            ColumnWidthKind::Flex(_) => true,
+            ColumnWidthKind::Intrinsic { flex } => flex.is_some(),
+            ColumnWidthKind::Min(a, b) | ColumnWidthKind::Max(a, b) => {
+                a.is_flexible() || b.is_flexible()
+            }
+            _ => false,
+        }
+    }
+
+    /// Resolve against the minimum and maximum content widths of the column's cells
+    fn resolve(&self, intrinsic: (Pixels, Pixels)) -> ColumnSizing {
+        match &self.0 {
+            ColumnWidthKind::Fixed(width) => ColumnSizing::fixed(Extent::Px(*width)),
+            ColumnWidthKind::Fraction(fraction) => ColumnSizing::fixed(Extent::Fraction(*fraction)),
+            ColumnWidthKind::Flex(flex) => ColumnSizing {
+                flex: Some(*flex),
+                ..ColumnSizing::fixed(Extent::Px(Pixels::ZERO))
+            },
+            ColumnWidthKind::Intrinsic { flex } => ColumnSizing {
+                width: Extent::Px(intrinsic.1),
+                flex: *flex,
+                min: Some(Extent::Px(intrinsic.0)),
+                max: None,
+            },
+            ColumnWidthKind::Min(a, b) => {
+                ColumnSizing::combine(a.resolve(intrinsic), b.resolve(intrinsic), true)
+            }
+            ColumnWidthKind::Max(a, b) => {
+                ColumnSizing::combine(a.resolve(intrinsic), b.resolve(intrinsic), false)
+            }
+        }
+    }
+}
+
+/// One line of a [`TableBorder`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BorderSide {
+    pub color: Hsla,
+    pub width: Pixels,
+}
+
+impl BorderSide {
+    pub fn new(color: impl Into<Hsla>, width: impl Into<Pixels>) -> Self {
+        Self {
+            color: color.into(),
+            width: width.into(),
+        }
+    }
+}
+
+/// Lines drawn around and between the cells of a [`Table`]
+///
+/// Borders are painted over the cells and take no space in the layout.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TableBorder {
+    pub top: Option<BorderSide>,
+    pub right: Option<BorderSide>,
+    pub bottom: Option<BorderSide>,
+    pub left: Option<BorderSide>,
+    /// Lines between rows
+    pub horizontal_inside: Option<BorderSide>,
+    /// Lines between columns
+    pub vertical_inside: Option<BorderSide>,
+    /// Corner radius of the outside border
+    pub radius: Pixels,
+}
+
+impl TableBorder {
+    /// The same line around and between every cell
+    pub fn all(side: BorderSide) -> Self {
+        Self::symmetric(Some(side), Some(side))
+    }
+
+    /// One line between the cells and another around the table
+    pub fn symmetric(inside: Option<BorderSide>, outside: Option<BorderSide>) -> Self {
+        Self {
+            top: outside,
+            right: outside,
+            bottom: outside,
+            left: outside,
+            horizontal_inside: inside,
+            vertical_inside: inside,
+            radius: Pixels::ZERO,
+        }
+    }
+
+    /// Hairlines in the theme's border color, rounded like other bordered surfaces
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self::all(BorderSide::new(theme.colors.border, px(1.))).radius(theme.radius.md)
+    }
+
+    pub fn radius(mut self, radius: impl Into<Pixels>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    fn paint(
+        &self,
+        bounds: Bounds<Pixels>,
+        row_edges: &[Pixels],
+        column_edges: &[Pixels],
+        window: &mut Window,
+    ) {
+        if let Some(side) = self.horizontal_inside {
+            for y in row_edges {
+                window.paint_quad(fill(
+                    Bounds::new(
+                        point(bounds.left(), *y - side.width / 2.),
+                        size(bounds.size.width, side.width),
+                    ),
+                    side.color,
+                ));
+            }
+        }
+        if let Some(side) = self.vertical_inside {
+            for x in column_edges {
+                window.paint_quad(fill(
+                    Bounds::new(
+                        point(*x - side.width / 2., bounds.top()),
+                        size(side.width, bounds.size.height),
+                    ),
+                    side.color,
+                ));
+            }
+        }
+
+        let sides = [self.top, self.right, self.bottom, self.left];
+        let Some(color) = sides.iter().flatten().map(|side| side.color).next() else {
+            return;
+        };
+        let width = |side: Option<BorderSide>| side.map_or(Pixels::ZERO, |side| side.width);
+        if sides.iter().flatten().all(|side| side.color == color) {
+            window.paint_quad(quad(
+                bounds,
+                self.radius,
+                transparent_black(),
+                Edges {
+                    top: width(self.top),
+                    right: width(self.right),
+                    bottom: width(self.bottom),
+                    left: width(self.left),
+                },
+                color,
+                BorderStyle::Solid,
+            ));
+        } else {
+            // Differently colored sides are painted one at a time, square
+            let (origin, extent) = (bounds.origin, bounds.size);
+            let strips = [
+                (
+                    self.top,
+                    Bounds::new(origin, size(extent.width, width(self.top))),
+                ),
+                (
+                    self.right,
+                    Bounds::new(
+                        point(bounds.right() - width(self.right), origin.y),
+                        size(width(self.right), extent.height),
+                    ),
+                ),
+                (
+                    self.bottom,
+                    Bounds::new(
+                        point(origin.x, bounds.bottom() - width(self.bottom)),
+                        size(extent.width, width(self.bottom)),
+                    ),
+                ),
+                (
+                    self.left,
+                    Bounds::new(origin, size(width(self.left), extent.height)),
+                ),
+            ];
+            for (side, strip) in strips {
+                if let Some(side) = side {
+                    window.paint_quad(fill(strip, side.color));
+                }
+            }
+        }
+    }
+}
+
+/// A row of cells in a [`Table`]
+///
+/// Style methods such as `bg` and `border_b_1` decorate the row; the
+/// decoration is painted behind its cells and does not affect layout.
+pub struct TableRow {
+    cells: Vec<AnyElement>,
+    decoration: StyleRefinement,
+}
+
+impl TableRow {
+    pub fn new() -> Self {
+        Self {
+            cells: Vec::new(),
+            decoration: StyleRefinement::default(),
+        }
+    }
+}
+
+impl Default for TableRow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentElement for TableRow {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.cells.extend(elements);
+    }
+}
+
+impl Styled for TableRow {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.decoration
+    }
+}
+
+/// Flutter-style Table: rows of cells lined up in columns
+///
+/// Every row is as tall as its tallest cell and every column follows its
+/// [`TableColumnWidth`], `FlexColumnWidth(1.)` unless set otherwise. A table
+/// with a flexible column fills the available width; otherwise it is as
+/// wide as its columns. Rows with fewer cells than the widest row are
+/// padded with empty cells.
+///
+/// ```ignore
+/// Table::new()
+///     .column_width(0, IntrinsicColumnWidth::new())
+///     .column_width(1, MinColumnWidth::new(FlexColumnWidth(1.), FixedColumnWidth(320.)))
+///     .border(TableBorder::from_theme(&cx.theme()))
+///     .row(TableRow::new().child("Weight").child("1.2 kg"))
+///     .row(TableRow::new().bg(cx.theme().colors.muted).child("Battery").child("10 h"))
+/// ```
+#[derive(Default)]
+pub struct Table {
+    rows: Vec<TableRow>,
+    column_widths: Vec<Option<TableColumnWidth>>,
+    default_column_width: Option<TableColumnWidth>,
+    border: Option<TableBorder>,
+    /// Rows taken over during layout and their bounds, kept until paint
+    layout: TableLayout,
+    geometry: TablePaint,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn row(mut self, row: TableRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    pub fn rows(mut self, rows: impl IntoIterator<Item = TableRow>) -> Self {
+        self.rows.extend(rows);
+        self
+    }
+
+    /// Width of the column at `index`
+    pub fn column_width(mut self, index: usize, width: impl Into<TableColumnWidth>) -> Self {
+        if self.column_widths.len() <= index {
+            self.column_widths.resize(index + 1, None);
+        }
+        self.column_widths[index] = Some(width.into());
+        self
+    }
+
+    /// Width of every column without its own `column_width`
+    pub fn default_column_width(mut self, width: impl Into<TableColumnWidth>) -> Self {
+        self.default_column_width = Some(width.into());
+        self
+    }
+
+    pub fn border(mut self, border: TableBorder) -> Self {
+        self.border = Some(border);
+        self
+    }
+
+    fn width_of(&self, column: usize) -> TableColumnWidth {
+        self.column_widths
+            .get(column)
+            .cloned()
+            .flatten()
+            .or_else(|| self.default_column_width.clone())
+            .unwrap_or_else(|| FlexColumnWidth::default().into())
+    }
+}
+
+/// Minimum and maximum content width of a cell, measured on its own
+fn measure_intrinsic_width(cell: LayoutId, window: &mut Window, cx: &mut App) -> (Pixels, Pixels) {
+    let mut width = |available: AvailableSpace| {
+        measure_detached(cell, size(available, available), window, cx).width
+    };
+    (
+        width(AvailableSpace::MinContent),
+        width(AvailableSpace::MaxContent),
+    )
+}
+
+#[derive(Default)]
+struct TableLayout {
+    rows: Vec<RowLayout>,
+}
+
+struct RowLayout {
+    layout_id: LayoutId,
+    decoration: StyleRefinement,
+    cells: Vec<AnyElement>,
+    slots: Vec<LayoutId>,
+}
+
+#[derive(Default)]
+struct TablePaint {
+    rows: Vec<Bounds<Pixels>>,
+    column_edges: Vec<Pixels>,
+}
+
+impl IntoElement for Table {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Table {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let column_count = self
+            .rows
+            .iter()
+            .map(|row| row.cells.len())
+            .max()
+            .unwrap_or(0);
+        let widths: Vec<_> = (0..column_count)
+            .map(|column| self.width_of(column))
+            .collect();
+
+        let mut rows: Vec<(StyleRefinement, Vec<AnyElement>)> = std::mem::take(&mut self.rows)
+            .into_iter()
+            .map(|row| {
+                let mut cells = row.cells;
+                cells.resize_with(column_count, || Empty.into_any_element());
+                (row.decoration, cells)
+            })
+            .collect();
+        let cell_ids: Vec<Vec<LayoutId>> = rows
+            .iter_mut()
+            .map(|(_, cells)| {
+                cells
+                    .iter_mut()
+                    .map(|cell| cell.request_layout(window, cx))
+                    .collect()
+            })
+            .collect();
+
+        let sizings: Vec<ColumnSizing> = widths
+            .iter()
+            .enumerate()
+            .map(|(column, width)| {
+                let mut intrinsic = (Pixels::ZERO, Pixels::ZERO);
+                if width.needs_intrinsic_width() {
+                    for ids in &cell_ids {
+                        let (min, max) = measure_intrinsic_width(ids[column], window, cx);
+                        intrinsic = (intrinsic.0.max(min), intrinsic.1.max(max));
+                    }
+                }
+                width.resolve(intrinsic)
+            })
+            .collect();
+
+        let row_style = Style {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Row,
+            ..Default::default()
+        };
+        let rows: Vec<RowLayout> = rows
+            .into_iter()
+            .zip(&cell_ids)
+            .map(|((decoration, cells), ids)| {
+                let slots: Vec<LayoutId> = ids
+                    .iter()
+                    .zip(&sizings)
+                    .map(|(id, sizing)| window.request_layout(sizing.style(), [*id], cx))
+                    .collect();
+                RowLayout {
+                    layout_id: window.request_layout(row_style.clone(), slots.iter().copied(), cx),
+                    decoration,
+                    cells,
+                    slots,
+                }
+            })
+            .collect();
+
+        let mut table_style = Style {
+            display: Display::Flex,
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        };
+        if widths.iter().any(TableColumnWidth::is_flexible) {
+            table_style.size.width = relative(1.).into();
+        }
+        let row_ids: Vec<LayoutId> = rows.iter().map(|row| row.layout_id).collect();
+        let layout_id = window.request_layout(table_style, row_ids, cx);
+
+        self.layout = TableLayout { rows };
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let layout = &mut self.layout;
+        let column_edges = layout
+            .rows
+            .first()
+            .map(|row| {
+                row.slots
+                    .iter()
+                    .skip(1)
+                    .map(|slot| window.layout_bounds(*slot).left())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let rows = layout
+            .rows
+            .iter_mut()
+            .map(|row| {
+                for cell in &mut row.cells {
+                    cell.prepaint(window, cx);
+                }
+                window.layout_bounds(row.layout_id)
+            })
+            .collect();
+
+        self.geometry = TablePaint { rows, column_edges };
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let (layout, geometry) = (&mut self.layout, &self.geometry);
+        for (row, row_bounds) in layout.rows.iter_mut().zip(&geometry.rows) {
+            let mut decoration = Style::default();
+            decoration.refine(&row.decoration);
+            decoration.paint(*row_bounds, window, cx, |window, cx| {
+                for cell in &mut row.cells {
+                    cell.paint(window, cx);
+                }
+            });
+        }
+
+        if let Some(border) = &self.border {
+            let row_edges: Vec<Pixels> =
+                geometry.rows.iter().skip(1).map(|row| row.top()).collect();
+            border.paint(bounds, &row_edges, &geometry.column_edges, window);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ColumnSizing, Extent, FixedColumnWidth, FlexColumnWidth, FractionColumnWidth,
+        IntrinsicColumnWidth, MaxColumnWidth, MinColumnWidth, Table, TableColumnWidth, TableRow,
+    };
+    use crate::test_util::{draw, probe, sized_probe};
+    use gpui::{div, point, px, size, Bounds, ParentElement, Styled, TestAppContext};
+
+    #[test]
+    fn test_column_width_combinators() {
+        let intrinsic = (px(40.), px(90.));
+        let resolve = |width: TableColumnWidth| width.resolve(intrinsic);
+
+        assert_eq!(
+            resolve(MaxColumnWidth::new(FixedColumnWidth(60.), IntrinsicColumnWidth::new()).into()),
+            ColumnSizing {
+                width: Extent::Px(px(90.)),
+                flex: None,
+                min: None,
+                max: None,
+            }
+        );
+        // A flexible column capped at a fixed width
+        assert_eq!(
+            resolve(MinColumnWidth::new(FlexColumnWidth(2.), FixedColumnWidth(120.)).into()),
+            ColumnSizing {
+                width: Extent::Px(px(0.)),
+                flex: Some(2.),
+                min: None,
+                max: Some(Extent::Px(px(120.))),
+            }
+        );
+        // Fixed and fractional widths can only be compared during layout
+        assert_eq!(
+            resolve(MaxColumnWidth::new(FixedColumnWidth(50.), FractionColumnWidth(0.2)).into()),
+            ColumnSizing {
+                width: Extent::Px(px(50.)),
+                flex: None,
+                min: Some(Extent::Fraction(0.2)),
+                max: None,
+            }
+        );
+    }
+
+    #[gpui::test]
+    fn test_table_fixed_flex_and_fraction_columns(cx: &mut TestAppContext) {
+        let (a, a_bounds) = sized_probe(10., 20.);
+        let (b, b_bounds) = probe();
+        let (c, c_bounds) = sized_probe(10., 40.);
+        let (d, d_bounds) = probe();
+        let (e, e_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            Table::new()
+                .column_width(0, FixedColumnWidth(100.))
+                .column_width(2, FractionColumnWidth(0.25))
+                .row(TableRow::new().child(a).child(b).child(c).child(d))
+                .row(TableRow::new().child(e))
+        });
+
+        // 400 - 100 - 100 left for two equal flex columns
+        assert_eq!(a_bounds.get().origin, point(px(0.), px(0.)));
+        assert_eq!(
+            b_bounds.get(),
+            Bounds::new(point(px(100.), px(0.)), size(px(100.), px(40.)))
+        );
+        assert_eq!(c_bounds.get().origin, point(px(200.), px(0.)));
+        assert_eq!(
+            d_bounds.get(),
+            Bounds::new(point(px(300.), px(0.)), size(px(100.), px(40.)))
+        );
+        // The first row is as tall as its tallest cell
+        assert_eq!(e_bounds.get().origin, point(px(0.), px(40.)));
+    }
+
+    #[gpui::test]
+    fn test_table_intrinsic_columns(cx: &mut TestAppContext) {
+        let (a, _) = sized_probe(50., 20.);
+        let (b, b_bounds) = probe();
+        let (c, _) = sized_probe(80., 20.);
+        let (d, d_bounds) = probe();
+        let (e, e_bounds) = probe();
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            Table::new()
+                .column_width(0, IntrinsicColumnWidth::new())
+                .column_width(
+                    1,
+                    MaxColumnWidth::new(FixedColumnWidth(60.), FixedColumnWidth(30.)),
+                )
+                .column_width(
+                    2,
+                    MinColumnWidth::new(FlexColumnWidth(1.), FixedColumnWidth(120.)),
+                )
+                .row(TableRow::new().child(a).child(b).child(e))
+                .row(TableRow::new().child(c).child(d))
+        });
+
+        // The widest cell of the first column sets its width in every row
+        assert_eq!(b_bounds.get().origin, point(px(80.), px(0.)));
+        assert_eq!(d_bounds.get().origin, point(px(80.), px(20.)));
+        assert_eq!(b_bounds.get().size.width, px(60.));
+        // The flexible column stops growing at its cap
+        assert_eq!(
+            e_bounds.get(),
+            Bounds::new(point(px(140.), px(0.)), size(px(120.), px(20.)))
+        );
+    }
+
+    #[gpui::test]
+    fn test_table_intrinsic_column_stretches_full_width_children(cx: &mut TestAppContext) {
+        let (label, _) = sized_probe(50., 10.);
+        let (divider, divider_bounds) = probe();
+        let (after, after_bounds) = probe();
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            Table::new()
+                .column_width(0, IntrinsicColumnWidth::new())
+                .row(
+                    TableRow::new()
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .child(label)
+                                .child(div().w_full().h(px(10.)).child(divider)),
+                        )
+                        .child(after),
+                )
+        });
+
+        // The divider spans the measured column rather than keeping the
+        // width it resolved while the column was measured
+        assert_eq!(
+            divider_bounds.get(),
+            Bounds::new(point(px(0.), px(10.)), size(px(50.), px(10.)))
+        );
+        assert_eq!(after_bounds.get().origin, point(px(50.), px(0.)));
+    }
+}
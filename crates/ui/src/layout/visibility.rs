@@ -0,0 +1,259 @@
+// crates/ui/src/layout/visibility.rs
+
+use super::Opacity;
+use crate::accessibility::Accessible;
+use gpui::*;
+
+/// Flutter-style Visibility widget
+///
+/// Shows or hides its child. By default a hidden child is removed
+/// entirely, losing any element state, and `replacement` (nothing unless
+/// set) is shown instead. The `maintain_*` options keep progressively more
+/// of the hidden child alive:
+///
+/// - `maintain_state`: still laid out offstage, so its state survives, but
+///   takes no space
+/// - `maintain_size`: keeps its space in the layout; implies `maintain_state`
+/// - `maintain_interactivity`: keeps receiving pointer events while
+///   invisible; implies `maintain_size`
+///
+/// A hidden child is always marked `aria_hidden`.
+#[derive(IntoElement)]
+pub struct Visibility {
+    child: AnyElement,
+    replacement: Option<AnyElement>,
+    visible: bool,
+    maintain_state: bool,
+    maintain_size: bool,
+    maintain_interactivity: bool,
+}
+
+impl Visibility {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            replacement: None,
+            visible: true,
+            maintain_state: false,
+            maintain_size: false,
+            maintain_interactivity: false,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Shown instead of a hidden child that is not maintained
+    pub fn replacement(mut self, replacement: impl IntoElement) -> Self {
+        self.replacement = Some(replacement.into_any_element());
+        self
+    }
+
+    pub fn maintain_state(mut self, maintain: bool) -> Self {
+        self.maintain_state = maintain;
+        self
+    }
+
+    pub fn maintain_size(mut self, maintain: bool) -> Self {
+        self.maintain_size = maintain;
+        self
+    }
+
+    pub fn maintain_interactivity(mut self, maintain: bool) -> Self {
+        self.maintain_interactivity = maintain;
+        self
+    }
+}
+
+impl RenderOnce for Visibility {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let maintain_size = self.maintain_size || self.maintain_interactivity;
+        let maintain_state = self.maintain_state || maintain_size;
+
+        if self.visible {
+            self.child
+        } else if self.maintain_interactivity {
+            Opacity::new(0., self.child).into_any_element()
+        } else if maintain_size {
+            Offstage {
+                keep_size: true,
+                ..Offstage::new(self.child)
+            }
+            .into_any_element()
+        } else if maintain_state {
+            Offstage::new(self.child).into_any_element()
+        } else {
+            self.replacement.unwrap_or_else(|| Empty.into_any_element())
+        }
+    }
+}
+
+/// Flutter-style Offstage widget
+///
+/// While `offstage` (the default), the child is still laid out, so it keeps
+/// its element state, but it is not painted, cannot be hit, takes no space
+/// and is marked `aria_hidden`.
+pub struct Offstage {
+    child: AnyElement,
+    offstage: bool,
+    /// Lay the child out in place, keeping its space, instead of on its own
+    keep_size: bool,
+}
+
+impl Offstage {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            offstage: true,
+            keep_size: false,
+        }
+    }
+
+    pub fn offstage(mut self, offstage: bool) -> Self {
+        self.offstage = offstage;
+        self
+    }
+}
+
+impl IntoElement for Offstage {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Offstage {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        if !self.offstage {
+            return (self.child.request_layout(window, cx), ());
+        }
+
+        let child = std::mem::replace(&mut self.child, Empty.into_any_element());
+        self.child = div().aria_hidden(true).child(child).into_any_element();
+        if self.keep_size {
+            return (self.child.request_layout(window, cx), ());
+        }
+
+        self.child.layout_as_root(
+            size(AvailableSpace::MaxContent, AvailableSpace::MaxContent),
+            window,
+            cx,
+        );
+        (window.request_layout(Style::default(), [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if !self.offstage {
+            self.child.prepaint(window, cx);
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if !self.offstage {
+            self.child.paint(window, cx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Offstage, Visibility};
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{div, point, px, size, Bounds, ParentElement, Styled, TestAppContext};
+
+    #[gpui::test]
+    fn test_offstage_takes_no_space_and_skips_prepaint(cx: &mut TestAppContext) {
+        let (hidden, hidden_bounds) = sized_probe(40., 40.);
+        let (shown, shown_bounds) = sized_probe(40., 40.);
+        let (after, after_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            div()
+                .flex()
+                .flex_col()
+                .child(Offstage::new(hidden))
+                .child(Offstage::new(shown).offstage(false))
+                .child(after)
+        });
+
+        assert_eq!(hidden_bounds.get(), Bounds::default());
+        assert_eq!(shown_bounds.get().origin, point(px(0.), px(0.)));
+        assert_eq!(after_bounds.get().origin, point(px(0.), px(40.)));
+    }
+
+    #[gpui::test]
+    fn test_visibility_modes(cx: &mut TestAppContext) {
+        let (removed, removed_bounds) = sized_probe(10., 10.);
+        let (replacement, replacement_bounds) = sized_probe(10., 5.);
+        let (state, state_bounds) = sized_probe(10., 10.);
+        let (sized, sized_bounds) = sized_probe(10., 10.);
+        let (interactive, interactive_bounds) = sized_probe(10., 10.);
+        let (after, after_bounds) = sized_probe(10., 10.);
+
+        draw(cx, size(px(400.), px(300.)), move |_, _| {
+            div()
+                .flex()
+                .flex_col()
+                .child(Visibility::new(removed).visible(false))
+                .child(
+                    Visibility::new(div())
+                        .visible(false)
+                        .replacement(replacement),
+                )
+                .child(Visibility::new(state).visible(false).maintain_state(true))
+                .child(Visibility::new(sized).visible(false).maintain_size(true))
+                .child(
+                    Visibility::new(interactive)
+                        .visible(false)
+                        .maintain_interactivity(true),
+                )
+                .child(after)
+        });
+
+        assert_eq!(removed_bounds.get(), Bounds::default());
+        assert_eq!(replacement_bounds.get().origin, point(px(0.), px(0.)));
+        assert_eq!(state_bounds.get(), Bounds::default());
+        // Keeps its space but is never prepainted
+        assert_eq!(sized_bounds.get(), Bounds::default());
+        // Still prepainted (and so hit-testable) in its own place
+        assert_eq!(interactive_bounds.get().origin, point(px(0.), px(15.)));
+        assert_eq!(after_bounds.get().origin, point(px(0.), px(25.)));
+    }
+}
@@ -0,0 +1,177 @@
+// crates/ui/src/layout/wrap.rs
+
+use super::{WrapAlignment, WrapCrossAlignment};
+use gpui::*;
+
+/// Flutter-style Wrap widget
+///
+/// Lays children out along `direction`, starting a new run whenever the
+/// next child does not fit. Built on flexbox wrapping with gaps.
+#[derive(IntoElement)]
+pub struct Wrap {
+    children: Vec<AnyElement>,
+    direction: Axis,
+    spacing: Pixels,
+    run_spacing: Pixels,
+    alignment: WrapAlignment,
+    run_alignment: WrapAlignment,
+    cross_axis_alignment: WrapCrossAlignment,
+}
+
+impl Wrap {
+    pub fn new() -> Self {
+        Self {
+            children: Vec::new(),
+            direction: Axis::Horizontal,
+            spacing: px(0.),
+            run_spacing: px(0.),
+            alignment: WrapAlignment::Start,
+            run_alignment: WrapAlignment::Start,
+            cross_axis_alignment: WrapCrossAlignment::Start,
+        }
+    }
+
+    pub fn direction(mut self, direction: Axis) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub fn horizontal(self) -> Self {
+        self.direction(Axis::Horizontal)
+    }
+
+    pub fn vertical(self) -> Self {
+        self.direction(Axis::Vertical)
+    }
+
+    /// Gap between children within a run
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = px(spacing);
+        self
+    }
+
+    /// Gap between runs
+    pub fn run_spacing(mut self, run_spacing: f32) -> Self {
+        self.run_spacing = px(run_spacing);
+        self
+    }
+
+    /// How children are placed along the main axis within each run
+    pub fn alignment(mut self, alignment: WrapAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// How runs are placed along the cross axis
+    pub fn run_alignment(mut self, run_alignment: WrapAlignment) -> Self {
+        self.run_alignment = run_alignment;
+        self
+    }
+
+    /// How children of different sizes are aligned within a run
+    pub fn cross_axis_alignment(mut self, alignment: WrapCrossAlignment) -> Self {
+        self.cross_axis_alignment = alignment;
+        self
+    }
+}
+
+impl Default for Wrap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentElement for Wrap {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl RenderOnce for Wrap {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let mut element = div().flex().flex_wrap();
+
+        element = match self.direction {
+            Axis::Horizontal => element
+                .flex_row()
+                .w_full()
+                .gap_x(self.spacing)
+                .gap_y(self.run_spacing),
+            Axis::Vertical => element
+                .flex_col()
+                .h_full()
+                .gap_y(self.spacing)
+                .gap_x(self.run_spacing),
+        };
+
+        element.style().justify_content = Some(self.alignment.justify_content());
+        element.style().align_content = Some(self.run_alignment.align_content());
+        element.style().align_items = Some(self.cross_axis_alignment.align_items());
+
+        element.children(self.children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Wrap;
+    use crate::layout::{WrapAlignment, WrapCrossAlignment};
+    use crate::test_util::{draw, sized_probe};
+    use gpui::{point, px, size, ParentElement, TestAppContext};
+
+    #[gpui::test]
+    fn test_wrap_breaks_into_runs(cx: &mut TestAppContext) {
+        let (a, a_bounds) = sized_probe(40., 20.);
+        let (b, b_bounds) = sized_probe(40., 30.);
+        let (c, c_bounds) = sized_probe(40., 20.);
+
+        draw(cx, size(px(100.), px(200.)), move |_, _| {
+            Wrap::new()
+                .spacing(10.)
+                .run_spacing(5.)
+                .child(a)
+                .child(b)
+                .child(c)
+        });
+
+        assert_eq!(a_bounds.get().origin, point(px(0.), px(0.)));
+        assert_eq!(b_bounds.get().origin, point(px(50.), px(0.)));
+        // The first run is as tall as its tallest child
+        assert_eq!(c_bounds.get().origin, point(px(0.), px(35.)));
+    }
+
+    #[gpui::test]
+    fn test_wrap_alignment(cx: &mut TestAppContext) {
+        let (a, a_bounds) = sized_probe(40., 20.);
+        let (b, b_bounds) = sized_probe(40., 30.);
+
+        draw(cx, size(px(100.), px(200.)), move |_, _| {
+            Wrap::new()
+                .alignment(WrapAlignment::End)
+                .cross_axis_alignment(WrapCrossAlignment::Center)
+                .child(a)
+                .child(b)
+        });
+
+        assert_eq!(a_bounds.get().origin, point(px(20.), px(5.)));
+        assert_eq!(b_bounds.get().origin, point(px(60.), px(0.)));
+    }
+
+    #[gpui::test]
+    fn test_vertical_wrap(cx: &mut TestAppContext) {
+        let (a, a_bounds) = sized_probe(20., 60.);
+        let (b, b_bounds) = sized_probe(30., 60.);
+
+        draw(cx, size(px(200.), px(100.)), move |_, _| {
+            Wrap::new()
+                .vertical()
+                .spacing(10.)
+                .run_spacing(8.)
+                .child(a)
+                .child(b)
+        });
+
+        assert_eq!(a_bounds.get().origin, point(px(0.), px(0.)));
+        assert_eq!(b_bounds.get().origin, point(px(28.), px(0.)));
+    }
+}
@@ -2,18 +2,25 @@
 pub mod accessibility;
 pub mod color;
 pub mod icons;
+pub mod media_query;
 pub mod styled_ext;
 pub mod theme;
 
 // Layout primitives
 pub mod layout;
 
+// Scrolling and lazily built lists
+pub mod scroll;
+
 // Component modules
 pub mod components;
 
 // Prelude for convenient imports
 pub mod prelude;
 
+#[cfg(test)]
+mod test_util;
+
 /// Initialize the gpui-flutter library
 pub fn init() {
     // Register any global state or actions here
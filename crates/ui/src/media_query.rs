@@ -0,0 +1,123 @@
+// crates/ui/src/media_query.rs
+
+use crate::theme::{Breakpoint, ThemeMode, ThemeRegistry};
+use gpui::{App, Global, Pixels, Size, Window, WindowAppearance};
+
+/// User preferences GPUI does not read from the platform, kept per app
+#[derive(Debug, Clone, Copy)]
+struct MediaPreferences {
+    text_scale_factor: f32,
+    reduce_motion: bool,
+}
+
+impl Global for MediaPreferences {}
+
+impl Default for MediaPreferences {
+    fn default() -> Self {
+        Self {
+            text_scale_factor: 1.0,
+            reduce_motion: false,
+        }
+    }
+}
+
+/// Flutter-style MediaQuery: a snapshot of the window and user preferences
+///
+/// Window size, scale factor and brightness come from GPUI. Text scale and
+/// reduced motion are not exposed by GPUI's platform layer, so the app sets
+/// them for all its windows with [`MediaQuery::set_text_scale_factor`] and
+/// [`MediaQuery::set_reduce_motion`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MediaQuery {
+    /// Size of the window's content area in logical pixels
+    pub size: Size<Pixels>,
+    /// Physical pixels per logical pixel
+    pub scale_factor: f32,
+    /// Whether the platform is using a light or dark appearance
+    pub platform_brightness: ThemeMode,
+    /// Multiplier applied to font sizes
+    pub text_scale_factor: f32,
+    /// Whether animations should be minimized
+    pub reduce_motion: bool,
+}
+
+impl MediaQuery {
+    /// Read the current media query for a window
+    pub fn of(window: &Window, cx: &App) -> Self {
+        let preferences = cx
+            .try_global::<MediaPreferences>()
+            .copied()
+            .unwrap_or_default();
+
+        let platform_brightness = match window.appearance() {
+            WindowAppearance::Light | WindowAppearance::VibrantLight => ThemeMode::Light,
+            WindowAppearance::Dark | WindowAppearance::VibrantDark => ThemeMode::Dark,
+        };
+
+        Self {
+            size: window.viewport_size(),
+            scale_factor: window.scale_factor(),
+            platform_brightness,
+            text_scale_factor: preferences.text_scale_factor,
+            reduce_motion: preferences.reduce_motion,
+        }
+    }
+
+    /// Set the text scale factor reported to every window of the app
+    pub fn set_text_scale_factor(cx: &mut App, factor: f32) {
+        cx.default_global::<MediaPreferences>().text_scale_factor = factor.max(0.1);
+    }
+
+    /// Set whether every window of the app should report reduced motion
+    pub fn set_reduce_motion(cx: &mut App, reduce_motion: bool) {
+        cx.default_global::<MediaPreferences>().reduce_motion = reduce_motion;
+    }
+
+    /// The largest breakpoint of the active theme reached by the window width
+    pub fn breakpoint(&self) -> Option<Breakpoint> {
+        ThemeRegistry::get_active()
+            .breakpoints
+            .active(self.size.width)
+    }
+
+    /// Whether the window is at least as wide as the given breakpoint
+    pub fn matches(&self, breakpoint: Breakpoint) -> bool {
+        ThemeRegistry::get_active()
+            .breakpoints
+            .matches(breakpoint, self.size.width)
+    }
+
+    /// Whether a window is at least as wide as the given breakpoint, for
+    /// callers without the app at hand
+    pub(crate) fn window_matches(window: &Window, breakpoint: Breakpoint) -> bool {
+        ThemeRegistry::get_active()
+            .breakpoints
+            .matches(breakpoint, window.viewport_size().width)
+    }
+
+    /// Scale a font size by the text scale factor
+    pub fn scale_text(&self, font_size: Pixels) -> Pixels {
+        font_size * self.text_scale_factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MediaQuery;
+    use crate::theme::{Breakpoint, ThemeMode};
+    use gpui::{px, size, TestAppContext};
+
+    #[gpui::test]
+    fn test_media_query_reads_window(cx: &mut TestAppContext) {
+        let cx = cx.add_empty_window();
+        cx.simulate_resize(size(px(900.), px(700.)));
+
+        let query = cx.update(|window, cx| MediaQuery::of(window, cx));
+
+        assert_eq!(query.size, size(px(900.), px(700.)));
+        assert_eq!(query.platform_brightness, ThemeMode::Light);
+        assert!(query.matches(Breakpoint::Md));
+        assert!(!query.matches(Breakpoint::Lg));
+        assert_eq!(query.breakpoint(), Some(Breakpoint::Md));
+    }
+}
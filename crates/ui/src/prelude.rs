@@ -8,14 +8,18 @@ pub use gpui::{
 };
 
 // Re-export our modules
-pub use crate::accessibility::{AccessibilityProps, Accessible, AriaRole};
+pub use crate::accessibility::{AccessibilityProps, Accessible, AriaChecked, AriaRole};
 pub use crate::color::Color;
 pub use crate::icons::{Icon, IconSize};
+pub use crate::media_query::MediaQuery;
 pub use crate::styled_ext::StyledExt;
-pub use crate::theme::{ActiveTheme, Theme, ThemeColors, ThemeRegistry};
+pub use crate::theme::{ActiveTheme, Breakpoint, Theme, ThemeColors, ThemeRegistry};
 
 // Re-export layout primitives
 pub use crate::layout::*;
 
+// Re-export scrolling widgets
+pub use crate::scroll::*;
+
 // Re-export components
 pub use crate::components::*;
@@ -0,0 +1,858 @@
+// crates/ui/src/scroll/custom_scroll_view.rs
+
+use super::sliver::{SliverGrid, SliverKind, SliverList, SliverPersistentHeader};
+use super::{ScrollController, Sliver};
+use gpui::*;
+use std::cell::RefCell;
+use std::ops::{Add, Range, Sub};
+use std::rc::Rc;
+
+/// Flutter-style CustomScrollView: one vertical scroll view made of slivers
+///
+/// Slivers are laid out one after another against the viewport each frame.
+/// Lists and grids only build the children within `cache_extent` of the
+/// visible area, and persistent headers such as [`SliverAppBar`](super::SliverAppBar)
+/// collapse, pin or float over the content below them.
+///
+/// ```ignore
+/// CustomScrollView::new()
+///     .sliver(SliverAppBar::new().title("Photos").expanded_height(200.).pinned(true))
+///     .sliver(SliverPersistentHeader::new(32., 32., |_, _, _, _| section("Recent")).pinned(true))
+///     .sliver(SliverGrid::builder(GridDelegate::FixedCrossAxisCount(3), photos.len(), tile))
+/// ```
+pub struct CustomScrollView {
+    id: ElementId,
+    slivers: Vec<Sliver>,
+    controller: Option<ScrollController>,
+    cache_extent: Pixels,
+    /// State kept between frames, taken from element state during layout
+    state: Rc<RefCell<ViewState>>,
+    /// Children built during prepaint, painted afterwards
+    children: SliverChildren,
+}
+
+impl CustomScrollView {
+    /// Without an explicit [`id`](Self::id) the scroll position is keyed by
+    /// the call site.
+    #[track_caller]
+    pub fn new() -> Self {
+        Self {
+            id: ElementId::CodeLocation(*core::panic::Location::caller()),
+            slivers: Vec::new(),
+            controller: None,
+            cache_extent: px(250.),
+            state: Rc::default(),
+            children: SliverChildren::default(),
+        }
+    }
+
+    /// Key under which the scroll position is stored between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn sliver(mut self, sliver: impl Into<Sliver>) -> Self {
+        self.slivers.push(sliver.into());
+        self
+    }
+
+    pub fn slivers(mut self, slivers: impl IntoIterator<Item = impl Into<Sliver>>) -> Self {
+        self.slivers.extend(slivers.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn controller(mut self, controller: ScrollController) -> Self {
+        self.controller = Some(controller);
+        self
+    }
+
+    /// How far beyond the viewport lists and grids build children ahead of time
+    pub fn cache_extent(mut self, extent: f32) -> Self {
+        self.cache_extent = px(extent);
+        self
+    }
+}
+
+impl Default for CustomScrollView {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a view remembers about its slivers between frames
+#[derive(Default)]
+struct ViewState {
+    controller: ScrollController,
+    slivers: Vec<SliverCache>,
+    last_offset: Option<Pixels>,
+}
+
+#[derive(Default)]
+struct SliverCache {
+    /// Measured list item heights, valid for `width`
+    extents: ItemExtents,
+    width: Pixels,
+    /// How much of a floating header has slid back into view
+    reveal: Pixels,
+}
+
+/// Running sums over a list that grows and shrinks at the end, where
+/// updating one entry and summing a prefix each take O(log n)
+#[derive(Default)]
+struct Fenwick<T> {
+    /// Node `i`, counting from one, sums the entries in
+    /// `i - lowest_bit(i)..i`
+    nodes: Vec<T>,
+}
+
+impl<T: Copy + Default + Add<Output = T> + Sub<Output = T>> Fenwick<T> {
+    fn push(&mut self, value: T) {
+        let index = self.nodes.len() + 1;
+        let below = index - (index & index.wrapping_neg());
+        let node = self.prefix(index - 1) - self.prefix(below) + value;
+        self.nodes.push(node);
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.nodes.truncate(len);
+    }
+
+    /// Replace every sum that covers entry `index` with `f` of it
+    fn update(&mut self, index: usize, f: impl Fn(T) -> T) {
+        let mut node = index + 1;
+        while node <= self.nodes.len() {
+            self.nodes[node - 1] = f(self.nodes[node - 1]);
+            node += node & node.wrapping_neg();
+        }
+    }
+
+    /// Sum of the first `count` entries
+    fn prefix(&self, count: usize) -> T {
+        let mut sum = T::default();
+        let mut node = count;
+        while node > 0 {
+            sum = sum + self.nodes[node - 1];
+            node -= node & node.wrapping_neg();
+        }
+        sum
+    }
+}
+
+/// Heights of a list's items, where items not measured yet count as the
+/// average of those that have been
+#[derive(Default)]
+struct ItemExtents {
+    heights: Vec<Option<Pixels>>,
+    /// Measured heights, with unmeasured items as zero
+    measured: Fenwick<Pixels>,
+    /// One for each measured item
+    counted: Fenwick<usize>,
+    measured_total: Pixels,
+    measured_count: usize,
+}
+
+impl ItemExtents {
+    fn resize(&mut self, count: usize) {
+        while self.heights.len() > count {
+            if let Some(Some(height)) = self.heights.pop() {
+                self.measured_total -= height;
+                self.measured_count -= 1;
+            }
+        }
+        self.measured.truncate(self.heights.len());
+        self.counted.truncate(self.heights.len());
+        while self.heights.len() < count {
+            self.heights.push(None);
+            self.measured.push(Pixels::ZERO);
+            self.counted.push(0);
+        }
+    }
+
+    fn estimate(&self) -> Pixels {
+        if self.measured_count == 0 {
+            Pixels::ZERO
+        } else {
+            self.measured_total / self.measured_count as f32
+        }
+    }
+
+    fn set(&mut self, index: usize, height: Pixels) {
+        match self.heights[index] {
+            Some(old) if old == height => return,
+            Some(old) => {
+                self.measured_total -= old;
+                self.measured.update(index, |sum| sum - old);
+            }
+            None => {
+                self.measured_count += 1;
+                self.counted.update(index, |count| count + 1);
+            }
+        }
+        self.measured_total += height;
+        self.measured.update(index, |sum| sum + height);
+        self.heights[index] = Some(height);
+    }
+
+    /// Height of the whole list
+    fn total(&self) -> Pixels {
+        self.measured_total + self.estimate() * (self.heights.len() - self.measured_count) as f32
+    }
+
+    /// Index of the first item ending after `offset`, and where it starts
+    fn item_at(&self, offset: Pixels) -> (usize, Pixels) {
+        // Descend the trees, taking each node whose items, measured or
+        // estimated, all end by `offset`
+        let estimate = self.estimate();
+        let len = self.heights.len();
+        let (mut index, mut start) = (0, Pixels::ZERO);
+        let mut step = if len == 0 { 0 } else { 1 << len.ilog2() };
+        while step > 0 {
+            let next = index + step;
+            if next <= len {
+                let unmeasured = step - self.counted.nodes[next - 1];
+                let end = start + self.measured.nodes[next - 1] + estimate * unmeasured as f32;
+                if end <= offset {
+                    (index, start) = (next, end);
+                }
+            }
+            step /= 2;
+        }
+        (index, start)
+    }
+}
+
+/// Children built for this frame, positioned relative to the viewport
+#[derive(Default)]
+struct SliverChildren {
+    content: Vec<(AnyElement, Point<Pixels>)>,
+    headers: Vec<(AnyElement, Point<Pixels>)>,
+}
+
+/// Running position while slivers are laid out in order
+struct SliverCursor {
+    /// Start of the next sliver, in scroll coordinates
+    position: Pixels,
+    /// Bottom edge of the pinned headers so far, in viewport coordinates
+    pinned_extent: Pixels,
+    offset: Pixels,
+    /// How far the offset moved since the last frame
+    delta: Pixels,
+    width: Pixels,
+    /// Scroll coordinates close enough to the viewport to build children for
+    build_range: Range<Pixels>,
+}
+
+impl SliverCursor {
+    fn is_near_viewport(&self, start: Pixels, end: Pixels) -> bool {
+        end > self.build_range.start && start < self.build_range.end
+    }
+
+    fn viewport_point(&self, x: Pixels, y: Pixels) -> Point<Pixels> {
+        point(x, y - self.offset)
+    }
+
+    /// Lay out `child` at `width`, returning its height
+    fn measure(&self, child: &mut AnyElement, window: &mut Window, cx: &mut App) -> Pixels {
+        child
+            .layout_as_root(
+                size(
+                    AvailableSpace::Definite(self.width),
+                    AvailableSpace::MinContent,
+                ),
+                window,
+                cx,
+            )
+            .height
+    }
+
+    fn box_adapter(
+        &mut self,
+        child: AnyElement,
+        children: &mut SliverChildren,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let mut child = div().w(self.width).child(child).into_any_element();
+        let height = self.measure(&mut child, window, cx);
+        if self.is_near_viewport(self.position, self.position + height) {
+            let origin = self.viewport_point(Pixels::ZERO, self.position);
+            children.content.push((child, origin));
+        }
+        self.position += height;
+    }
+
+    fn list(
+        &mut self,
+        list: SliverList,
+        cache: &mut SliverCache,
+        children: &mut SliverChildren,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let build = |index: usize, window: &mut Window, cx: &mut App| {
+            let item = div().w(self.width).child((list.builder)(index, window, cx));
+            match list.item_extent {
+                Some(extent) => item.h(extent).overflow_hidden().into_any_element(),
+                None => item.into_any_element(),
+            }
+        };
+
+        if let Some(extent) = list.item_extent {
+            // Fixed extents place every item without measuring any
+            let (first, end) = if extent > Pixels::ZERO {
+                let first = ((self.build_range.start - self.position) / extent)
+                    .floor()
+                    .max(0.) as usize;
+                let end = ((self.build_range.end - self.position) / extent)
+                    .ceil()
+                    .max(0.) as usize;
+                (first.min(list.item_count), end.min(list.item_count))
+            } else {
+                (0, 0)
+            };
+            for index in first..end {
+                let mut item = build(index, window, cx);
+                self.measure(&mut item, window, cx);
+                let start = self.position + extent * index as f32;
+                children
+                    .content
+                    .push((item, self.viewport_point(Pixels::ZERO, start)));
+            }
+            self.position += extent * list.item_count as f32;
+            return;
+        }
+
+        let extents = &mut cache.extents;
+        if cache.width != self.width {
+            *extents = ItemExtents::default();
+            cache.width = self.width;
+        }
+        extents.resize(list.item_count);
+
+        // With nothing measured yet, the first item sets the estimate
+        if list.item_count > 0 && extents.measured_count == 0 {
+            let mut first = build(0, window, cx);
+            extents.set(0, self.measure(&mut first, window, cx));
+        }
+
+        let (first, start) = extents.item_at(self.build_range.start - self.position);
+        let mut position = self.position + start;
+        for index in first..list.item_count {
+            if position >= self.build_range.end {
+                break;
+            }
+            let mut item = build(index, window, cx);
+            let height = self.measure(&mut item, window, cx);
+            extents.set(index, height);
+            let origin = self.viewport_point(Pixels::ZERO, position);
+            children.content.push((item, origin));
+            position += height;
+        }
+        self.position += extents.total();
+    }
+
+    fn grid(
+        &mut self,
+        grid: SliverGrid,
+        children: &mut SliverChildren,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let columns = grid
+            .delegate
+            .cross_axis_count(self.width, grid.cross_axis_spacing);
+        let tile_width = ((self.width - grid.cross_axis_spacing * (columns - 1) as f32)
+            / columns as f32)
+            .max(Pixels::ZERO);
+        let tile = size(tile_width, tile_width / grid.child_aspect_ratio);
+        let stride = tile.height + grid.main_axis_spacing;
+
+        let rows = grid.item_count.div_ceil(columns);
+        if rows == 0 {
+            return;
+        }
+
+        let first_row = ((self.build_range.start - self.position) / stride)
+            .floor()
+            .max(0.) as usize;
+        let end_row = (((self.build_range.end - self.position) / stride)
+            .ceil()
+            .max(0.) as usize)
+            .min(rows);
+        for row in first_row..end_row {
+            let top = self.position + stride * row as f32;
+            let start = row * columns;
+            let end = (start + columns).min(grid.item_count);
+            for (column, index) in (start..end).enumerate() {
+                let mut tile_element = div()
+                    .w(tile.width)
+                    .h(tile.height)
+                    .child((grid.builder)(index, window, cx))
+                    .into_any_element();
+                tile_element.layout_as_root(tile.map(AvailableSpace::Definite), window, cx);
+                let left = (tile.width + grid.cross_axis_spacing) * column as f32;
+                children
+                    .content
+                    .push((tile_element, self.viewport_point(left, top)));
+            }
+        }
+
+        self.position += stride * rows as f32 - grid.main_axis_spacing;
+    }
+
+    fn persistent_header(
+        &mut self,
+        header: SliverPersistentHeader,
+        cache: &mut SliverCache,
+        children: &mut SliverChildren,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let (min, max) = (header.min_extent, header.max_extent);
+        // Where the header's bottom edge would be if it simply scrolled
+        let bottom = self.position + max - self.offset;
+        self.position += max;
+
+        if header.floating {
+            cache.reveal = (cache.reveal - self.delta).clamp(Pixels::ZERO, max);
+        } else {
+            cache.reveal = Pixels::ZERO;
+        }
+
+        let (top, extent, overlaps_content) = if header.pinned {
+            let natural = bottom - self.pinned_extent;
+            let extent = natural.max(cache.reveal).clamp(min, max);
+            let top = (bottom - extent).max(self.pinned_extent);
+            (top, extent, natural < extent)
+        } else {
+            let extent = bottom.clamp(min, max);
+            let floating_bottom = self.pinned_extent + cache.reveal;
+            if header.floating && floating_bottom > bottom {
+                (floating_bottom - max, max, true)
+            } else {
+                (bottom - extent, extent, false)
+            }
+        };
+
+        if header.pinned {
+            self.pinned_extent = top + extent;
+        }
+        if top + extent <= Pixels::ZERO {
+            return;
+        }
+
+        let content = (header.builder)(max - extent, overlaps_content, window, cx);
+        let mut element = div()
+            .w(self.width)
+            .h(extent)
+            .child(content)
+            .into_any_element();
+        element.layout_as_root(
+            size(self.width, extent).map(AvailableSpace::Definite),
+            window,
+            cx,
+        );
+        children.headers.push((element, point(Pixels::ZERO, top)));
+    }
+}
+
+impl IntoElement for CustomScrollView {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for CustomScrollView {
+    type RequestLayoutState = ();
+    type PrepaintState = Hitbox;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let state =
+            window.with_element_state(id.unwrap(), |state: Option<Rc<RefCell<ViewState>>>, _| {
+                let state = state.unwrap_or_default();
+                (state.clone(), state)
+            });
+        if let Some(controller) = &self.controller {
+            state.borrow_mut().controller = controller.clone();
+        }
+
+        let style = Style {
+            size: size(relative(1.).into(), relative(1.).into()),
+            ..Default::default()
+        };
+        self.state = state;
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let state = &self.state;
+        let hitbox = window.insert_hitbox(bounds, HitboxBehavior::Normal);
+        let controller = state.borrow().controller.clone();
+        controller.set_axis(Axis::Vertical);
+        controller.tick(window, cx);
+
+        let offset = controller.offset();
+        let viewport = bounds.size.height;
+        let mut children = SliverChildren::default();
+        let total_extent = {
+            let mut state = state.borrow_mut();
+            let delta = offset - state.last_offset.unwrap_or(offset);
+            state.last_offset = Some(offset);
+            state
+                .slivers
+                .resize_with(self.slivers.len(), SliverCache::default);
+
+            let mut cursor = SliverCursor {
+                position: Pixels::ZERO,
+                pinned_extent: Pixels::ZERO,
+                offset,
+                delta,
+                width: bounds.size.width,
+                build_range: offset - self.cache_extent..offset + viewport + self.cache_extent,
+            };
+            for (sliver, cache) in self.slivers.drain(..).zip(&mut state.slivers) {
+                match sliver.0 {
+                    SliverKind::ToBoxAdapter(child) => {
+                        cursor.box_adapter(child, &mut children, window, cx)
+                    }
+                    SliverKind::List(list) => cursor.list(list, cache, &mut children, window, cx),
+                    SliverKind::Grid(grid) => cursor.grid(grid, &mut children, window, cx),
+                    SliverKind::PersistentHeader(header) => {
+                        cursor.persistent_header(header, cache, &mut children, window, cx)
+                    }
+                }
+            }
+            cursor.position
+        };
+
+        let max_scroll_extent = (total_extent - viewport).max(Pixels::ZERO);
+        controller.set_extents(viewport, max_scroll_extent);
+        if offset > max_scroll_extent {
+            // The content got shorter; settle at the new end next frame
+            controller.jump_to(max_scroll_extent);
+            window.request_animation_frame();
+        }
+
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            // Headers are prepainted last so they sit above the content
+            for (child, origin) in children.content.iter_mut().chain(&mut children.headers) {
+                child.prepaint_at(bounds.origin + *origin, window, cx);
+            }
+        });
+        controller.notify_listeners(window, cx);
+
+        self.children = children;
+        hitbox
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            let children = &mut self.children;
+            for (child, _) in children.content.iter_mut().chain(&mut children.headers) {
+                child.paint(window, cx);
+            }
+        });
+
+        let controller = self.state.borrow().controller.clone();
+        let hitbox = hitbox.clone();
+        let view = window.current_view();
+        window.on_mouse_event(move |event: &ScrollWheelEvent, phase, window, cx| {
+            if phase != DispatchPhase::Bubble || !hitbox.should_handle_scroll(window) {
+                return;
+            }
+
+            let offset = controller.offset();
+            let delta = event.delta.pixel_delta(px(20.));
+            controller.jump_to(offset - delta.y);
+            // Leave the event to outer scroll views once this one hits an end
+            if controller.offset() != offset {
+                cx.stop_propagation();
+                cx.notify(view);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomScrollView;
+    use crate::scroll::{
+        GridDelegate, ScrollController, SliverAppBar, SliverGrid, SliverList,
+        SliverPersistentHeader, SliverToBoxAdapter,
+    };
+    use crate::test_util::{draw_view, redraw};
+    use gpui::{
+        canvas, point, px, size, Bounds, Canvas, Modifiers, Pixels, ScrollDelta, ScrollWheelEvent,
+        Styled, TestAppContext, TouchPhase,
+    };
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    type Probes = Rc<RefCell<HashMap<usize, Bounds<Pixels>>>>;
+
+    fn probe(key: usize, probes: &Probes) -> Canvas<()> {
+        let probes = probes.clone();
+        canvas(
+            move |bounds, _, _| {
+                probes.borrow_mut().insert(key, bounds);
+            },
+            |_, _, _, _| {},
+        )
+        .w_full()
+    }
+
+    #[gpui::test]
+    fn test_custom_scroll_view_builds_visible_list_items(cx: &mut TestAppContext) {
+        let probes: Probes = Rc::default();
+        let built = Rc::new(Cell::new(0));
+        let controller = ScrollController::new();
+
+        let recorded = probes.clone();
+        let count = built.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(200.), px(300.)), move |_, _| {
+            let items = recorded.clone();
+            let count = count.clone();
+            CustomScrollView::new()
+                .controller(attached.clone())
+                .sliver(SliverToBoxAdapter::new(
+                    probe(usize::MAX, &recorded).h(px(100.)),
+                ))
+                .sliver(SliverList::builder(10_000, move |index, _, _| {
+                    count.set(count.get() + 1);
+                    probe(index, &items).h(px(50.))
+                }))
+        });
+
+        probes.borrow_mut().clear();
+        built.set(0);
+        redraw(cx);
+        assert!(built.get() < 20, "built {} items", built.get());
+        assert_eq!(probes.borrow()[&3].origin, point(px(0.), px(250.)));
+
+        controller.jump_to(px(1000.));
+        redraw(cx);
+        assert_eq!(probes.borrow()[&18].origin, point(px(0.), px(0.)));
+        assert_eq!(controller.max_scroll_extent(), px(100. + 500_000. - 300.));
+    }
+
+    #[gpui::test]
+    fn test_fixed_extent_list_builds_from_the_offset(cx: &mut TestAppContext) {
+        let probes: Probes = Rc::default();
+        let built = Rc::new(Cell::new(0));
+        let controller = ScrollController::new();
+
+        let recorded = probes.clone();
+        let count = built.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(200.), px(300.)), move |_, _| {
+            let items = recorded.clone();
+            let count = count.clone();
+            CustomScrollView::new().controller(attached.clone()).sliver(
+                SliverList::builder(1_000_000, move |index, _, _| {
+                    count.set(count.get() + 1);
+                    probe(index, &items)
+                })
+                .item_extent(30.),
+            )
+        });
+
+        controller.jump_to(px(15_000_015.));
+        probes.borrow_mut().clear();
+        built.set(0);
+        redraw(cx);
+        assert!(built.get() < 40, "built {} items", built.get());
+        assert_eq!(probes.borrow()[&500_000].origin, point(px(0.), px(-15.)));
+        assert_eq!(controller.max_scroll_extent(), px(30_000_000. - 300.));
+    }
+
+    #[test]
+    fn test_item_extents_find_items_by_offset() {
+        let mut extents = super::ItemExtents::default();
+        extents.resize(5);
+        extents.set(0, px(10.));
+        extents.set(1, px(30.));
+
+        // The three unmeasured items count as the average of 20px
+        assert_eq!(extents.total(), px(100.));
+        assert_eq!(extents.item_at(px(0.)), (0, px(0.)));
+        assert_eq!(extents.item_at(px(10.)), (1, px(10.)));
+        assert_eq!(extents.item_at(px(55.)), (2, px(40.)));
+        assert_eq!(extents.item_at(px(500.)), (5, px(100.)));
+
+        extents.set(2, px(40.));
+        assert_eq!(extents.item_at(px(55.)), (2, px(40.)));
+        assert_eq!(extents.item_at(px(80.)), (3, px(80.)));
+    }
+
+    #[test]
+    fn test_item_extents_measure_late_items() {
+        let mut extents = super::ItemExtents::default();
+        extents.resize(100);
+        extents.set(0, px(20.));
+        extents.set(1, px(20.));
+        assert_eq!(extents.item_at(px(1000.)), (50, px(1000.)));
+
+        // Item 90 raises the estimate for the others to 30px
+        extents.set(90, px(50.));
+        assert_eq!(extents.item_at(px(100.)), (4, px(100.)));
+        assert_eq!(extents.item_at(px(2700.)), (90, px(2680.)));
+        assert_eq!(extents.item_at(px(2730.)), (91, px(2730.)));
+        assert_eq!(extents.total(), px(2730. + 30. * 9.));
+
+        // Growing and shrinking keep the measured sums
+        extents.resize(200);
+        assert_eq!(extents.item_at(px(2740.)), (91, px(2730.)));
+        extents.resize(50);
+        assert_eq!(extents.total(), px(40. + 20. * 48.));
+    }
+
+    #[gpui::test]
+    fn test_pinned_headers_collapse_and_stack(cx: &mut TestAppContext) {
+        let probes: Probes = Rc::default();
+        let shrink = Rc::new(Cell::new(px(0.)));
+        let controller = ScrollController::new();
+
+        let recorded = probes.clone();
+        let shrink_offset = shrink.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(200.), px(300.)), move |_, _| {
+            let app_bar = recorded.clone();
+            let section = recorded.clone();
+            let shrink_offset = shrink_offset.clone();
+            CustomScrollView::new()
+                .controller(attached.clone())
+                .sliver(
+                    SliverPersistentHeader::new(50., 200., move |shrink, _, _, _| {
+                        shrink_offset.set(shrink);
+                        probe(0, &app_bar).h_full()
+                    })
+                    .pinned(true),
+                )
+                .sliver(SliverToBoxAdapter::new(probe(1, &recorded).h(px(400.))))
+                .sliver(
+                    SliverPersistentHeader::new(30., 30., move |_, _, _, _| {
+                        probe(2, &section).h(px(30.))
+                    })
+                    .pinned(true),
+                )
+                .sliver(SliverList::builder(100, |_, _, _| gpui::div().h(px(40.))))
+        });
+
+        controller.jump_to(px(100.));
+        redraw(cx);
+        assert_eq!(shrink.get(), px(100.));
+        assert_eq!(
+            probes.borrow()[&0],
+            Bounds::new(point(px(0.), px(0.)), size(px(200.), px(100.)))
+        );
+
+        // Past both headers: the app bar stays collapsed and the section
+        // header sticks right below it
+        controller.jump_to(px(1000.));
+        redraw(cx);
+        assert_eq!(probes.borrow()[&0].size.height, px(50.));
+        assert_eq!(probes.borrow()[&2].origin, point(px(0.), px(50.)));
+    }
+
+    #[gpui::test]
+    fn test_floating_header_reappears_on_scroll_up(cx: &mut TestAppContext) {
+        let probes: Probes = Rc::default();
+        let controller = ScrollController::new();
+
+        let recorded = probes.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(200.), px(300.)), move |_, _| {
+            let header = recorded.clone();
+            CustomScrollView::new()
+                .controller(attached.clone())
+                .sliver(
+                    SliverPersistentHeader::new(60., 60., move |_, _, _, _| {
+                        probe(0, &header).h(px(60.))
+                    })
+                    .floating(true),
+                )
+                .sliver(SliverList::builder(100, |_, _, _| gpui::div().h(px(40.))))
+        });
+
+        controller.jump_to(px(500.));
+        redraw(cx);
+        probes.borrow_mut().clear();
+        redraw(cx);
+        assert!(!probes.borrow().contains_key(&0));
+
+        cx.simulate_event(ScrollWheelEvent {
+            position: point(px(50.), px(150.)),
+            delta: ScrollDelta::Pixels(point(px(0.), px(20.))),
+            modifiers: Modifiers::default(),
+            touch_phase: TouchPhase::Moved,
+        });
+        redraw(cx);
+        assert_eq!(controller.offset(), px(480.));
+        assert_eq!(probes.borrow()[&0].origin, point(px(0.), px(-40.)));
+    }
+
+    #[gpui::test]
+    fn test_sliver_grid_after_app_bar(cx: &mut TestAppContext) {
+        let probes: Probes = Rc::default();
+
+        let recorded = probes.clone();
+        draw_view(cx, size(px(200.), px(300.)), move |_, _| {
+            let tiles = recorded.clone();
+            CustomScrollView::new()
+                .sliver(SliverAppBar::new().title("Photos").pinned(true))
+                .sliver(
+                    SliverGrid::builder(
+                        GridDelegate::FixedCrossAxisCount(2),
+                        1_000,
+                        move |index, _, _| probe(index, &tiles).h_full(),
+                    )
+                    .cross_axis_spacing(20.)
+                    .main_axis_spacing(10.),
+                )
+        });
+
+        let probes = probes.borrow();
+        assert_eq!(
+            probes[&3],
+            Bounds::new(point(px(110.), px(56. + 100.)), size(px(90.), px(90.)))
+        );
+        assert!(!probes.contains_key(&20));
+    }
+}
@@ -0,0 +1,421 @@
+// crates/ui/src/scroll/grid_view.rs
+
+use super::{ListView, ListViewController};
+use crate::layout::{BoxConstraints, EdgeInsets, LayoutBuilder};
+use gpui::*;
+use std::rc::Rc;
+
+type TileBuilder = Rc<dyn Fn(usize, &mut Window, &mut App) -> AnyElement>;
+
+/// How a [`GridView`] decides the number of columns
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridDelegate {
+    /// Always this many columns
+    FixedCrossAxisCount(usize),
+    /// As few columns as possible with none wider than this
+    MaxCrossAxisExtent(Pixels),
+}
+
+impl GridDelegate {
+    /// Number of columns that fit in `width` with `spacing` between them
+    pub fn cross_axis_count(self, width: Pixels, spacing: Pixels) -> usize {
+        match self {
+            GridDelegate::FixedCrossAxisCount(count) => count.max(1),
+            GridDelegate::MaxCrossAxisExtent(extent) => {
+                ((width / (extent + spacing)).ceil() as usize).max(1)
+            }
+        }
+    }
+}
+
+/// Settings shared by [`GridView`] and [`GridViewBuilder`]
+struct GridSpec {
+    id: ElementId,
+    delegate: GridDelegate,
+    main_axis_spacing: Pixels,
+    cross_axis_spacing: Pixels,
+    child_aspect_ratio: f32,
+    padding: EdgeInsets,
+}
+
+impl GridSpec {
+    fn new(id: ElementId, delegate: GridDelegate) -> Self {
+        Self {
+            id,
+            delegate,
+            main_axis_spacing: px(0.),
+            cross_axis_spacing: px(0.),
+            child_aspect_ratio: 1.0,
+            padding: EdgeInsets::ZERO,
+        }
+    }
+
+    /// Number of columns and tile size for the given constraints
+    fn geometry(&self, constraints: BoxConstraints) -> (usize, GridGeometry) {
+        let width = if constraints.has_bounded_width() {
+            constraints.max_width - self.padding.horizontal()
+        } else {
+            Pixels::ZERO
+        };
+        let columns = self
+            .delegate
+            .cross_axis_count(width, self.cross_axis_spacing);
+        let tile_width = ((width - self.cross_axis_spacing * (columns - 1) as f32)
+            / columns as f32)
+            .max(Pixels::ZERO);
+        let geometry = GridGeometry {
+            tile: size(tile_width, tile_width / self.child_aspect_ratio),
+            cross_axis_spacing: self.cross_axis_spacing,
+        };
+        (columns, geometry)
+    }
+}
+
+/// Flutter-style GridView: a scrolling grid of equally sized tiles
+///
+/// `count` and `extent` take their tiles as children; `builder` returns a
+/// [`GridViewBuilder`], which creates them on demand and only for the rows
+/// on screen, on top of [`ListView`].
+///
+/// ```ignore
+/// GridView::builder(GridDelegate::MaxCrossAxisExtent(px(160.)), photos.len(), move |index, _, _| {
+///     img(photos[index].clone()).size_full()
+/// })
+/// .main_axis_spacing(8.)
+/// .cross_axis_spacing(8.)
+/// ```
+#[derive(IntoElement)]
+pub struct GridView {
+    spec: GridSpec,
+    children: Vec<AnyElement>,
+}
+
+impl GridView {
+    /// A grid with a fixed number of columns
+    #[track_caller]
+    pub fn count(cross_axis_count: usize) -> Self {
+        Self {
+            spec: GridSpec::new(
+                ElementId::CodeLocation(*core::panic::Location::caller()),
+                GridDelegate::FixedCrossAxisCount(cross_axis_count),
+            ),
+            children: Vec::new(),
+        }
+    }
+
+    /// A grid whose tiles are at most `max_cross_axis_extent` wide
+    #[track_caller]
+    pub fn extent(max_cross_axis_extent: f32) -> Self {
+        Self {
+            spec: GridSpec::new(
+                ElementId::CodeLocation(*core::panic::Location::caller()),
+                GridDelegate::MaxCrossAxisExtent(px(max_cross_axis_extent)),
+            ),
+            children: Vec::new(),
+        }
+    }
+
+    /// A grid of `item_count` tiles, each built on demand from its index
+    #[track_caller]
+    pub fn builder<E: IntoElement>(
+        delegate: GridDelegate,
+        item_count: usize,
+        builder: impl Fn(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> GridViewBuilder {
+        GridViewBuilder {
+            spec: GridSpec::new(
+                ElementId::CodeLocation(*core::panic::Location::caller()),
+                delegate,
+            ),
+            item_count,
+            builder: Rc::new(move |index, window, cx| {
+                builder(index, window, cx).into_any_element()
+            }),
+            controller: None,
+        }
+    }
+
+    /// Key under which the scroll position is stored between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.spec.id = id.into();
+        self
+    }
+
+    /// Gap between rows
+    pub fn main_axis_spacing(mut self, spacing: f32) -> Self {
+        self.spec.main_axis_spacing = px(spacing);
+        self
+    }
+
+    /// Gap between columns
+    pub fn cross_axis_spacing(mut self, spacing: f32) -> Self {
+        self.spec.cross_axis_spacing = px(spacing);
+        self
+    }
+
+    /// Tile width divided by tile height
+    pub fn child_aspect_ratio(mut self, ratio: f32) -> Self {
+        self.spec.child_aspect_ratio = ratio.max(f32::EPSILON);
+        self
+    }
+
+    /// Insets around the tiles, inside the scrolling area
+    pub fn padding(mut self, padding: impl Into<EdgeInsets>) -> Self {
+        self.spec.padding = padding.into();
+        self
+    }
+}
+
+impl ParentElement for GridView {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+/// A [`GridView`] whose tiles are built on demand, made by
+/// [`GridView::builder`]
+///
+/// A [`ListViewController`] drives it by tile index.
+#[derive(IntoElement)]
+pub struct GridViewBuilder {
+    spec: GridSpec,
+    item_count: usize,
+    builder: TileBuilder,
+    controller: Option<ListViewController>,
+}
+
+impl GridViewBuilder {
+    /// Key under which the scroll position is stored between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.spec.id = id.into();
+        self
+    }
+
+    /// Gap between rows
+    pub fn main_axis_spacing(mut self, spacing: f32) -> Self {
+        self.spec.main_axis_spacing = px(spacing);
+        self
+    }
+
+    /// Gap between columns
+    pub fn cross_axis_spacing(mut self, spacing: f32) -> Self {
+        self.spec.cross_axis_spacing = px(spacing);
+        self
+    }
+
+    /// Tile width divided by tile height
+    pub fn child_aspect_ratio(mut self, ratio: f32) -> Self {
+        self.spec.child_aspect_ratio = ratio.max(f32::EPSILON);
+        self
+    }
+
+    /// Insets around the tiles, inside the scrolling area
+    pub fn padding(mut self, padding: impl Into<EdgeInsets>) -> Self {
+        self.spec.padding = padding.into();
+        self
+    }
+
+    /// Scroll the grid by tile index
+    pub fn controller(mut self, controller: ListViewController) -> Self {
+        self.controller = Some(controller);
+        self
+    }
+}
+
+/// Tile size resolved for one width
+#[derive(Clone, Copy)]
+struct GridGeometry {
+    tile: Size<Pixels>,
+    cross_axis_spacing: Pixels,
+}
+
+impl GridGeometry {
+    fn row(self, tiles: impl IntoIterator<Item = AnyElement>) -> Div {
+        div()
+            .flex()
+            .flex_none()
+            .gap_x(self.cross_axis_spacing)
+            .children(tiles.into_iter().map(|tile| {
+                div()
+                    .flex_none()
+                    .w(self.tile.width)
+                    .h(self.tile.height)
+                    .child(tile)
+            }))
+    }
+}
+
+impl RenderOnce for GridView {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        LayoutBuilder::new(move |constraints, _window, _cx| {
+            let spec = self.spec;
+            let (columns, geometry) = spec.geometry(constraints);
+            let mut rows = Vec::new();
+            let mut children = self.children.into_iter().peekable();
+            while children.peek().is_some() {
+                rows.push(geometry.row(children.by_ref().take(columns)));
+            }
+
+            div()
+                .id(spec.id)
+                .size_full()
+                .overflow_y_scroll()
+                .pt(spec.padding.top)
+                .pr(spec.padding.right)
+                .pb(spec.padding.bottom)
+                .pl(spec.padding.left)
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_y(spec.main_axis_spacing)
+                        .children(rows),
+                )
+        })
+    }
+}
+
+impl RenderOnce for GridViewBuilder {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        LayoutBuilder::new(move |constraints, _window, _cx| {
+            let spec = self.spec;
+            let (columns, geometry) = spec.geometry(constraints);
+            let (item_count, builder) = (self.item_count, self.builder);
+            let main_axis_spacing = spec.main_axis_spacing;
+            let row_count = item_count.div_ceil(columns);
+            let mut list = ListView::separated(
+                row_count,
+                move |row, window, cx| {
+                    let start = row * columns;
+                    let end = (start + columns).min(item_count);
+                    geometry.row((start..end).map(|index| builder(index, window, cx)))
+                },
+                move |_, _, _| div().h(main_axis_spacing),
+            )
+            .id(spec.id)
+            .padding(spec.padding);
+
+            if let Some(controller) = self.controller {
+                controller.map_pending_index(|index| index / columns);
+                list = list.controller(controller);
+            }
+
+            list
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GridDelegate, GridView};
+    use crate::scroll::ListViewController;
+    use crate::test_util::{draw_view, redraw};
+    use gpui::{canvas, point, px, size, Bounds, ParentElement, Pixels, Styled, TestAppContext};
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    type Tiles = Rc<RefCell<HashMap<usize, Bounds<Pixels>>>>;
+
+    fn tile(index: usize, tiles: &Tiles) -> impl gpui::IntoElement {
+        let tiles = tiles.clone();
+        canvas(
+            move |bounds, _, _| {
+                tiles.borrow_mut().insert(index, bounds);
+            },
+            |_, _, _, _| {},
+        )
+        .size_full()
+    }
+
+    #[test]
+    fn test_max_extent_column_count() {
+        let delegate = GridDelegate::MaxCrossAxisExtent(px(150.));
+
+        assert_eq!(delegate.cross_axis_count(px(400.), px(0.)), 3);
+        assert_eq!(delegate.cross_axis_count(px(300.), px(0.)), 2);
+        assert_eq!(delegate.cross_axis_count(px(0.), px(0.)), 1);
+        assert_eq!(
+            GridDelegate::FixedCrossAxisCount(0).cross_axis_count(px(400.), px(0.)),
+            1
+        );
+    }
+
+    #[gpui::test]
+    fn test_grid_builder_lays_out_visible_tiles(cx: &mut TestAppContext) {
+        let tiles: Tiles = Rc::default();
+        let built = Rc::new(Cell::new(0));
+
+        let recorded = tiles.clone();
+        let count = built.clone();
+        let cx = draw_view(cx, size(px(400.), px(300.)), move |_, _| {
+            let recorded = recorded.clone();
+            let count = count.clone();
+            GridView::builder(
+                GridDelegate::FixedCrossAxisCount(4),
+                10_000,
+                move |index, _, _| {
+                    count.set(count.get() + 1);
+                    tile(index, &recorded)
+                },
+            )
+            .main_axis_spacing(10.)
+            .cross_axis_spacing(10.)
+            .child_aspect_ratio(0.5)
+        });
+
+        tiles.borrow_mut().clear();
+        built.set(0);
+        redraw(cx);
+
+        // (400 - 3 * 10) / 4 = 92.5 wide, twice as tall
+        let tiles = tiles.borrow();
+        assert_eq!(tiles[&5].size, size(px(92.5), px(185.)));
+        assert_eq!(tiles[&5].origin, point(px(102.5), px(195.)));
+        assert!(!tiles.contains_key(&8));
+        assert!(built.get() < 100, "built {} tiles", built.get());
+    }
+
+    #[gpui::test]
+    fn test_grid_controller_scrolls_to_tile_row(cx: &mut TestAppContext) {
+        let tiles: Tiles = Rc::default();
+        let controller = ListViewController::new();
+
+        let recorded = tiles.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(400.), px(300.)), move |_, _| {
+            let recorded = recorded.clone();
+            GridView::builder(
+                GridDelegate::MaxCrossAxisExtent(px(100.)),
+                1_000,
+                move |index, _, _| tile(index, &recorded),
+            )
+            .controller(attached.clone())
+        });
+
+        controller.jump_to_index(41);
+        redraw(cx);
+
+        // Four 100px columns: tile 41 starts row 10
+        assert_eq!(tiles.borrow()[&41].origin, point(px(100.), px(0.)));
+    }
+
+    #[gpui::test]
+    fn test_grid_count_with_children(cx: &mut TestAppContext) {
+        let tiles: Tiles = Rc::default();
+
+        let recorded = tiles.clone();
+        draw_view(cx, size(px(200.), px(300.)), move |_, _| {
+            GridView::count(2)
+                .cross_axis_spacing(20.)
+                .main_axis_spacing(5.)
+                .padding(px(10.))
+                .children((0..3).map(|index| tile(index, &recorded)))
+        });
+
+        let tiles = tiles.borrow();
+        assert_eq!(tiles[&0].size, size(px(80.), px(80.)));
+        assert_eq!(tiles[&1].origin, point(px(110.), px(10.)));
+        assert_eq!(tiles[&2].origin, point(px(10.), px(95.)));
+    }
+}
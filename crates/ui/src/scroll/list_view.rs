@@ -0,0 +1,500 @@
+// crates/ui/src/scroll/list_view.rs
+
+use crate::layout::EdgeInsets;
+use gpui::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type ItemBuilder = Rc<dyn Fn(usize, &mut Window, &mut App) -> AnyElement>;
+
+/// Drives the scroll position of a [`ListView`] by item index
+///
+/// Cloning a controller shares it. Scroll requests are applied on the next
+/// frame, so call `cx.notify()` on the owning view after making one.
+#[derive(Clone, Default)]
+pub struct ListViewController(Rc<RefCell<ControllerState>>);
+
+#[derive(Default)]
+struct ControllerState {
+    list: Option<ListState>,
+    reverse: bool,
+    pending: Option<ScrollRequest>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ScrollRequest {
+    index: usize,
+    alignment: f32,
+}
+
+impl ListViewController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show the item at `index` at the leading edge of the list
+    pub fn jump_to_index(&self, index: usize) {
+        self.scroll_to_index(index, 0.0);
+    }
+
+    /// Show the item at `index` with its leading edge at `alignment` of the viewport
+    ///
+    /// `0.0` puts the item at the leading edge (top, or bottom when reversed),
+    /// `0.5` centers it and `1.0` puts it against the trailing edge.
+    pub fn scroll_to_index(&self, index: usize, alignment: f32) {
+        self.0.borrow_mut().pending = Some(ScrollRequest {
+            index,
+            alignment: alignment.clamp(0.0, 1.0),
+        });
+    }
+
+    /// Number of items in the attached list
+    pub fn item_count(&self) -> usize {
+        self.0
+            .borrow()
+            .list
+            .as_ref()
+            .map_or(0, |list| list.item_count())
+    }
+
+    /// The list state for this frame, created or resized as needed
+    ///
+    /// A change in item count is treated as items added or removed at the
+    /// visual bottom of the list: the end of a normal list, index 0 of a
+    /// reversed one. Cached item heights and scroll position survive it.
+    fn bind(&self, item_count: usize, reverse: bool, overdraw: Pixels) -> ListState {
+        let mut state = self.0.borrow_mut();
+
+        match &state.list {
+            Some(list) if state.reverse == reverse => {
+                let old_count = list.item_count();
+                if item_count > old_count {
+                    list.splice(old_count..old_count, item_count - old_count);
+                } else if item_count < old_count {
+                    list.splice(item_count..old_count, 0);
+                }
+                list.clone()
+            }
+            _ => {
+                let alignment = if reverse {
+                    ListAlignment::Bottom
+                } else {
+                    ListAlignment::Top
+                };
+                let list = ListState::new(item_count, alignment, overdraw);
+                state.list = Some(list.clone());
+                state.reverse = reverse;
+                list
+            }
+        }
+    }
+
+    /// Rewrite the index of a pending request, for views that show several
+    /// items per list row
+    pub(crate) fn map_pending_index(&self, f: impl FnOnce(usize) -> usize) {
+        if let Some(request) = &mut self.0.borrow_mut().pending {
+            request.index = f(request.index);
+        }
+    }
+
+    fn take_pending(&self) -> Option<ScrollRequest> {
+        self.0.borrow_mut().pending.take()
+    }
+}
+
+/// Flutter-style ListView that only builds the items on screen
+///
+/// Built on GPUI's `list`, so items may have different heights; each is
+/// measured the first time it scrolls into view. `item_extent` forces a fixed
+/// height on every item instead.
+///
+/// ```ignore
+/// ListView::builder(rows.len(), move |index, _window, _cx| {
+///     Text::new(rows[index].clone())
+/// })
+/// .padding(cx.theme().spacing.md)
+/// ```
+pub struct ListView {
+    id: ElementId,
+    item_count: usize,
+    item_builder: ItemBuilder,
+    separator_builder: Option<ItemBuilder>,
+    item_extent: Option<Pixels>,
+    controller: Option<ListViewController>,
+    reverse: bool,
+    padding: EdgeInsets,
+    cache_extent: Pixels,
+}
+
+impl ListView {
+    /// A list of `item_count` items, each built on demand from its index
+    ///
+    /// Without an explicit [`id`](Self::id) the scroll position is keyed by
+    /// the call site.
+    #[track_caller]
+    pub fn builder<E: IntoElement>(
+        item_count: usize,
+        item_builder: impl Fn(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        Self {
+            id: ElementId::CodeLocation(*core::panic::Location::caller()),
+            item_count,
+            item_builder: Rc::new(move |index, window, cx| {
+                item_builder(index, window, cx).into_any_element()
+            }),
+            separator_builder: None,
+            item_extent: None,
+            controller: None,
+            reverse: false,
+            padding: EdgeInsets::ZERO,
+            cache_extent: px(250.),
+        }
+    }
+
+    /// Like [`builder`](Self::builder), with a separator after every item but the last
+    ///
+    /// The separator builder receives the index of the item it follows.
+    #[track_caller]
+    pub fn separated<E: IntoElement, S: IntoElement>(
+        item_count: usize,
+        item_builder: impl Fn(usize, &mut Window, &mut App) -> E + 'static,
+        separator_builder: impl Fn(usize, &mut Window, &mut App) -> S + 'static,
+    ) -> Self {
+        let mut list = Self::builder(item_count, item_builder);
+        list.separator_builder = Some(Rc::new(move |index, window, cx| {
+            separator_builder(index, window, cx).into_any_element()
+        }));
+        list
+    }
+
+    /// Key under which the scroll position is stored between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Give every item this height, clipping taller content
+    pub fn item_extent(mut self, extent: f32) -> Self {
+        self.item_extent = Some(px(extent));
+        self
+    }
+
+    pub fn controller(mut self, controller: ListViewController) -> Self {
+        self.controller = Some(controller);
+        self
+    }
+
+    /// Start at the bottom with item 0 there, as in a chat
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Insets around the items, inside the scrolling area
+    pub fn padding(mut self, padding: impl Into<EdgeInsets>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// How far beyond the viewport items are measured ahead of time
+    pub fn cache_extent(mut self, extent: f32) -> Self {
+        self.cache_extent = px(extent);
+        self
+    }
+
+    /// Builds the element at a position in the underlying list, which runs
+    /// top to bottom regardless of `reverse`
+    fn render_item_fn(&self) -> ItemBuilder {
+        let item_count = self.item_count;
+        let reverse = self.reverse;
+        let item_extent = self.item_extent;
+        let item_builder = self.item_builder.clone();
+        let separator_builder = self.separator_builder.clone();
+        let padding = self.padding;
+
+        Rc::new(move |position, window, cx| {
+            let index = if reverse {
+                item_count - 1 - position
+            } else {
+                position
+            };
+
+            let mut item = item_builder(index, window, cx);
+            if let Some(extent) = item_extent {
+                item = div()
+                    .flex_none()
+                    .h(extent)
+                    .overflow_hidden()
+                    .child(item)
+                    .into_any_element();
+            }
+
+            let entry = match &separator_builder {
+                Some(separator_builder) if index + 1 < item_count => {
+                    let separator = separator_builder(index, window, cx);
+                    // The separator sits between this item and the next one,
+                    // which is above it when reversed
+                    let column = div().flex().flex_col();
+                    if reverse {
+                        column.child(separator).child(item).into_any_element()
+                    } else {
+                        column.child(item).child(separator).into_any_element()
+                    }
+                }
+                _ => item,
+            };
+
+            // GPUI's list only honors vertical padding, so the horizontal
+            // insets go on each entry
+            if padding.horizontal() > Pixels::ZERO {
+                div()
+                    .pl(padding.left)
+                    .pr(padding.right)
+                    .child(entry)
+                    .into_any_element()
+            } else {
+                entry
+            }
+        })
+    }
+
+    /// Point the list at a requested item, measuring the items above it so
+    /// the item lands at the requested alignment
+    fn apply_scroll_request(
+        &self,
+        request: ScrollRequest,
+        list: &ListState,
+        bounds: Bounds<Pixels>,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if self.item_count == 0 {
+            return;
+        }
+
+        let index = request.index.min(self.item_count - 1);
+        let (position, alignment) = if self.reverse {
+            (self.item_count - 1 - index, 1.0 - request.alignment)
+        } else {
+            (index, request.alignment)
+        };
+
+        let viewport_height = bounds.size.height - self.padding.vertical();
+        let item_space = size(
+            AvailableSpace::Definite(bounds.size.width),
+            AvailableSpace::MinContent,
+        );
+        let render_item = self.render_item_fn();
+        let mut measure = |position: usize| {
+            render_item(position, window, cx)
+                .layout_as_root(item_space, window, cx)
+                .height
+        };
+
+        let item_height = measure(position);
+        let mut remaining = ((viewport_height - item_height) * alignment).max(Pixels::ZERO);
+        let mut scroll_top = ListOffset {
+            item_ix: position,
+            offset_in_item: Pixels::ZERO,
+        };
+
+        while remaining > Pixels::ZERO && scroll_top.item_ix > 0 {
+            scroll_top.item_ix -= 1;
+            let height = measure(scroll_top.item_ix);
+            if height >= remaining {
+                scroll_top.offset_in_item = height - remaining;
+                break;
+            }
+            remaining -= height;
+        }
+
+        list.scroll_to(scroll_top);
+    }
+}
+
+impl IntoElement for ListView {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ListView {
+    type RequestLayoutState = (AnyElement, ListViewController);
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let controller = match &self.controller {
+            Some(controller) => controller.clone(),
+            None => window.with_element_state(
+                id.unwrap(),
+                |controller: Option<ListViewController>, _| {
+                    let controller = controller.unwrap_or_default();
+                    (controller.clone(), controller)
+                },
+            ),
+        };
+
+        let state = controller.bind(self.item_count, self.reverse, self.cache_extent);
+        let render_item = self.render_item_fn();
+        let mut list = list(state, move |position, window, cx| {
+            render_item(position, window, cx)
+        })
+        .size_full()
+        .pt(self.padding.top)
+        .pb(self.padding.bottom)
+        .into_any_element();
+
+        let layout_id = list.request_layout(window, cx);
+        (layout_id, (list, controller))
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        (list, controller): &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(request) = controller.take_pending() {
+            let state = controller.bind(self.item_count, self.reverse, self.cache_extent);
+            self.apply_scroll_request(request, &state, bounds, window, cx);
+        }
+
+        list.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        (list, _): &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        list.paint(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ListView, ListViewController};
+    use crate::layout::EdgeInsets;
+    use crate::test_util::{draw_view, redraw};
+    use gpui::{canvas, div, px, size, Bounds, Pixels, Styled, TestAppContext};
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    type Rows = Rc<RefCell<HashMap<usize, Bounds<Pixels>>>>;
+
+    /// A 20px row that records where it was laid out
+    fn row(index: usize, rows: &Rows) -> impl gpui::IntoElement {
+        let rows = rows.clone();
+        canvas(
+            move |bounds, _, _| {
+                rows.borrow_mut().insert(index, bounds);
+            },
+            |_, _, _, _| {},
+        )
+        .w_full()
+        .h(px(20.))
+    }
+
+    #[gpui::test]
+    fn test_list_view_only_builds_visible_items(cx: &mut TestAppContext) {
+        let rows: Rows = Rc::default();
+        let built = Rc::new(Cell::new(0));
+
+        let recorded = rows.clone();
+        let count = built.clone();
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, _| {
+            let recorded = recorded.clone();
+            let count = count.clone();
+            ListView::builder(100_000, move |index, _, _| {
+                count.set(count.get() + 1);
+                row(index, &recorded)
+            })
+        });
+
+        rows.borrow_mut().clear();
+        built.set(0);
+        redraw(cx);
+
+        // The visible rows plus the cache extent below them
+        assert!(built.get() <= 20, "built {} items", built.get());
+        assert_eq!(rows.borrow()[&0].origin.y, px(0.));
+        assert_eq!(rows.borrow()[&4].origin.y, px(80.));
+        assert!(!rows.borrow().contains_key(&5));
+    }
+
+    #[gpui::test]
+    fn test_list_view_scroll_to_index_with_alignment(cx: &mut TestAppContext) {
+        let rows: Rows = Rc::default();
+        let controller = ListViewController::new();
+
+        let recorded = rows.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, _| {
+            let recorded = recorded.clone();
+            ListView::builder(1_000, move |index, _, _| row(index, &recorded))
+                .controller(attached.clone())
+        });
+
+        controller.scroll_to_index(500, 0.5);
+        redraw(cx);
+        assert_eq!(rows.borrow()[&500].origin.y, px(40.));
+        assert_eq!(rows.borrow()[&499].origin.y, px(20.));
+
+        controller.jump_to_index(10);
+        redraw(cx);
+        assert_eq!(rows.borrow()[&10].origin.y, px(0.));
+    }
+
+    #[gpui::test]
+    fn test_list_view_reverse_separated_with_padding(cx: &mut TestAppContext) {
+        let rows: Rows = Rc::default();
+
+        let recorded = rows.clone();
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, _| {
+            let recorded = recorded.clone();
+            ListView::separated(
+                10,
+                move |index, _, _| row(index, &recorded),
+                |_, _, _| div().h(px(1.)),
+            )
+            .reverse(true)
+            .padding(EdgeInsets::all(px(4.)))
+        });
+
+        rows.borrow_mut().clear();
+        redraw(cx);
+
+        // Item 0 sits at the bottom, inside the padding, with the separator
+        // between it and item 1 above
+        let rows = rows.borrow();
+        assert_eq!(rows[&0].origin.y, px(76.));
+        assert_eq!(rows[&1].origin.y, px(55.));
+        assert_eq!(rows[&0].origin.x, px(4.));
+        assert_eq!(rows[&0].size.width, px(192.));
+    }
+}
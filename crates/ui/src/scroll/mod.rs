@@ -0,0 +1,19 @@
+// crates/ui/src/scroll/mod.rs
+
+mod custom_scroll_view;
+mod grid_view;
+mod list_view;
+mod scroll_controller;
+mod scrollbar;
+mod single_child_scroll_view;
+mod sliver;
+mod sliver_app_bar;
+
+pub use custom_scroll_view::CustomScrollView;
+pub use grid_view::{GridDelegate, GridView, GridViewBuilder};
+pub use list_view::{ListView, ListViewController};
+pub use scroll_controller::ScrollController;
+pub use scrollbar::Scrollbar;
+pub use single_child_scroll_view::{KeyboardDismissBehavior, SingleChildScrollView};
+pub use sliver::{Sliver, SliverGrid, SliverList, SliverPersistentHeader, SliverToBoxAdapter};
+pub use sliver_app_bar::SliverAppBar;
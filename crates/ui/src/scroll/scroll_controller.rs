@@ -0,0 +1,240 @@
+// crates/ui/src/scroll/scroll_controller.rs
+
+use gpui::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+type ScrollListener = Rc<dyn Fn(Pixels, &mut Window, &mut App)>;
+
+/// Flutter-style ScrollController for pixel-based scroll views
+///
+/// Wraps the GPUI [`ScrollHandle`] of a [`SingleChildScrollView`](super::SingleChildScrollView)
+/// or [`CustomScrollView`](super::CustomScrollView) and reports offsets as a
+/// positive distance along the view's axis.
+/// Cloning a controller shares it, so the same one can be given to a
+/// [`Scrollbar`](super::Scrollbar).
+#[derive(Clone, Default)]
+pub struct ScrollController {
+    handle: ScrollHandle,
+    state: Rc<RefCell<ControllerState>>,
+}
+
+struct ControllerState {
+    axis: Axis,
+    animation: Option<ScrollAnimation>,
+    listeners: Vec<ScrollListener>,
+    last_reported: Option<Pixels>,
+    /// Viewport and max scroll extent reported by views that lay out their
+    /// content themselves instead of through a scrolling div
+    extents: Option<(Pixels, Pixels)>,
+}
+
+impl Default for ControllerState {
+    fn default() -> Self {
+        Self {
+            axis: Axis::Vertical,
+            animation: None,
+            listeners: Vec::new(),
+            last_reported: None,
+            extents: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ScrollAnimation {
+    from: Pixels,
+    to: Pixels,
+    /// Set by the first frame that advances the animation
+    start: Option<Instant>,
+    duration: Duration,
+}
+
+impl ScrollAnimation {
+    /// Offset at `now`, eased out, and whether the animation has finished
+    fn sample(&self, now: Instant) -> (Pixels, bool) {
+        let elapsed = self
+            .start
+            .map_or(Duration::ZERO, |start| now.saturating_duration_since(start));
+        if elapsed >= self.duration {
+            return (self.to, true);
+        }
+
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        let eased = 1.0 - (1.0 - t).powi(3);
+        (self.from + (self.to - self.from) * eased, false)
+    }
+}
+
+impl ScrollController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The underlying GPUI scroll handle
+    pub fn handle(&self) -> &ScrollHandle {
+        &self.handle
+    }
+
+    /// The axis of the scroll view this controller is attached to
+    pub fn axis(&self) -> Axis {
+        self.state.borrow().axis
+    }
+
+    /// How far the view is scrolled from its start
+    pub fn offset(&self) -> Pixels {
+        -self.handle.offset().along(self.axis())
+    }
+
+    /// The largest offset the content allows, as of the last frame
+    pub fn max_scroll_extent(&self) -> Pixels {
+        match self.state.borrow().extents {
+            Some((_, max)) => max,
+            None => self.handle.max_offset().along(self.axis()),
+        }
+    }
+
+    /// Length of the visible part of the content, as of the last frame
+    pub fn viewport_extent(&self) -> Pixels {
+        match self.state.borrow().extents {
+            Some((viewport, _)) => viewport,
+            None => self.handle.bounds().size.along(self.axis()),
+        }
+    }
+
+    /// Scroll to `offset` immediately, cancelling any animation
+    pub fn jump_to(&self, offset: Pixels) {
+        self.state.borrow_mut().animation = None;
+        self.set_offset(offset);
+    }
+
+    /// Scroll to `offset` over `duration` with an ease-out curve
+    ///
+    /// The view advances the animation each frame, starting from the next
+    /// one, so call `cx.notify()` on the owning view to start it.
+    pub fn animate_to(&self, offset: Pixels, duration: Duration) {
+        if duration.is_zero() {
+            self.jump_to(offset);
+            return;
+        }
+
+        let from = self.offset();
+        self.state.borrow_mut().animation = Some(ScrollAnimation {
+            from,
+            to: self.clamp(offset),
+            start: None,
+            duration,
+        });
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.state.borrow().animation.is_some()
+    }
+
+    /// Call `listener` with the new offset after every frame in which it changed
+    pub fn add_listener(&self, listener: impl Fn(Pixels, &mut Window, &mut App) + 'static) {
+        self.state.borrow_mut().listeners.push(Rc::new(listener));
+    }
+
+    pub(crate) fn set_axis(&self, axis: Axis) {
+        self.state.borrow_mut().axis = axis;
+    }
+
+    /// Record the extents of a view that does its own scrolling layout
+    pub(crate) fn set_extents(&self, viewport: Pixels, max_scroll_extent: Pixels) {
+        self.state.borrow_mut().extents = Some((viewport, max_scroll_extent.max(Pixels::ZERO)));
+    }
+
+    fn clamp(&self, offset: Pixels) -> Pixels {
+        let offset = offset.max(Pixels::ZERO);
+        // Before the first layout there is no extent to clamp against; the
+        // scroll view clamps when it lays out
+        if self.viewport_extent() > Pixels::ZERO {
+            offset.min(self.max_scroll_extent())
+        } else {
+            offset
+        }
+    }
+
+    fn set_offset(&self, offset: Pixels) {
+        let offset = self.clamp(offset);
+        let mut position = self.handle.offset();
+        match self.axis() {
+            Axis::Vertical => position.y = -offset,
+            Axis::Horizontal => position.x = -offset,
+        }
+        self.handle.set_offset(position);
+    }
+
+    /// Advance a running animation; called before the view lays out its content
+    pub(crate) fn tick(&self, window: &mut Window, cx: &App) {
+        let now = cx.background_executor().now();
+        let animation = {
+            let mut state = self.state.borrow_mut();
+            let Some(animation) = state.animation.as_mut() else {
+                return;
+            };
+            animation.start.get_or_insert(now);
+            *animation
+        };
+
+        let (offset, finished) = animation.sample(now);
+        self.set_offset(offset);
+        if finished {
+            self.state.borrow_mut().animation = None;
+        } else {
+            window.request_animation_frame();
+        }
+    }
+
+    /// Tell listeners about an offset change; called after the view laid out
+    pub(crate) fn notify_listeners(&self, window: &mut Window, cx: &mut App) {
+        let offset = self.offset();
+        let listeners = {
+            let mut state = self.state.borrow_mut();
+            if state.last_reported == Some(offset) {
+                return;
+            }
+            // The first frame only records where the view starts
+            let first_frame = state.last_reported.is_none();
+            state.last_reported = Some(offset);
+            if first_frame && offset == Pixels::ZERO {
+                return;
+            }
+            state.listeners.clone()
+        };
+
+        for listener in listeners {
+            listener(offset, window, cx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScrollAnimation;
+    use gpui::px;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_scroll_animation_eases_to_target() {
+        let start = Instant::now();
+        let animation = ScrollAnimation {
+            from: px(0.),
+            to: px(100.),
+            start: Some(start),
+            duration: Duration::from_millis(200),
+        };
+
+        let (halfway, finished) = animation.sample(start + Duration::from_millis(100));
+        assert!(!finished);
+        // Ease-out covers more than half the distance in half the time
+        assert!(halfway > px(50.) && halfway < px(100.));
+
+        assert_eq!(
+            animation.sample(start + Duration::from_millis(250)),
+            (px(100.), true)
+        );
+    }
+}
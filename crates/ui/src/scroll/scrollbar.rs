@@ -0,0 +1,442 @@
+// crates/ui/src/scroll/scrollbar.rs
+
+use super::ScrollController;
+use crate::theme::ActiveTheme;
+use gpui::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// How long an auto-hiding thumb stays fully visible after scrolling stops
+const HIDE_DELAY: Duration = Duration::from_millis(1000);
+/// How long the thumb takes to fade out once the delay has passed
+const FADE_DURATION: Duration = Duration::from_millis(250);
+/// Shortest thumb, so it stays grabbable on very long content
+const MIN_THUMB_LENGTH: Pixels = px(24.);
+
+/// Opacity of an auto-hiding thumb a given time after the last activity
+fn auto_hide_opacity(since_activity: Duration) -> f32 {
+    match since_activity.checked_sub(HIDE_DELAY) {
+        None => 1.0,
+        Some(fading) => 1.0 - (fading.as_secs_f32() / FADE_DURATION.as_secs_f32()).min(1.0),
+    }
+}
+
+/// Flutter-style Scrollbar drawn over the edge of a scroll view
+///
+/// Wraps a scroll view driven by `controller` and draws a track along the
+/// trailing edge of the controller's axis. The thumb uses the theme's
+/// `muted_foreground`; it hides itself a moment after scrolling stops unless
+/// `thumb_visibility` is set, and reappears on hover. The thumb can be
+/// dragged, and clicking the track pages toward the click.
+///
+/// ```ignore
+/// let controller = ScrollController::new();
+/// Scrollbar::new(&controller, SingleChildScrollView::new(content).controller(controller.clone()))
+/// ```
+#[derive(IntoElement)]
+pub struct Scrollbar {
+    id: ElementId,
+    child: AnyElement,
+    controller: ScrollController,
+    thickness: Pixels,
+    thumb_visibility: bool,
+}
+
+impl Scrollbar {
+    #[track_caller]
+    pub fn new(controller: &ScrollController, child: impl IntoElement) -> Self {
+        Self {
+            id: ElementId::CodeLocation(*core::panic::Location::caller()),
+            child: child.into_any_element(),
+            controller: controller.clone(),
+            thickness: px(10.),
+            thumb_visibility: false,
+        }
+    }
+
+    /// Key under which hover and drag state is stored between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Width of the track across the scroll axis
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = px(thickness);
+        self
+    }
+
+    /// Keep the thumb visible instead of hiding it when idle
+    pub fn thumb_visibility(mut self, always_visible: bool) -> Self {
+        self.thumb_visibility = always_visible;
+        self
+    }
+}
+
+impl RenderOnce for Scrollbar {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        div()
+            .relative()
+            .size_full()
+            .child(self.child)
+            .child(ScrollbarTrack {
+                id: self.id,
+                controller: self.controller,
+                thickness: self.thickness,
+                thumb_visibility: self.thumb_visibility,
+            })
+    }
+}
+
+#[derive(Default)]
+struct ScrollbarState {
+    /// Where within the thumb the pointer grabbed it, while dragging
+    drag: Option<Pixels>,
+    hovered: bool,
+    last_activity: Option<Instant>,
+    last_offset: Option<Pixels>,
+}
+
+struct TrackLayout {
+    hitbox: Hitbox,
+    thumb: Bounds<Pixels>,
+    opacity: f32,
+}
+
+struct ScrollbarTrack {
+    id: ElementId,
+    controller: ScrollController,
+    thickness: Pixels,
+    thumb_visibility: bool,
+}
+
+impl ScrollbarTrack {
+    /// Offset that puts the thumb's leading edge at `thumb_start` along the track
+    fn offset_for_thumb(&self, thumb_start: Pixels, track: Bounds<Pixels>) -> Pixels {
+        let axis = self.controller.axis();
+        let max = self.controller.max_scroll_extent();
+        let free = track.size.along(axis) - self.thumb_length(track);
+        if free <= Pixels::ZERO {
+            return Pixels::ZERO;
+        }
+        max * (thumb_start / free).clamp(0.0, 1.0)
+    }
+
+    fn thumb_length(&self, track: Bounds<Pixels>) -> Pixels {
+        let track_length = track.size.along(self.controller.axis());
+        let viewport = self.controller.viewport_extent();
+        let content = viewport + self.controller.max_scroll_extent();
+        (track_length * (viewport / content))
+            .max(MIN_THUMB_LENGTH)
+            .min(track_length)
+    }
+}
+
+impl IntoElement for ScrollbarTrack {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ScrollbarTrack {
+    type RequestLayoutState = Rc<RefCell<ScrollbarState>>;
+    type PrepaintState = Option<TrackLayout>;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let state = window.with_element_state(
+            id.unwrap(),
+            |state: Option<Rc<RefCell<ScrollbarState>>>, _| {
+                let state = state.unwrap_or_default();
+                (state.clone(), state)
+            },
+        );
+
+        let zero: Length = px(0.).into();
+        let mut style = Style {
+            position: Position::Absolute,
+            ..Default::default()
+        };
+        match self.controller.axis() {
+            Axis::Vertical => {
+                style.inset.top = zero;
+                style.inset.bottom = zero;
+                style.inset.right = zero;
+                style.size.width = self.thickness.into();
+            }
+            Axis::Horizontal => {
+                style.inset.left = zero;
+                style.inset.right = zero;
+                style.inset.bottom = zero;
+                style.size.height = self.thickness.into();
+            }
+        }
+
+        (window.request_layout(style, [], cx), state)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        state: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let axis = self.controller.axis();
+        let max = self.controller.max_scroll_extent();
+        if max <= Pixels::ZERO {
+            return None;
+        }
+
+        let now = cx.background_executor().now();
+        let offset = self.controller.offset();
+        let mut state = state.borrow_mut();
+        if state.last_offset.is_some_and(|last| last != offset) {
+            state.last_activity = Some(now);
+        }
+        state.last_offset = Some(offset);
+
+        let opacity = if self.thumb_visibility || state.hovered || state.drag.is_some() {
+            1.0
+        } else {
+            let opacity = state
+                .last_activity
+                .map_or(0.0, |activity| auto_hide_opacity(now - activity));
+            if opacity > 0.0 {
+                // Keep drawing frames while the hide delay or fade runs
+                window.request_animation_frame();
+            }
+            opacity
+        };
+
+        let thumb_length = self.thumb_length(bounds);
+        let free = bounds.size.along(axis) - thumb_length;
+        let thumb_start = free * (offset / max).clamp(0.0, 1.0);
+        let inset = px(2.);
+        let thumb = match axis {
+            Axis::Vertical => Bounds {
+                origin: point(bounds.left() + inset, bounds.top() + thumb_start),
+                size: size(bounds.size.width - inset * 2., thumb_length),
+            },
+            Axis::Horizontal => Bounds {
+                origin: point(bounds.left() + thumb_start, bounds.top() + inset),
+                size: size(thumb_length, bounds.size.height - inset * 2.),
+            },
+        };
+
+        Some(TrackLayout {
+            hitbox: window.insert_hitbox(bounds, HitboxBehavior::Normal),
+            thumb,
+            opacity,
+        })
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        state: &mut Self::RequestLayoutState,
+        layout: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let Some(layout) = layout.take() else {
+            return;
+        };
+
+        let colors = cx.theme().colors;
+        let active = {
+            let state = state.borrow();
+            state.hovered || state.drag.is_some()
+        };
+        if layout.opacity > 0.0 {
+            if active {
+                window.paint_quad(fill(
+                    bounds,
+                    Hsla::from(colors.muted).opacity(0.5 * layout.opacity),
+                ));
+            }
+            let radius = layout.thumb.size.along(self.controller.axis().invert()) / 2.;
+            window.paint_quad(
+                fill(
+                    layout.thumb,
+                    Hsla::from(colors.muted_foreground).opacity(layout.opacity),
+                )
+                .corner_radii(radius),
+            );
+        }
+
+        let axis = self.controller.axis();
+        let view = window.current_view();
+        let hitbox = layout.hitbox;
+        let thumb = layout.thumb;
+        let track = ScrollbarTrack {
+            id: self.id.clone(),
+            controller: self.controller.clone(),
+            thickness: self.thickness,
+            thumb_visibility: self.thumb_visibility,
+        };
+
+        window.on_mouse_event({
+            let state = state.clone();
+            let controller = self.controller.clone();
+            let hitbox = hitbox.clone();
+            move |event: &MouseDownEvent, phase, window, cx| {
+                if phase != DispatchPhase::Bubble
+                    || event.button != MouseButton::Left
+                    || !hitbox.is_hovered(window)
+                {
+                    return;
+                }
+
+                let position = event.position.along(axis);
+                if thumb.contains(&event.position) {
+                    state.borrow_mut().drag = Some(position - thumb.origin.along(axis));
+                } else {
+                    // Page toward the click by one viewport
+                    let page = controller.viewport_extent();
+                    let offset = controller.offset();
+                    if position < thumb.origin.along(axis) {
+                        controller.jump_to(offset - page);
+                    } else {
+                        controller.jump_to(offset + page);
+                    }
+                }
+                cx.stop_propagation();
+                cx.notify(view);
+            }
+        });
+
+        window.on_mouse_event({
+            let state = state.clone();
+            let controller = self.controller.clone();
+            let hitbox = hitbox.clone();
+            move |event: &MouseMoveEvent, phase, window, cx| {
+                if phase != DispatchPhase::Bubble {
+                    return;
+                }
+
+                let mut state = state.borrow_mut();
+                let hovered = hitbox.is_hovered(window);
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    if !hovered {
+                        // Leaving the track starts the hide timer over
+                        state.last_activity = Some(cx.background_executor().now());
+                    }
+                    cx.notify(view);
+                }
+
+                if let Some(grab) = state.drag {
+                    if event.pressed_button == Some(MouseButton::Left) {
+                        let thumb_start = event.position.along(axis) - bounds.origin.along(axis);
+                        controller.jump_to(track.offset_for_thumb(thumb_start - grab, bounds));
+                        cx.notify(view);
+                    } else {
+                        state.drag = None;
+                        state.last_activity = Some(cx.background_executor().now());
+                    }
+                }
+            }
+        });
+
+        window.on_mouse_event({
+            let state = state.clone();
+            move |event: &MouseUpEvent, phase, _window, cx| {
+                if phase == DispatchPhase::Bubble
+                    && event.button == MouseButton::Left
+                    && state.borrow_mut().drag.take().is_some()
+                {
+                    state.borrow_mut().last_activity = Some(cx.background_executor().now());
+                    cx.notify(view);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{auto_hide_opacity, Scrollbar};
+    use crate::scroll::{ScrollController, SingleChildScrollView};
+    use crate::test_util::draw_view;
+    use gpui::{div, point, px, size, Modifiers, MouseButton, Styled, TestAppContext};
+    use std::time::Duration;
+
+    #[test]
+    fn test_auto_hide_opacity() {
+        assert_eq!(auto_hide_opacity(Duration::from_millis(500)), 1.0);
+        assert_eq!(auto_hide_opacity(Duration::from_millis(1125)), 0.5);
+        assert_eq!(auto_hide_opacity(Duration::from_secs(5)), 0.0);
+    }
+
+    fn scrollable(cx: &mut TestAppContext) -> (ScrollController, &mut gpui::VisualTestContext) {
+        let controller = ScrollController::new();
+
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(100.), px(200.)), move |_, _| {
+            Scrollbar::new(
+                &attached,
+                SingleChildScrollView::new(div().w_full().h(px(1000.)))
+                    .controller(attached.clone()),
+            )
+        });
+
+        (controller, cx)
+    }
+
+    #[gpui::test]
+    fn test_scrollbar_click_on_track_pages(cx: &mut TestAppContext) {
+        let (controller, cx) = scrollable(cx);
+
+        // The thumb is 200 * 200 / 1000 = 40px long at the top of the track
+        cx.simulate_click(point(px(95.), px(150.)), Modifiers::default());
+        assert_eq!(controller.offset(), px(200.));
+
+        cx.simulate_click(point(px(95.), px(10.)), Modifiers::default());
+        assert_eq!(controller.offset(), px(0.));
+    }
+
+    #[gpui::test]
+    fn test_scrollbar_thumb_drag(cx: &mut TestAppContext) {
+        let (controller, cx) = scrollable(cx);
+
+        cx.simulate_mouse_down(
+            point(px(95.), px(20.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_move(
+            point(px(95.), px(60.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+        cx.simulate_mouse_up(
+            point(px(95.), px(60.)),
+            MouseButton::Left,
+            Modifiers::default(),
+        );
+
+        // 40px of the 160px the thumb can travel
+        assert_eq!(controller.offset(), px(200.));
+    }
+}
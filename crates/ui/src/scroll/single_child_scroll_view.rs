@@ -0,0 +1,296 @@
+// crates/ui/src/scroll/single_child_scroll_view.rs
+
+use super::ScrollController;
+use crate::layout::EdgeInsets;
+use gpui::*;
+
+/// Whether scrolling takes keyboard focus away from text inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyboardDismissBehavior {
+    /// Focus is left alone
+    #[default]
+    Manual,
+    /// Focus is cleared as soon as the user scrolls the view
+    OnDrag,
+}
+
+/// Flutter-style SingleChildScrollView
+///
+/// Fills its parent and scrolls a single child that may be longer than it
+/// along `axis`. Unlike [`ListView`](super::ListView), the whole child is
+/// built and laid out every frame.
+pub struct SingleChildScrollView {
+    id: ElementId,
+    child: Option<AnyElement>,
+    axis: Axis,
+    controller: Option<ScrollController>,
+    padding: EdgeInsets,
+    keyboard_dismiss_behavior: KeyboardDismissBehavior,
+}
+
+impl SingleChildScrollView {
+    /// Without an explicit [`id`](Self::id) the scroll position is keyed by
+    /// the call site.
+    #[track_caller]
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            id: ElementId::CodeLocation(*core::panic::Location::caller()),
+            child: Some(child.into_any_element()),
+            axis: Axis::Vertical,
+            controller: None,
+            padding: EdgeInsets::ZERO,
+            keyboard_dismiss_behavior: KeyboardDismissBehavior::Manual,
+        }
+    }
+
+    /// Key under which the scroll position is stored between frames
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn horizontal(self) -> Self {
+        self.axis(Axis::Horizontal)
+    }
+
+    pub fn vertical(self) -> Self {
+        self.axis(Axis::Vertical)
+    }
+
+    pub fn controller(mut self, controller: ScrollController) -> Self {
+        self.controller = Some(controller);
+        self
+    }
+
+    /// Insets around the child, inside the scrolling area
+    pub fn padding(mut self, padding: impl Into<EdgeInsets>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    pub fn keyboard_dismiss_behavior(mut self, behavior: KeyboardDismissBehavior) -> Self {
+        self.keyboard_dismiss_behavior = behavior;
+        self
+    }
+}
+
+impl IntoElement for SingleChildScrollView {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for SingleChildScrollView {
+    type RequestLayoutState = (AnyElement, ScrollController);
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let controller = match &self.controller {
+            Some(controller) => controller.clone(),
+            None => {
+                window.with_element_state(id.unwrap(), |controller: Option<ScrollController>, _| {
+                    let controller = controller.unwrap_or_default();
+                    (controller.clone(), controller)
+                })
+            }
+        };
+        controller.set_axis(self.axis);
+
+        // The content keeps its natural length on the scroll axis and fills
+        // the viewport on the other
+        let padding = self.padding;
+        let content = div()
+            .flex()
+            .flex_none()
+            .pt(padding.top)
+            .pr(padding.right)
+            .pb(padding.bottom)
+            .pl(padding.left)
+            .children(self.child.take());
+
+        let viewport = div()
+            .id("viewport")
+            .flex()
+            .size_full()
+            .track_scroll(controller.handle());
+        let mut viewport = match self.axis {
+            Axis::Vertical => viewport
+                .flex_col()
+                .overflow_y_scroll()
+                .child(content.flex_col().w_full()),
+            Axis::Horizontal => viewport
+                .flex_row()
+                .overflow_x_scroll()
+                .child(content.flex_row().h_full()),
+        };
+
+        if self.keyboard_dismiss_behavior == KeyboardDismissBehavior::OnDrag {
+            viewport = viewport.on_scroll_wheel(|_, window, _| window.blur());
+        }
+
+        let mut viewport = viewport.into_any_element();
+        let layout_id = viewport.request_layout(window, cx);
+        (layout_id, (viewport, controller))
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        (viewport, controller): &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        controller.tick(window, cx);
+        viewport.prepaint(window, cx);
+        controller.notify_listeners(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        (viewport, _): &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        viewport.paint(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyboardDismissBehavior, SingleChildScrollView};
+    use crate::layout::EdgeInsets;
+    use crate::scroll::ScrollController;
+    use crate::test_util::{draw_view, redraw, ProbeBounds};
+    use gpui::{
+        canvas, div, point, px, size, FocusHandle, InteractiveElement, Modifiers, Pixels,
+        ScrollDelta, ScrollWheelEvent, Styled, TestAppContext, TouchPhase,
+    };
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    /// A fixed-size block that records its bounds on every frame
+    fn block(width: f32, height: f32, bounds: &ProbeBounds) -> impl gpui::IntoElement {
+        let bounds = bounds.clone();
+        canvas(move |b, _, _| bounds.set(b), |_, _, _, _| {})
+            .w(px(width))
+            .h(px(height))
+    }
+
+    #[gpui::test]
+    fn test_scroll_view_jump_and_listeners(cx: &mut TestAppContext) {
+        let bounds: ProbeBounds = Rc::default();
+        let controller = ScrollController::new();
+        let reported = Rc::new(RefCell::new(Vec::<Pixels>::new()));
+
+        let recorded = reported.clone();
+        controller.add_listener(move |offset, _, _| recorded.borrow_mut().push(offset));
+
+        let probe = bounds.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(100.), px(200.)), move |_, _| {
+            SingleChildScrollView::new(block(100., 1000., &probe))
+                .controller(attached.clone())
+                .padding(EdgeInsets::all(px(10.)))
+        });
+
+        assert_eq!(bounds.get().origin, point(px(10.), px(10.)));
+        assert_eq!(controller.max_scroll_extent(), px(820.));
+
+        controller.jump_to(px(300.));
+        redraw(cx);
+        assert_eq!(controller.offset(), px(300.));
+        assert_eq!(bounds.get().origin, point(px(10.), px(-290.)));
+
+        // Offsets past the end are clamped
+        controller.jump_to(px(5000.));
+        redraw(cx);
+        assert_eq!(controller.offset(), px(820.));
+        assert_eq!(*reported.borrow(), vec![px(300.), px(820.)]);
+    }
+
+    #[gpui::test]
+    fn test_scroll_view_animate_to(cx: &mut TestAppContext) {
+        let bounds: ProbeBounds = Rc::default();
+        let controller = ScrollController::new();
+
+        let probe = bounds.clone();
+        let attached = controller.clone();
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, _| {
+            SingleChildScrollView::new(block(1000., 100., &probe))
+                .horizontal()
+                .controller(attached.clone())
+        });
+
+        controller.animate_to(px(400.), Duration::from_millis(200));
+        assert!(controller.is_animating());
+        // The animation starts with the next frame
+        redraw(cx);
+        assert_eq!(controller.offset(), px(0.));
+
+        cx.executor().advance_clock(Duration::from_millis(100));
+        redraw(cx);
+        assert!(controller.is_animating());
+        assert!(controller.offset() > px(200.) && controller.offset() < px(400.));
+
+        cx.executor().advance_clock(Duration::from_millis(100));
+        redraw(cx);
+        assert!(!controller.is_animating());
+        assert_eq!(controller.offset(), px(400.));
+        assert_eq!(bounds.get().origin, point(px(-400.), px(0.)));
+    }
+
+    #[gpui::test]
+    fn test_scroll_view_dismisses_focus_on_drag(cx: &mut TestAppContext) {
+        let focus: Rc<RefCell<Option<FocusHandle>>> = Rc::default();
+
+        let handle = focus.clone();
+        let cx = draw_view(cx, size(px(200.), px(100.)), move |_, cx| {
+            let focus_handle = handle
+                .borrow_mut()
+                .get_or_insert_with(|| cx.focus_handle())
+                .clone();
+            SingleChildScrollView::new(div().h(px(500.)).track_focus(&focus_handle))
+                .keyboard_dismiss_behavior(KeyboardDismissBehavior::OnDrag)
+        });
+
+        let focus_handle = focus.borrow().clone().unwrap();
+        cx.update(|window, _| window.focus(&focus_handle));
+        assert!(cx.update(|window, _| focus_handle.is_focused(window)));
+
+        cx.simulate_event(ScrollWheelEvent {
+            position: point(px(50.), px(50.)),
+            delta: ScrollDelta::Pixels(point(px(0.), px(-20.))),
+            modifiers: Modifiers::default(),
+            touch_phase: TouchPhase::Moved,
+        });
+        assert!(!cx.update(|window, _| focus_handle.is_focused(window)));
+    }
+}
@@ -0,0 +1,184 @@
+// crates/ui/src/scroll/sliver.rs
+
+use super::GridDelegate;
+use gpui::*;
+use std::rc::Rc;
+
+pub(crate) type SliverItemBuilder = Rc<dyn Fn(usize, &mut Window, &mut App) -> AnyElement>;
+pub(crate) type HeaderBuilder = Box<dyn FnOnce(Pixels, bool, &mut Window, &mut App) -> AnyElement>;
+
+/// One section of a [`CustomScrollView`](super::CustomScrollView)
+///
+/// Every sliver type converts into this, so views can hold a mixed list of
+/// them.
+pub struct Sliver(pub(crate) SliverKind);
+
+pub(crate) enum SliverKind {
+    ToBoxAdapter(AnyElement),
+    List(SliverList),
+    Grid(SliverGrid),
+    PersistentHeader(SliverPersistentHeader),
+}
+
+/// A sliver holding a single ordinary element, laid out at its natural height
+pub struct SliverToBoxAdapter {
+    child: AnyElement,
+}
+
+impl SliverToBoxAdapter {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+        }
+    }
+}
+
+impl From<SliverToBoxAdapter> for Sliver {
+    fn from(adapter: SliverToBoxAdapter) -> Self {
+        Sliver(SliverKind::ToBoxAdapter(adapter.child))
+    }
+}
+
+/// A sliver of items built on demand, only while they are near the viewport
+///
+/// Items are measured the first time they are built; until then their height
+/// is estimated from the items measured so far. `item_extent` gives every
+/// item a fixed height instead.
+pub struct SliverList {
+    pub(crate) item_count: usize,
+    pub(crate) builder: SliverItemBuilder,
+    pub(crate) item_extent: Option<Pixels>,
+}
+
+impl SliverList {
+    pub fn builder<E: IntoElement>(
+        item_count: usize,
+        builder: impl Fn(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        Self {
+            item_count,
+            builder: Rc::new(move |index, window, cx| {
+                builder(index, window, cx).into_any_element()
+            }),
+            item_extent: None,
+        }
+    }
+
+    /// Give every item this height, so none need measuring
+    pub fn item_extent(mut self, extent: f32) -> Self {
+        self.item_extent = Some(px(extent));
+        self
+    }
+}
+
+impl From<SliverList> for Sliver {
+    fn from(list: SliverList) -> Self {
+        Sliver(SliverKind::List(list))
+    }
+}
+
+/// A sliver of equally sized tiles, built on demand one row at a time
+///
+/// Columns are chosen by a [`GridDelegate`], as in [`GridView`](super::GridView).
+pub struct SliverGrid {
+    pub(crate) delegate: GridDelegate,
+    pub(crate) item_count: usize,
+    pub(crate) builder: SliverItemBuilder,
+    pub(crate) main_axis_spacing: Pixels,
+    pub(crate) cross_axis_spacing: Pixels,
+    pub(crate) child_aspect_ratio: f32,
+}
+
+impl SliverGrid {
+    pub fn builder<E: IntoElement>(
+        delegate: GridDelegate,
+        item_count: usize,
+        builder: impl Fn(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        Self {
+            delegate,
+            item_count,
+            builder: Rc::new(move |index, window, cx| {
+                builder(index, window, cx).into_any_element()
+            }),
+            main_axis_spacing: px(0.),
+            cross_axis_spacing: px(0.),
+            child_aspect_ratio: 1.0,
+        }
+    }
+
+    /// Gap between rows
+    pub fn main_axis_spacing(mut self, spacing: f32) -> Self {
+        self.main_axis_spacing = px(spacing);
+        self
+    }
+
+    /// Gap between columns
+    pub fn cross_axis_spacing(mut self, spacing: f32) -> Self {
+        self.cross_axis_spacing = px(spacing);
+        self
+    }
+
+    /// Tile width divided by tile height
+    pub fn child_aspect_ratio(mut self, ratio: f32) -> Self {
+        self.child_aspect_ratio = ratio.max(f32::EPSILON);
+        self
+    }
+}
+
+impl From<SliverGrid> for Sliver {
+    fn from(grid: SliverGrid) -> Self {
+        Sliver(SliverKind::Grid(grid))
+    }
+}
+
+/// A header that shrinks from `max_extent` to `min_extent` as it scrolls away
+///
+/// The builder receives the shrink offset (how far below `max_extent` the
+/// header currently is) and whether content is scrolled underneath it.
+/// A `pinned` header stops at the top of the viewport, below any earlier
+/// pinned headers, which makes it a sticky section header when both extents
+/// are equal. A `floating` header slides back in as soon as the user scrolls
+/// toward the start, wherever its natural position is.
+pub struct SliverPersistentHeader {
+    pub(crate) min_extent: Pixels,
+    pub(crate) max_extent: Pixels,
+    pub(crate) pinned: bool,
+    pub(crate) floating: bool,
+    pub(crate) builder: HeaderBuilder,
+}
+
+impl SliverPersistentHeader {
+    pub fn new<E: IntoElement>(
+        min_extent: f32,
+        max_extent: f32,
+        builder: impl FnOnce(Pixels, bool, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        let min_extent = px(min_extent.max(0.));
+        Self {
+            min_extent,
+            max_extent: px(max_extent).max(min_extent),
+            pinned: false,
+            floating: false,
+            builder: Box::new(move |shrink_offset, overlaps_content, window, cx| {
+                builder(shrink_offset, overlaps_content, window, cx).into_any_element()
+            }),
+        }
+    }
+
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    pub fn floating(mut self, floating: bool) -> Self {
+        self.floating = floating;
+        self
+    }
+}
+
+impl From<SliverPersistentHeader> for Sliver {
+    fn from(header: SliverPersistentHeader) -> Self {
+        Sliver(SliverKind::PersistentHeader(header))
+    }
+}
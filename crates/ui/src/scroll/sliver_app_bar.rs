@@ -0,0 +1,161 @@
+// crates/ui/src/scroll/sliver_app_bar.rs
+
+use super::{Sliver, SliverPersistentHeader};
+use crate::theme::ActiveTheme;
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+
+/// Flutter-style SliverAppBar: a toolbar that collapses as content scrolls
+///
+/// With an `expanded_height` larger than the toolbar, the bar starts tall,
+/// showing its `flexible_space` behind the toolbar, and shrinks to the
+/// toolbar height as the view scrolls; the flexible space fades out on the
+/// way. `pinned` keeps the collapsed toolbar on screen and `floating` brings
+/// it back as soon as the user scrolls up. Colors come from the theme.
+///
+/// ```ignore
+/// CustomScrollView::new()
+///     .sliver(SliverAppBar::new().title("Inbox").expanded_height(160.).pinned(true))
+///     .sliver(SliverList::builder(messages.len(), move |index, _, _| row(&messages[index])))
+/// ```
+pub struct SliverAppBar {
+    leading: Option<AnyElement>,
+    title: Option<AnyElement>,
+    actions: Vec<AnyElement>,
+    flexible_space: Option<AnyElement>,
+    toolbar_height: Pixels,
+    expanded_height: Option<Pixels>,
+    pinned: bool,
+    floating: bool,
+}
+
+impl SliverAppBar {
+    pub fn new() -> Self {
+        Self {
+            leading: None,
+            title: None,
+            actions: Vec::new(),
+            flexible_space: None,
+            toolbar_height: px(56.),
+            expanded_height: None,
+            pinned: false,
+            floating: false,
+        }
+    }
+
+    /// Element before the title, typically a back or menu button
+    pub fn leading(mut self, leading: impl IntoElement) -> Self {
+        self.leading = Some(leading.into_any_element());
+        self
+    }
+
+    pub fn title(mut self, title: impl IntoElement) -> Self {
+        self.title = Some(title.into_any_element());
+        self
+    }
+
+    /// Add an element after the title, at the trailing end of the toolbar
+    pub fn action(mut self, action: impl IntoElement) -> Self {
+        self.actions.push(action.into_any_element());
+        self
+    }
+
+    /// Content filling the expanded bar behind the toolbar
+    pub fn flexible_space(mut self, space: impl IntoElement) -> Self {
+        self.flexible_space = Some(space.into_any_element());
+        self
+    }
+
+    /// Height of the toolbar, and of the bar once fully collapsed
+    pub fn toolbar_height(mut self, height: f32) -> Self {
+        self.toolbar_height = px(height);
+        self
+    }
+
+    /// Height of the bar before it starts collapsing
+    pub fn expanded_height(mut self, height: f32) -> Self {
+        self.expanded_height = Some(px(height));
+        self
+    }
+
+    pub fn pinned(mut self, pinned: bool) -> Self {
+        self.pinned = pinned;
+        self
+    }
+
+    pub fn floating(mut self, floating: bool) -> Self {
+        self.floating = floating;
+        self
+    }
+}
+
+impl Default for SliverAppBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<SliverAppBar> for Sliver {
+    fn from(bar: SliverAppBar) -> Self {
+        let toolbar_height = bar.toolbar_height;
+        let expanded_height = bar.expanded_height.unwrap_or(toolbar_height);
+        let collapse_range = (expanded_height - toolbar_height).max(Pixels::ZERO);
+
+        SliverPersistentHeader::new(
+            toolbar_height.into(),
+            expanded_height.into(),
+            move |shrink_offset, overlaps_content, _window, cx| {
+                let theme = cx.theme();
+                let collapsed = if collapse_range > Pixels::ZERO {
+                    (shrink_offset / collapse_range).clamp(0.0, 1.0)
+                } else {
+                    1.0
+                };
+
+                div()
+                    .relative()
+                    .size_full()
+                    .overflow_hidden()
+                    .bg(Hsla::from(theme.colors.background))
+                    .when(overlaps_content, |bar| {
+                        bar.border_b_1().border_color(theme.colors.border)
+                    })
+                    .children(bar.flexible_space.map(|space| {
+                        div()
+                            .absolute()
+                            .inset_0()
+                            .opacity(1.0 - collapsed)
+                            .child(space)
+                    }))
+                    .child(
+                        div()
+                            .absolute()
+                            .left_0()
+                            .right_0()
+                            .bottom_0()
+                            .h(toolbar_height)
+                            .flex()
+                            .items_center()
+                            .gap(theme.spacing.sm)
+                            .px(theme.spacing.md)
+                            .text_color(theme.colors.foreground)
+                            .children(bar.leading)
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .overflow_hidden()
+                                    .text_size(theme.typography.size_lg)
+                                    .font_weight(FontWeight(
+                                        theme.typography.weight_semibold as f32,
+                                    ))
+                                    .children(bar.title),
+                            )
+                            .children(bar.actions),
+                    )
+            },
+        )
+        .pinned(bar.pinned)
+        .floating(bar.floating)
+        .into()
+    }
+}
@@ -1,6 +1,8 @@
 // crates/ui/src/styled_ext.rs
 
-use gpui::{px, Styled};
+use crate::media_query::MediaQuery;
+use crate::theme::Breakpoint;
+use gpui::{px, Styled, Window};
 
 /// Extension trait for convenient styling methods
 pub trait StyledExt: Styled + Sized {
@@ -111,6 +113,35 @@ pub trait StyledExt: Styled + Sized {
     fn rounded_full(self) -> Self {
         self.rounded(px(9999.))
     }
+
+    // Responsive helpers
+    /// Apply `f` when the window is at least as wide as `breakpoint`
+    fn when_breakpoint(
+        self,
+        breakpoint: Breakpoint,
+        window: &Window,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        if MediaQuery::window_matches(window, breakpoint) {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Apply `f` when the window is narrower than `breakpoint`
+    fn when_below_breakpoint(
+        self,
+        breakpoint: Breakpoint,
+        window: &Window,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        if MediaQuery::window_matches(window, breakpoint) {
+            self
+        } else {
+            f(self)
+        }
+    }
 }
 
 // Implement for all Styled types
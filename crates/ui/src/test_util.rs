@@ -0,0 +1,80 @@
+// crates/ui/src/test_util.rs
+
+//! Helpers shared by element tests that run inside GPUI's test context
+
+use gpui::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Shared slot that receives the bounds an element was laid out at
+pub(crate) type ProbeBounds = Rc<Cell<Bounds<Pixels>>>;
+
+/// An element that fills its parent and records the bounds it receives
+pub(crate) fn probe() -> (Canvas<()>, ProbeBounds) {
+    let bounds: ProbeBounds = Rc::default();
+    let recorded = bounds.clone();
+
+    let element = canvas(move |bounds, _, _| recorded.set(bounds), |_, _, _, _| {}).size_full();
+
+    (element, bounds)
+}
+
+/// A probe with a fixed size, for measuring how a parent positions children
+pub(crate) fn sized_probe(width: f32, height: f32) -> (Canvas<()>, ProbeBounds) {
+    let bounds: ProbeBounds = Rc::default();
+    let recorded = bounds.clone();
+
+    let element = canvas(move |bounds, _, _| recorded.set(bounds), |_, _, _, _| {})
+        .w(px(width))
+        .h(px(height));
+
+    (element, bounds)
+}
+
+/// Lay out, prepaint and paint an element at the window origin
+pub(crate) fn draw<E: IntoElement>(
+    cx: &mut TestAppContext,
+    space: Size<Pixels>,
+    render: impl FnOnce(&mut Window, &mut App) -> E,
+) {
+    let cx = cx.add_empty_window();
+    cx.draw(point(px(0.), px(0.)), space, |window, cx| {
+        render(window, cx).into_any_element()
+    });
+}
+
+/// A root view that renders the given closure on every frame
+struct TestView<F> {
+    render: F,
+}
+
+impl<F, E> Render for TestView<F>
+where
+    F: Fn(&mut Window, &mut App) -> E + 'static,
+    E: IntoElement,
+{
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        (self.render)(window, cx)
+    }
+}
+
+/// Open a window of the given size whose root view renders `render`
+///
+/// Unlike [`draw`], elements run inside a view and can keep state across
+/// frames; call `refresh` and `run_until_parked` on the result to draw again.
+pub(crate) fn draw_view<E: IntoElement>(
+    cx: &mut TestAppContext,
+    space: Size<Pixels>,
+    render: impl Fn(&mut Window, &mut App) -> E + 'static,
+) -> &mut VisualTestContext {
+    let (_, cx) = cx.add_window_view(|_, _| TestView { render });
+    cx.simulate_resize(space);
+    cx.run_until_parked();
+    cx
+}
+
+/// Render another frame of a window opened with [`draw_view`]
+pub(crate) fn redraw(cx: &mut VisualTestContext) {
+    cx.update(|window, _| window.refresh());
+    cx.run_until_parked();
+}
@@ -9,6 +9,7 @@ pub use registry::{ActiveTheme, ThemeRegistry};
 pub use types::*;
 
 use gpui::{px, Pixels};
+use std::time::Duration;
 
 /// The main Theme struct used at runtime
 #[derive(Debug, Clone)]
@@ -19,6 +20,8 @@ pub struct Theme {
     pub radius: Radius,
     pub spacing: Spacing,
     pub typography: Typography,
+    pub breakpoints: Breakpoints,
+    pub motion: Motion,
 }
 
 #[derive(Debug, Clone)]
@@ -63,6 +66,93 @@ impl From<SpacingConfig> for Spacing {
     }
 }
 
+/// Responsive breakpoints, named after the smallest window width they cover
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Xxl,
+}
+
+impl Breakpoint {
+    /// All breakpoints, from smallest to largest
+    pub const ALL: [Breakpoint; 5] = [
+        Breakpoint::Sm,
+        Breakpoint::Md,
+        Breakpoint::Lg,
+        Breakpoint::Xl,
+        Breakpoint::Xxl,
+    ];
+}
+
+#[derive(Debug, Clone)]
+pub struct Breakpoints {
+    pub sm: Pixels,
+    pub md: Pixels,
+    pub lg: Pixels,
+    pub xl: Pixels,
+    pub xxl: Pixels,
+}
+
+impl Breakpoints {
+    /// Minimum width at which the breakpoint applies
+    pub fn min_width(&self, breakpoint: Breakpoint) -> Pixels {
+        match breakpoint {
+            Breakpoint::Sm => self.sm,
+            Breakpoint::Md => self.md,
+            Breakpoint::Lg => self.lg,
+            Breakpoint::Xl => self.xl,
+            Breakpoint::Xxl => self.xxl,
+        }
+    }
+
+    /// Whether a window of the given width is at or above the breakpoint
+    pub fn matches(&self, breakpoint: Breakpoint, width: Pixels) -> bool {
+        width >= self.min_width(breakpoint)
+    }
+
+    /// The largest breakpoint reached by the given width, if any
+    pub fn active(&self, width: Pixels) -> Option<Breakpoint> {
+        Breakpoint::ALL
+            .into_iter()
+            .rev()
+            .find(|breakpoint| self.matches(*breakpoint, width))
+    }
+}
+
+impl From<BreakpointsConfig> for Breakpoints {
+    fn from(config: BreakpointsConfig) -> Self {
+        Self {
+            sm: px(config.sm),
+            md: px(config.md),
+            lg: px(config.lg),
+            xl: px(config.xl),
+            xxl: px(config.xxl),
+        }
+    }
+}
+
+/// Animation durations for transitions, from quick state changes to
+/// larger movements
+#[derive(Debug, Clone)]
+pub struct Motion {
+    pub fast: Duration,
+    pub normal: Duration,
+    pub slow: Duration,
+}
+
+impl From<MotionConfig> for Motion {
+    fn from(config: MotionConfig) -> Self {
+        Self {
+            fast: Duration::from_millis(config.fast),
+            normal: Duration::from_millis(config.normal),
+            slow: Duration::from_millis(config.slow),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Typography {
     pub font_family: String,
@@ -107,6 +197,8 @@ impl Theme {
             radius: config.radius.into(),
             spacing: config.spacing.into(),
             typography: config.typography.into(),
+            breakpoints: config.breakpoints.into(),
+            motion: config.motion.into(),
         }
     }
 }
@@ -120,3 +212,28 @@ pub fn init() {
     ThemeRegistry::register_from_json(light_theme).expect("Failed to load default light theme");
     ThemeRegistry::register_from_json(dark_theme).expect("Failed to load default dark theme");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_active_breakpoint() {
+        let breakpoints = Breakpoints::from(BreakpointsConfig::default());
+
+        assert_eq!(breakpoints.active(px(320.)), None);
+        assert_eq!(breakpoints.active(px(768.)), Some(Breakpoint::Md));
+        assert_eq!(breakpoints.active(px(1100.)), Some(Breakpoint::Lg));
+        assert_eq!(breakpoints.active(px(4000.)), Some(Breakpoint::Xxl));
+    }
+
+    #[test]
+    fn test_breakpoints_default_when_missing_from_json() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../../themes/default-light.json")).unwrap();
+        json.as_object_mut().unwrap().remove("breakpoints");
+
+        let config: ThemeConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config.breakpoints.md, 768.0);
+    }
+}
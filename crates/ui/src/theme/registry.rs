@@ -103,6 +103,8 @@ impl ThemeRegistry {
             radius: Default::default(),
             spacing: Default::default(),
             typography: Default::default(),
+            breakpoints: Default::default(),
+            motion: Default::default(),
         };
 
         Theme::from_config(config)
@@ -17,6 +17,10 @@ pub struct ThemeConfig {
     pub radius: RadiusConfig,
     pub spacing: SpacingConfig,
     pub typography: TypographyConfig,
+    #[serde(default)]
+    pub breakpoints: BreakpointsConfig,
+    #[serde(default)]
+    pub motion: MotionConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +97,46 @@ impl Default for SpacingConfig {
     }
 }
 
+/// Minimum window widths (in pixels) at which each breakpoint starts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreakpointsConfig {
+    pub sm: f32,  // 640px
+    pub md: f32,  // 768px
+    pub lg: f32,  // 1024px
+    pub xl: f32,  // 1280px
+    pub xxl: f32, // 1536px
+}
+
+impl Default for BreakpointsConfig {
+    fn default() -> Self {
+        Self {
+            sm: 640.0,
+            md: 768.0,
+            lg: 1024.0,
+            xl: 1280.0,
+            xxl: 1536.0,
+        }
+    }
+}
+
+/// Animation durations in milliseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MotionConfig {
+    pub fast: u64,   // 100ms
+    pub normal: u64, // 200ms
+    pub slow: u64,   // 300ms
+}
+
+impl Default for MotionConfig {
+    fn default() -> Self {
+        Self {
+            fast: 100,
+            normal: 200,
+            slow: 300,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypographyConfig {
     pub font_family: String,